@@ -115,6 +115,16 @@ pub struct CommitmentRules {
     pub grace_period_days: u32,
 }
 
+/// Lifecycle state of a commitment (mirrors commitment_core).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommitmentStatus {
+    Active,
+    Settled,
+    Violated,
+    EarlyExit,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Commitment {
@@ -127,7 +137,14 @@ pub struct Commitment {
     pub created_at: u64,
     pub expires_at: u64,
     pub current_value: i128,
-    pub status: String, // "active", "settled", "violated", "early_exit"
+    pub status: CommitmentStatus,
+    pub memo: String,
+    pub settled_amount: i128,
+    pub settled_at: u64,
+    pub max_drawdown_percent: u32,
+    pub last_update_ts: u64,
+    pub realized_pnl: i128,
+    pub beneficiary: Option<Address>,
 }
 
 // ============================================================================
@@ -800,7 +817,7 @@ impl AllocationStrategiesContract {
     /// # Design Spike: Cross-Contract Validation
     /// This function performs a real cross-contract call to `commitment_core` to:
     /// 1. Validate the commitment exists.
-    /// 2. Validate the commitment is in "active" status.
+    /// 2. Validate the commitment is `CommitmentStatus::Active`.
     /// 3. Retrieve the current value (balance).
     fn get_commitment_balance(env: &Env, commitment_id: String) -> Result<i128, Error> {
         // Retrieve commitment_core contract address
@@ -830,9 +847,8 @@ impl AllocationStrategiesContract {
             .try_into_val(env)
             .map_err(|_| Error::AllocationNotFound)?;
 
-        // SECURITY: Validate commitment status is "active"
-        let active_status = String::from_str(env, "active");
-        if commitment.status != active_status {
+        // SECURITY: Validate commitment status is Active
+        if commitment.status != CommitmentStatus::Active {
             return Err(Error::PoolInactive); // Reusing PoolInactive or could use a new error code
         }
 
@@ -2,8 +2,8 @@
 extern crate std;
 
 use crate::{
-    AllocationStrategiesContract, AllocationStrategiesContractClient, Error, RiskLevel, Strategy,
-    Commitment, CommitmentRules, CURRENT_VERSION,
+    AllocationStrategiesContract, AllocationStrategiesContractClient, Commitment, CommitmentRules,
+    CommitmentStatus, Error, RiskLevel, Strategy, CURRENT_VERSION,
 };
 use soroban_sdk::{
     contract, contractimpl, testutils::Address as _, testutils::Ledger, Address, Env, Map, String,
@@ -53,7 +53,13 @@ fn create_contract(env: &Env) -> (Address, Address, AllocationStrategiesContract
     (admin, mock_core_id, client)
 }
 
-fn create_mock_commitment(env: &Env, core_id: &Address, id: &str, amount: i128, status: &str) {
+fn create_mock_commitment(
+    env: &Env,
+    core_id: &Address,
+    id: &str,
+    amount: i128,
+    status: CommitmentStatus,
+) {
     let mock_client = MockCommitmentCoreClient::new(env, core_id);
     
     let rules = CommitmentRules {
@@ -75,7 +81,14 @@ fn create_mock_commitment(env: &Env, core_id: &Address, id: &str, amount: i128,
         created_at: env.ledger().timestamp(),
         expires_at: env.ledger().timestamp() + 86400 * 30,
         current_value: amount,
-        status: String::from_str(env, status),
+        status,
+        memo: String::from_str(env, ""),
+        settled_amount: 0,
+        settled_at: 0,
+        max_drawdown_percent: 0,
+        last_update_ts: 0,
+        realized_pnl: 0,
+        beneficiary: None,
     };
     
     mock_client.set_commitment(&commitment);
@@ -164,7 +177,7 @@ fn test_rebalance_owner_match_verification() {
     let non_owner = Address::generate(&env);
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
     let initial_summary = client.allocate(&owner, &commitment_id, &amount, &Strategy::Balanced);
     assert_eq!(initial_summary.total_allocated, amount);
 
@@ -191,7 +204,7 @@ fn test_rebalance_strategy_persistence_safe_strategy() {
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
     let initial_summary = client.allocate(&user, &commitment_id, &amount, &Strategy::Safe);
     assert_eq!(initial_summary.strategy, Strategy::Safe);
 
@@ -227,7 +240,7 @@ fn test_rebalance_strategy_persistence_aggressive_strategy() {
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
 
     // Create allocation with Aggressive strategy
     let initial_summary = client.allocate(&user, &commitment_id, &amount, &Strategy::Aggressive);
@@ -266,7 +279,7 @@ fn test_rebalance_summary_correctness() {
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
     let strategy = Strategy::Balanced;
 
     // Create initial allocation
@@ -322,7 +335,7 @@ fn test_rebalance_with_pool_status_changes() {
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
 
     // Create initial allocation
     let initial_summary = client.allocate(&user, &commitment_id, &amount, &Strategy::Balanced);
@@ -369,8 +382,8 @@ fn test_rebalance_multiple_commitments_isolation() {
     let commitment1 = String::from_str(&env, "commit_1");
     let commitment2 = String::from_str(&env, "commit_2");
     let amount = 50_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
-    create_mock_commitment(&env, &core_id, "commit_2", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
+    create_mock_commitment(&env, &core_id, "commit_2", amount, CommitmentStatus::Active);
 
     // Create two separate allocations with different strategies
     let summary1 = client.allocate(&user1, &commitment1, &amount, &Strategy::Safe);
@@ -419,7 +432,7 @@ fn test_rebalance_summary_timestamp_updates() {
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
 
     // Create initial allocation
     let initial_summary = client.allocate(&user, &commitment_id, &amount, &Strategy::Balanced);
@@ -461,7 +474,7 @@ fn test_rebalance_edge_case_zero_allocation() {
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
 
     // Initial allocation should fail due to no active pools
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -484,7 +497,7 @@ fn test_rebalance_with_capacity_constraints() {
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
 
     // Create allocation that hits capacity constraints
     let initial_summary = client.allocate(&user, &commitment_id, &amount, &Strategy::Safe);
@@ -493,7 +506,13 @@ fn test_rebalance_with_capacity_constraints() {
     // Fill up pool 0 to capacity
     let other_user = Address::generate(&env);
     let other_commitment = String::from_str(&env, "commit_2");
-    create_mock_commitment(&env, &core_id, "commit_2", 50_000_000, "active");
+    create_mock_commitment(
+        &env,
+        &core_id,
+        "commit_2",
+        50_000_000,
+        CommitmentStatus::Active,
+    );
     client.allocate(&other_user, &other_commitment, &50_000_000, &Strategy::Safe);
 
     // Rebalance should handle capacity constraints correctly
@@ -558,7 +577,7 @@ fn test_safe_strategy_allocation() {
     let commitment_id = String::from_str(&env, "commit_1");
     let amount = 100_000_000i128;
     
-    create_mock_commitment(&env, &core_id, "commit_1", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_1", amount, CommitmentStatus::Active);
 
     let summary = client.allocate(&user, &commitment_id, &amount, &Strategy::Safe);
 
@@ -585,7 +604,7 @@ fn test_balanced_strategy_allocation() {
     let commitment_id = String::from_str(&env, "commit_2");
     let amount = 100_000_000i128;
     
-    create_mock_commitment(&env, &core_id, "commit_2", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_2", amount, CommitmentStatus::Active);
     
     let summary = client.allocate(&user, &commitment_id, &amount, &Strategy::Balanced);
 
@@ -605,7 +624,7 @@ fn test_aggressive_strategy_allocation() {
     let commitment_id = String::from_str(&env, "commit_3");
     let amount = 100_000_000i128;
     
-    create_mock_commitment(&env, &core_id, "commit_3", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_3", amount, CommitmentStatus::Active);
 
     let summary = client.allocate(&user, &commitment_id, &amount, &Strategy::Aggressive);
 
@@ -630,7 +649,7 @@ fn test_get_allocation() {
     let commitment_id = String::from_str(&env, "commit_4");
     let amount = 50_000_000i128;
     
-    create_mock_commitment(&env, &core_id, "commit_4", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_4", amount, CommitmentStatus::Active);
 
     client.allocate(&user, &commitment_id, &amount, &Strategy::Safe);
 
@@ -653,7 +672,7 @@ fn test_rebalance() {
     let commitment_id = String::from_str(&env, "commit_5");
     let amount = 100_000_000i128;
     
-    create_mock_commitment(&env, &core_id, "commit_5", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_5", amount, CommitmentStatus::Active);
 
     // Initial allocation
     client.allocate(&user, &commitment_id, &amount, &Strategy::Safe);
@@ -684,7 +703,7 @@ fn test_pool_liquidity_tracking() {
     let commitment_id = String::from_str(&env, "commit_6");
     let amount = 100_000_000i128;
     
-    create_mock_commitment(&env, &core_id, "commit_6", amount, "active");
+    create_mock_commitment(&env, &core_id, "commit_6", amount, CommitmentStatus::Active);
 
     // Check initial liquidity
     let pool_before = client.get_pool(&0);
@@ -714,8 +733,8 @@ fn test_allocation_rate_limit_enforced() {
     
     setup_test_pools(&env, &client, &admin);
     
-    create_mock_commitment(&env, &core_id, "c1", 10_000_000, "active");
-    create_mock_commitment(&env, &core_id, "c2", 10_000_000, "active");
+    create_mock_commitment(&env, &core_id, "c1", 10_000_000, CommitmentStatus::Active);
+    create_mock_commitment(&env, &core_id, "c2", 10_000_000, CommitmentStatus::Active);
 
     // First allocation should succeed
     client.allocate(&user, &String::from_str(&env, "c1"), &10_000_000, &Strategy::Balanced);
@@ -740,7 +759,7 @@ fn test_rebalance_rate_limit_enforced() {
     
     setup_test_pools(&env, &client, &admin);
     
-    create_mock_commitment(&env, &core_id, "c1", 10_000_000, "active");
+    create_mock_commitment(&env, &core_id, "c1", 10_000_000, CommitmentStatus::Active);
 
     // First allocate
     client.allocate(&user, &String::from_str(&env, "c1"), &10_000_000, &Strategy::Balanced);
@@ -770,9 +789,9 @@ fn test_allocation_rate_limit_exempt() {
     
     setup_test_pools(&env, &client, &admin);
     
-    create_mock_commitment(&env, &core_id, "c1", 10_000_000, "active");
-    create_mock_commitment(&env, &core_id, "c2", 10_000_000, "active");
-    create_mock_commitment(&env, &core_id, "c3", 10_000_000, "active");
+    create_mock_commitment(&env, &core_id, "c1", 10_000_000, CommitmentStatus::Active);
+    create_mock_commitment(&env, &core_id, "c2", 10_000_000, CommitmentStatus::Active);
+    create_mock_commitment(&env, &core_id, "c3", 10_000_000, CommitmentStatus::Active);
 
     // Multiple allocations should succeed for exempt user
     client.allocate(&user, &String::from_str(&env, "c1"), &10_000_000, &Strategy::Balanced);
@@ -798,7 +817,7 @@ fn test_rebalance_rate_limit_exempt() {
     
     setup_test_pools(&env, &client, &admin);
     
-    create_mock_commitment(&env, &core_id, "c1", 10_000_000, "active");
+    create_mock_commitment(&env, &core_id, "c1", 10_000_000, CommitmentStatus::Active);
 
     // First allocate
     client.allocate(&user, &String::from_str(&env, "c1"), &10_000_000, &Strategy::Balanced);
@@ -841,10 +860,16 @@ fn test_allocation_inactive_commitment_fails() {
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "settled_commitment");
     
-    // Create commitment with "settled" status
-    create_mock_commitment(&env, &core_id, "settled_commitment", 100_000_000, "settled");
-
-    // Should fail because status is not "active"
+    // Create commitment with Settled status
+    create_mock_commitment(
+        &env,
+        &core_id,
+        "settled_commitment",
+        100_000_000,
+        CommitmentStatus::Settled,
+    );
+
+    // Should fail because status is not Active
     client.allocate(&user, &commitment_id, &10_000_000, &Strategy::Safe);
 }
 
@@ -861,7 +886,13 @@ fn test_allocation_exceeds_commitment_balance_fails() {
     let commitment_id = String::from_str(&env, "low_balance_commit");
     
     // Commitment has 50M balance
-    create_mock_commitment(&env, &core_id, "low_balance_commit", 50_000_000, "active");
+    create_mock_commitment(
+        &env,
+        &core_id,
+        "low_balance_commit",
+        50_000_000,
+        CommitmentStatus::Active,
+    );
 
     // Attempt to allocate 100M should fail
     client.allocate(&user, &commitment_id, &100_000_000, &Strategy::Safe);
@@ -1199,7 +1230,13 @@ fn test_allocate_exactly_at_max_capacity_accepted() {
 
     let user = Address::generate(&env);
     let commitment_id = String::from_str(&env, "cap_exact");
-    create_mock_commitment(&env, &core_id, "cap_exact", capacity, "active");
+    create_mock_commitment(
+        &env,
+        &core_id,
+        "cap_exact",
+        capacity,
+        CommitmentStatus::Active,
+    );
 
     let summary = client.allocate(&user, &commitment_id, &capacity, &Strategy::Safe);
 
@@ -1224,7 +1261,7 @@ fn test_allocate_one_over_max_capacity_rejected() {
     let user = Address::generate(&env);
     let over = capacity + 1;
     let commitment_id = String::from_str(&env, "cap_over");
-    create_mock_commitment(&env, &core_id, "cap_over", over, "active");
+    create_mock_commitment(&env, &core_id, "cap_over", over, CommitmentStatus::Active);
 
     // Must panic – one stroop over capacity.
     client.allocate(&user, &commitment_id, &over, &Strategy::Safe);
@@ -1246,7 +1283,13 @@ fn test_rebalance_across_pools_respects_capacity() {
     let user = Address::generate(&env);
     let amount = 100_000_000i128;
     let commitment_id = String::from_str(&env, "rebal_cap");
-    create_mock_commitment(&env, &core_id, "rebal_cap", amount, "active");
+    create_mock_commitment(
+        &env,
+        &core_id,
+        "rebal_cap",
+        amount,
+        CommitmentStatus::Active,
+    );
 
     client.allocate(&user, &commitment_id, &amount, &Strategy::Safe);
 
@@ -1282,7 +1325,13 @@ fn test_update_pool_capacity_below_total_liquidity_rejected() {
     let user = Address::generate(&env);
     let amount = 80_000_000i128;
     let commitment_id = String::from_str(&env, "cap_reduce");
-    create_mock_commitment(&env, &core_id, "cap_reduce", amount, "active");
+    create_mock_commitment(
+        &env,
+        &core_id,
+        "cap_reduce",
+        amount,
+        CommitmentStatus::Active,
+    );
 
     // Allocate 80M into the pool.
     client.allocate(&user, &commitment_id, &amount, &Strategy::Safe);
@@ -1305,7 +1354,13 @@ fn test_rebalance_total_liquidity_no_underflow() {
     let user = Address::generate(&env);
     let amount = 100_000_000i128;
     let commitment_id = String::from_str(&env, "no_underflow");
-    create_mock_commitment(&env, &core_id, "no_underflow", amount, "active");
+    create_mock_commitment(
+        &env,
+        &core_id,
+        "no_underflow",
+        amount,
+        CommitmentStatus::Active,
+    );
 
     client.allocate(&user, &commitment_id, &amount, &Strategy::Safe);
 
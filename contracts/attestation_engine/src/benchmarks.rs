@@ -84,7 +84,14 @@ fn store_mock_commitment(
         created_at: 0,
         expires_at: 86_400,
         current_value: 1_000,
-        status: String::from_str(e, "active"),
+        status: CommitmentStatus::Active,
+        memo: String::from_str(e, ""),
+        settled_amount: 0,
+        settled_at: 0,
+        max_drawdown_percent: 0,
+        last_update_ts: 0,
+        realized_pnl: 0,
+        beneficiary: None,
     };
 
     e.as_contract(core_contract_id, || {
@@ -5,13 +5,39 @@
 //! The engine trusts `commitment_core` as the canonical source for commitment
 //! existence and lifecycle state, while it derives fee totals and volatility
 //! exposure from recorded attestation history.
-use shared_utils::{BatchError, BatchMode, BatchProcessor, BatchResultVoid, Pausable, RateLimiter};
+//!
+//! # Attestation events
+//! Topics are `(name, commitment_id, ..actors)`. Data is a tuple whose first element
+//! is the `commitment_id` and whose last element is the ledger timestamp.
+//!
+//! | Event                 | Topics                                              | Data                                                        |
+//! |-----------------------|-----------------------------------------------------|-------------------------------------------------------------|
+//! | `AttestationRecorded` | `(AttestationRecorded, commitment_id, verifier)`    | `(commitment_id, attestation_type, is_compliant, timestamp)` |
+//! | `AttestationUpdated`  | `(AttestationUpdated, commitment_id, verifier)`     | `(commitment_id, attestation_type, is_compliant, timestamp)` |
+//! | `ViolationPending`    | `(ViolationPending, commitment_id, verifier)`       | `(commitment_id, signed, required, timestamp)`              |
+//! | `ViolationRecorded`   | `(ViolationRecorded, commitment_id)`                | `(commitment_id, drawdown_percent, max_loss_percent, timestamp)` |
+use shared_utils::{
+    BatchError, BatchMode, BatchProcessor, BatchResultVoid, Pausable, RateLimiter, Storage,
+};
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, BytesN, Env,
     IntoVal, Map, String, Symbol, TryIntoVal, Val, Vec,
 };
 
-const CURRENT_VERSION: u32 = 1;
+/// Version 2 stores attestations in `AttestationChunk` entries instead of one
+/// `Attestations` Vec per commitment.
+const CURRENT_VERSION: u32 = 2;
+
+/// Compliance score an active commitment must reach in `verify_compliance`
+/// unless a per-commitment override is configured.
+pub const DEFAULT_MIN_COMPLIANCE_SCORE: u32 = 50;
+
+/// Default score impact of a `violation` attestation (scaled by severity).
+pub const DEFAULT_VIOLATION_WEIGHT: u32 = 20;
+/// Default score bonus of a compliant non-violation attestation.
+pub const DEFAULT_COMPLIANT_WEIGHT: u32 = 1;
+/// Upper bound for a configured attestation weight.
+pub const MAX_ATTESTATION_WEIGHT: u32 = 100;
 
 // ============================================================================
 // Error Types
@@ -30,9 +56,11 @@ pub enum AttestationError {
     Unauthorized = 3,
     /// Invalid commitment ID
     InvalidCommitmentId = 4,
-    /// Invalid attestation type. Allowed types: "health_check", "violation", "fee_generation", "drawdown".
+    /// Invalid attestation type. Allowed types: "health_check", "violation", "fee_generation",
+    /// "drawdown", "volatility".
     InvalidAttestationType = 5,
-    /// Invalid attestation data for the given type
+    /// Invalid attestation data for the given type, or more than
+    /// `MAX_ATTESTATION_DATA_KEYS` keys / `MAX_ATTESTATION_DATA_BYTES` bytes of data
     InvalidAttestationData = 6,
     /// Commitment not found in core contract
     CommitmentNotFound = 7,
@@ -50,6 +78,22 @@ pub enum AttestationError {
     InvalidVersion = 13,
     /// Migration already applied.
     AlreadyMigrated = 14,
+    /// Compliance threshold must be within 0-100.
+    InvalidComplianceThreshold = 15,
+    /// Attestation weight exceeds `MAX_ATTESTATION_WEIGHT`.
+    InvalidAttestationWeight = 16,
+    /// Commitment already holds `MAX_ATTESTATIONS_PER_COMMITMENT` attestations.
+    TooManyAttestations = 17,
+    /// Verifier has already co-signed this pending violation.
+    AlreadySigned = 18,
+    /// Commitment already holds `MAX_PENDING_VIOLATIONS` pending violations.
+    TooManyPendingAttestations = 19,
+    /// No attestation exists at the requested index.
+    AttestationIndexOutOfRange = 20,
+    /// Maximum batch size must be at least 1.
+    InvalidBatchSize = 21,
+    /// The attestation has already been revoked.
+    AttestationRevoked = 22,
 }
 
 // ============================================================================
@@ -65,11 +109,16 @@ pub enum DataKey {
     CoreContract,
     /// Verifier whitelist (Address -> bool)
     Verifier(Address),
-    /// Attestations for a commitment (commitment_id -> Vec<Attestation>)
+    /// Attestations for a commitment, oldest first, `CHUNK_SIZE` per chunk
+    /// ((commitment_id, chunk_index) -> Vec<Attestation>)
+    AttestationChunk(String, u32),
+    /// Pre-chunking list of a commitment's attestations (commitment_id ->
+    /// Vec<LegacyAttestation>); re-chunked and removed on first access
     Attestations(String),
     /// Health metrics for a commitment (commitment_id -> HealthMetrics)
     HealthMetrics(String),
-    /// Attestation counter for a commitment (commitment_id -> u64)
+    /// Attestations stored for a commitment, i.e. the length of its chunked list
+    /// (commitment_id -> u64)
     AttestationCounter(String),
     /// Reentrancy guard
     ReentrancyGuard,
@@ -130,6 +179,33 @@ pub enum DataKey {
     CollectedFees(Address),
     /// Storage schema version
     Version,
+    /// Per-commitment minimum compliance score override (commitment_id -> u32)
+    ComplianceThreshold(String),
+    /// Score impact per attestation type (attestation_type -> u32)
+    AttestationWeight(String),
+    /// Chunk `n` of the commitment IDs with at least one attestation, in
+    /// first-attested order; each chunk holds up to `ID_INDEX_CHUNK_SIZE` IDs
+    AllCommitmentIdsWithAttestations(u32),
+    /// Number of IDs across all `AllCommitmentIdsWithAttestations` chunks (u32)
+    AttestedCommitmentCount,
+    /// Whether informational events are emitted (bool, default true)
+    EventsEnabled,
+    /// Seconds within which `upsert_attestation` replaces instead of appending (u64)
+    UpsertWindow,
+    /// Distinct verifiers needed to activate a violation attestation (u32, default 1)
+    RequiredSigners,
+    /// Violation attestations still collecting co-signers (commitment_id -> Vec<PendingViolation>)
+    PendingViolations(String),
+    /// Compliance score decay for commitments without fresh attestations (ScoreDecayConfig)
+    ScoreDecay,
+    /// Whether the latest recorded drawdown breached max loss (commitment_id -> bool)
+    InDrawdownViolation(String),
+    /// Drawdown violations as (timestamp, drawdown_percent, max_loss_percent), oldest first
+    ViolationHistory(String),
+    /// Seconds before an unresolved pending violation is dropped (u64)
+    PendingTtlSecs,
+    /// Volatility above which the compliance score is penalised, in bps (i128, 0 disables)
+    VolatilityThreshold,
 }
 
 #[contracttype]
@@ -137,10 +213,26 @@ pub enum DataKey {
 pub struct Attestation {
     pub commitment_id: String,
     pub timestamp: u64,
-    pub attestation_type: String, // "health_check", "violation", "fee_generation", "drawdown"
+    pub attestation_type: String, // "health_check", "violation", "fee_generation", "drawdown", "volatility"
     pub data: Map<String, String>, // Flexible data structure
     pub is_compliant: bool,
     pub verified_by: Address,
+    /// Set by `revoke_attestation`. Revoked attestations stay in storage for the
+    /// audit trail but no longer count towards metrics or the compliance score.
+    pub is_revoked: bool,
+}
+
+/// Stored layout of an attestation under the legacy `DataKey::Attestations` list,
+/// before `is_revoked` existed. Kept only so such lists can be re-chunked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyAttestation {
+    pub commitment_id: String,
+    pub timestamp: u64,
+    pub attestation_type: String,
+    pub data: Map<String, String>,
+    pub is_compliant: bool,
+    pub verified_by: Address,
 }
 
 /// Parameters for batch attestation operations
@@ -163,9 +255,61 @@ pub struct AttestationsPage {
     pub next_offset: u32,
 }
 
+/// A violation attestation waiting for `RequiredSigners` distinct verifiers.
+/// Signers co-sign by attesting the same commitment, data and compliance flag.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingViolation {
+    pub data: Map<String, String>,
+    pub is_compliant: bool,
+    pub signers: Vec<Address>,
+    pub created_at: u64,
+}
+
+/// Compliance score decay applied while a commitment goes unattested.
+/// `points_per_day == 0` disables decay (the default).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreDecayConfig {
+    /// Score points lost per full day since the last attestation.
+    pub points_per_day: u32,
+    /// Decay never takes the score below this value (0-100).
+    pub floor: u32,
+}
+
+/// Name under which `batch_attest`'s size limit is stored in the shared batch config.
+const BATCH_CONTRACT_NAME: &str = "attestation_engine";
+
 /// Maximum number of attestations returned per page (avoids exceeding Soroban limits).
 pub const MAX_PAGE_SIZE: u32 = 100;
 
+/// IDs per `AllCommitmentIdsWithAttestations` chunk, so no single entry grows
+/// with the number of attested commitments.
+pub const ID_INDEX_CHUNK_SIZE: u32 = 50;
+
+/// Attestations per `AttestationChunk` entry, so no single entry grows with a
+/// commitment's history and paging reads only the chunks it returns.
+pub const CHUNK_SIZE: u32 = 50;
+
+/// Maximum attestations stored per commitment. Scoring and metric rebuilds still read
+/// the whole history, so it must stay small enough to load in one call.
+pub const MAX_ATTESTATIONS_PER_COMMITMENT: u32 = 200;
+
+/// Maximum keys in one attestation's `data` map.
+pub const MAX_ATTESTATION_DATA_KEYS: u32 = 16;
+
+/// Maximum total bytes of keys and values in one attestation's `data` map.
+pub const MAX_ATTESTATION_DATA_BYTES: u32 = 1_024;
+
+/// Maximum unresolved pending violations per commitment.
+pub const MAX_PENDING_VIOLATIONS: u32 = 20;
+
+/// Default lifetime of a pending violation before it is dropped (7 days).
+pub const DEFAULT_PENDING_TTL_SECS: u64 = 7 * 86_400;
+
+/// Entries kept in a commitment's violation history; the oldest is dropped beyond this.
+pub const MAX_VIOLATION_HISTORY: u32 = 50;
+
 // Import Commitment types from commitment_core (define locally for cross-contract calls)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -178,6 +322,16 @@ pub struct CommitmentRules {
     pub grace_period_days: u32,
 }
 
+/// Lifecycle state of a commitment (mirrors commitment_core).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommitmentStatus {
+    Active,
+    Settled,
+    Violated,
+    EarlyExit,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Commitment {
@@ -190,7 +344,14 @@ pub struct Commitment {
     pub created_at: u64,
     pub expires_at: u64,
     pub current_value: i128,
-    pub status: String, // "active", "settled", "violated", "early_exit"
+    pub status: CommitmentStatus,
+    pub memo: String,
+    pub settled_amount: i128,
+    pub settled_at: u64,
+    pub max_drawdown_percent: u32,
+    pub last_update_ts: u64,
+    pub realized_pnl: i128,
+    pub beneficiary: Option<Address>,
 }
 
 #[contracttype]
@@ -199,8 +360,19 @@ pub struct HealthMetrics {
     pub commitment_id: String,
     pub current_value: i128,
     pub initial_value: i128,
+    /// Drawdown in whole percent, truncated toward zero. Kept for callers that
+    /// predate `drawdown_bps`; always equals `drawdown_bps / 100`.
     pub drawdown_percent: i128,
+    /// Drawdown in basis points (1 bps = 0.01%), so sub-1% losses stay visible.
+    /// Never negative: a commitment trading above its initial value reports 0.
+    pub drawdown_bps: i128,
+    /// Worst drawdown seen so far, in whole percent. Recoveries never lower it.
+    pub max_drawdown_percent: i128,
     pub fees_generated: i128,
+    /// Realized volatility in basis points, never negative. See `get_health_metrics`
+    /// for how it is derived. Breaking change: this used to be the cumulative
+    /// whole-percent change between drawdown attestations; readers of the old
+    /// value must rescale.
     pub volatility_exposure: i128,
     pub last_attestation: u64,
     pub compliance_score: u32, // 0-100
@@ -209,8 +381,11 @@ pub struct HealthMetrics {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 struct AttestationMetricAggregate {
     fees_generated: i128,
-    latest_drawdown_percent: Option<i128>,
+    latest_drawdown_bps: Option<i128>,
+    max_drawdown_bps: i128,
+    /// Average absolute change between consecutive drawdown attestations, in bps.
     volatility_exposure: i128,
+    latest_volatility_bps: Option<i128>,
     last_attestation: u64,
 }
 
@@ -519,6 +694,11 @@ impl AttestationEngineContract {
     }
 
     /// Migrate storage from a previous version to CURRENT_VERSION (admin-only).
+    ///
+    /// Version 1 kept each commitment's attestations in a single `Attestations`
+    /// entry. Those lists cannot be enumerated here, so each one is re-chunked the
+    /// first time its count is read (see `get_attestation_count`), which every
+    /// attestation read and write does first.
     pub fn migrate(e: Env, caller: Address, from_version: u32) -> Result<(), AttestationError> {
         require_admin(&e, &caller)?;
 
@@ -581,19 +761,33 @@ impl AttestationEngineContract {
         let violation = String::from_str(e, "violation");
         let fee_generation = String::from_str(e, "fee_generation");
         let drawdown = String::from_str(e, "drawdown");
+        let volatility = String::from_str(e, "volatility");
 
         *att_type == health_check
             || *att_type == violation
             || *att_type == fee_generation
             || *att_type == drawdown
+            || *att_type == volatility
     }
 
     /// Validate attestation data based on type
     fn validate_attestation_data(e: &Env, att_type: &String, data: &Map<String, String>) -> bool {
+        if data.len() > MAX_ATTESTATION_DATA_KEYS {
+            return false;
+        }
+        let mut bytes: u32 = 0;
+        for (key, value) in data.iter() {
+            bytes = bytes.saturating_add(key.len()).saturating_add(value.len());
+        }
+        if bytes > MAX_ATTESTATION_DATA_BYTES {
+            return false;
+        }
+
         let health_check = String::from_str(e, "health_check");
         let violation = String::from_str(e, "violation");
         let fee_generation = String::from_str(e, "fee_generation");
         let drawdown = String::from_str(e, "drawdown");
+        let volatility = String::from_str(e, "volatility");
 
         if *att_type == health_check {
             // health_check: optional fields, always valid
@@ -611,6 +805,10 @@ impl AttestationEngineContract {
             // drawdown: requires "drawdown_percent"
             let drawdown_percent_key = String::from_str(e, "drawdown_percent");
             data.contains_key(drawdown_percent_key)
+        } else if *att_type == volatility {
+            // volatility: requires "volatility_bps"
+            let volatility_bps_key = String::from_str(e, "volatility_bps");
+            data.contains_key(volatility_bps_key)
         } else {
             false
         }
@@ -637,6 +835,146 @@ impl AttestationEngineContract {
         matches!(result, Ok(Ok(_)))
     }
 
+    /// Read a commitment from `commitment_core` without trapping.
+    ///
+    /// Returns `None` when the core address is unset, points at a contract
+    /// that does not implement `get_commitment`, the commitment is missing, or
+    /// the returned value does not decode. Callers treat `None` as "no rules
+    /// available" and fall back to a conservative default.
+    fn read_core_commitment(e: &Env, commitment_id: &String) -> Option<Commitment> {
+        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract)?;
+
+        let mut args = Vec::new(e);
+        args.push_back(commitment_id.clone().into_val(e));
+        match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &commitment_core,
+            &Symbol::new(e, "get_commitment"),
+            args,
+        ) {
+            Ok(Ok(val)) => val.try_into_val(e).ok(),
+            _ => None,
+        }
+    }
+
+    /// Whether core reports `commitment_id` as breaking its rules.
+    ///
+    /// Reads `has_violations` from core's `get_violation_details`, which agrees
+    /// with `check_violations` but publishes no events. `false` when core is
+    /// unset or the call fails, so an unreachable core does not by itself mark a
+    /// commitment non-compliant.
+    fn read_core_violated(e: &Env, commitment_id: &String) -> bool {
+        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
+            Some(address) => address,
+            None => return false,
+        };
+
+        let mut args = Vec::new(e);
+        args.push_back(commitment_id.clone().into_val(e));
+        match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &commitment_core,
+            &Symbol::new(e, "get_violation_details"),
+            args,
+        ) {
+            Ok(Ok(val)) => val
+                .try_into_val(e)
+                .ok()
+                .and_then(|details: Map<Symbol, Val>| details.get(Symbol::new(e, "has_violations")))
+                .and_then(|flag| flag.try_into_val(e).ok())
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// Read core's `max_value_age_secs`; `0` (disabled) if core is unset or the call fails.
+    fn read_core_max_value_age(e: &Env) -> u64 {
+        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
+            Some(address) => address,
+            None => return 0,
+        };
+
+        match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &commitment_core,
+            &Symbol::new(e, "get_max_value_age"),
+            Vec::new(e),
+        ) {
+            Ok(Ok(val)) => val.try_into_val(e).unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    /// Read core's `(timestamp, value)` snapshots; empty if core is unset or the call fails.
+    fn read_core_value_history(e: &Env, commitment_id: &String) -> Vec<(u64, i128)> {
+        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
+            Some(address) => address,
+            None => return Vec::new(e),
+        };
+
+        let mut args = Vec::new(e);
+        args.push_back(commitment_id.clone().into_val(e));
+        args.push_back(u32::MAX.into_val(e));
+        match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &commitment_core,
+            &Symbol::new(e, "get_value_history"),
+            args,
+        ) {
+            Ok(Ok(val)) => val.try_into_val(e).unwrap_or_else(|_| Vec::new(e)),
+            _ => Vec::new(e),
+        }
+    }
+
+    /// Realized volatility in bps: the mean of `|v[i] - v[i-1]| * 10_000 / v[i-1]`
+    /// over consecutive snapshots. Pairs whose earlier value is not positive are
+    /// skipped; `None` if no pair qualifies.
+    fn realized_volatility_bps(history: &Vec<(u64, i128)>) -> Option<i128> {
+        let mut total = 0i128;
+        let mut pairs = 0i128;
+        let mut previous: Option<i128> = None;
+        for (_, value) in history.iter() {
+            if let Some(prev) = previous.filter(|prev| *prev > 0) {
+                if let Some(change_bps) = Self::absolute_difference(value, prev)
+                    .and_then(|delta| delta.checked_mul(10_000))
+                    .map(|scaled| scaled / prev)
+                {
+                    total = total.saturating_add(change_bps);
+                    pairs += 1;
+                }
+            }
+            previous = Some(value);
+        }
+        if pairs == 0 {
+            None
+        } else {
+            Some(total / pairs)
+        }
+    }
+
+    /// Volatility exposure for `commitment_id`, by precedence:
+    /// 1. realized volatility from core's value history (`realized_volatility_bps`);
+    /// 2. the latest verifier-reported `volatility` attestation;
+    /// 3. the mean absolute change between consecutive drawdown attestations.
+    fn volatility_exposure(
+        e: &Env,
+        commitment_id: &String,
+        aggregates: &AttestationMetricAggregate,
+    ) -> i128 {
+        let history = Self::read_core_value_history(e, commitment_id);
+        Self::realized_volatility_bps(&history)
+            .or(aggregates.latest_volatility_bps)
+            .unwrap_or(aggregates.volatility_exposure)
+            .max(0)
+    }
+
+    /// Score points lost to volatility: one per whole percent above the configured
+    /// threshold. Zero while no threshold is set.
+    fn volatility_penalty(e: &Env, volatility_bps: i128) -> u32 {
+        let threshold = Self::get_volatility_threshold(e.clone());
+        if threshold <= 0 || volatility_bps <= threshold {
+            return 0;
+        }
+        let points = (volatility_bps - threshold) / 100;
+        points.min(100) as u32
+    }
+
     // ========================================================================
     // Health Metrics Update
     // ========================================================================
@@ -658,30 +996,30 @@ impl AttestationEngineContract {
                     current_value: 0,
                     initial_value: 0,
                     drawdown_percent: 0,
+                    drawdown_bps: 0,
+                    max_drawdown_percent: 0,
                     fees_generated: 0,
                     volatility_exposure: 0,
                     last_attestation: 0,
                     compliance_score: 100,
                 });
 
-        let attestation_key = DataKey::Attestations(commitment_id.clone());
-        let attestations: Vec<Attestation> = e
-            .storage()
-            .persistent()
-            .get(&attestation_key)
-            .unwrap_or_else(|| Vec::new(e));
+        let attestations = Self::load_attestations_from_storage(e, commitment_id);
         let aggregates = Self::aggregate_attestation_metrics(e, &attestations);
 
         metrics.last_attestation = aggregates.last_attestation;
         metrics.fees_generated = aggregates.fees_generated;
-        metrics.volatility_exposure = aggregates.volatility_exposure;
-        if let Some(drawdown_percent) = aggregates.latest_drawdown_percent {
-            metrics.drawdown_percent = drawdown_percent;
+        metrics.volatility_exposure = Self::volatility_exposure(e, commitment_id, &aggregates);
+        if let Some(drawdown_bps) = aggregates.latest_drawdown_bps {
+            metrics.drawdown_bps = drawdown_bps.max(0);
+            metrics.drawdown_percent = metrics.drawdown_bps / 100;
         }
+        metrics.max_drawdown_percent = metrics
+            .max_drawdown_percent
+            .max(aggregates.max_drawdown_bps / 100);
 
         // Update type-specific metrics that depend on the latest attestation.
         let fee_generation = String::from_str(e, "fee_generation");
-        let violation = String::from_str(e, "violation");
 
         if attestation.attestation_type == fee_generation {
             let fee_amount_key = String::from_str(e, "fee_amount");
@@ -693,37 +1031,129 @@ impl AttestationEngineContract {
                     e.storage().instance().set(&DataKey::TotalFees, &new_total);
                 }
             }
-        } else if attestation.attestation_type == violation {
-            // Decrease compliance score for violations
+        }
+
+        metrics.compliance_score = Self::score_after(e, metrics.compliance_score, attestation);
+
+        // Store updated metrics
+        e.storage().persistent().set(&key, &metrics);
+    }
+
+    /// `score` adjusted for one newly recorded attestation.
+    fn score_after(e: &Env, score: u32, attestation: &Attestation) -> u32 {
+        let weight = Self::attestation_weight(e, &attestation.attestation_type);
+        if attestation.attestation_type == String::from_str(e, "violation") {
+            // Decrease compliance score for violations: high = 1.5x, medium = 1x,
+            // low = 0.5x the configured violation weight.
             let severity_key = String::from_str(e, "severity");
             let penalty = if let Some(severity) = attestation.data.get(severity_key) {
                 let high = String::from_str(e, "high");
                 let medium = String::from_str(e, "medium");
                 if severity == high {
-                    30u32
+                    weight.saturating_mul(3) / 2
                 } else if severity == medium {
-                    20u32
+                    weight
                 } else {
-                    10u32
+                    weight / 2
                 }
             } else {
-                20u32 // Default penalty
+                weight // Default penalty
             };
 
-            metrics.compliance_score = metrics.compliance_score.saturating_sub(penalty);
+            score.saturating_sub(penalty)
+        } else if attestation.is_compliant {
+            // Bonus for compliant attestations, capped at 100
+            core::cmp::min(100, score.saturating_add(weight))
+        } else {
+            score
         }
+    }
 
-        // Compliance bonus for compliant attestations
-        if attestation.is_compliant && attestation.attestation_type != violation {
-            // Small bonus for compliant attestations, capped at 100
-            metrics.compliance_score =
-                core::cmp::min(100, metrics.compliance_score.saturating_add(1));
-        }
+    /// Recompute cached metrics from the unrevoked attestations, replaying the
+    /// score from 100 in recording order. No-op if nothing is cached yet.
+    fn rebuild_health_metrics(e: &Env, commitment_id: &String, attestations: &Vec<Attestation>) {
+        let key = DataKey::HealthMetrics(commitment_id.clone());
+        let mut metrics: HealthMetrics = match e.storage().persistent().get(&key) {
+            Some(metrics) => metrics,
+            None => return,
+        };
+
+        let aggregates = Self::aggregate_attestation_metrics(e, attestations);
+        metrics.last_attestation = aggregates.last_attestation;
+        metrics.fees_generated = aggregates.fees_generated;
+        metrics.volatility_exposure = Self::volatility_exposure(e, commitment_id, &aggregates);
+        let drawdown_bps = aggregates.latest_drawdown_bps.unwrap_or(0).max(0);
+        metrics.drawdown_bps = drawdown_bps;
+        metrics.drawdown_percent = drawdown_bps / 100;
+        metrics.max_drawdown_percent = aggregates.max_drawdown_bps / 100;
+        metrics.compliance_score = attestations
+            .iter()
+            .filter(|att| !att.is_revoked)
+            .fold(100, |score, att| Self::score_after(e, score, &att));
 
-        // Store updated metrics
         e.storage().persistent().set(&key, &metrics);
     }
 
+    /// Fee amount `attestation` added to `TotalFees` when it was recorded.
+    fn counted_fee(e: &Env, attestation: &Attestation) -> i128 {
+        if attestation.attestation_type != String::from_str(e, "fee_generation") {
+            return 0;
+        }
+        attestation
+            .data
+            .get(String::from_str(e, "fee_amount"))
+            .and_then(|fee_str| Self::parse_i128_from_string(e, &fee_str))
+            .unwrap_or(0)
+    }
+
+    /// Whether `attestation` added one to `TotalViolations` when it was recorded.
+    fn counted_violation(e: &Env, attestation: &Attestation) -> bool {
+        attestation.attestation_type == String::from_str(e, "violation")
+            || !attestation.is_compliant
+    }
+
+    /// Move the global `TotalFees` and `TotalViolations` off `previous` and onto
+    /// `replacement` (if any), so a rewritten or revoked attestation is not counted twice.
+    fn replace_global_totals(e: &Env, previous: &Attestation, replacement: Option<&Attestation>) {
+        let total_fees: i128 = e.storage().instance().get(&DataKey::TotalFees).unwrap_or(0);
+        let replacement_fee = replacement.map_or(0, |att| Self::counted_fee(e, att));
+        let new_fees = total_fees
+            .checked_sub(Self::counted_fee(e, previous))
+            .and_then(|fees| fees.checked_add(replacement_fee))
+            .unwrap_or(total_fees);
+        e.storage().instance().set(&DataKey::TotalFees, &new_fees);
+
+        let total_violations: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalViolations)
+            .unwrap_or(0);
+        let mut new_violations = total_violations;
+        if Self::counted_violation(e, previous) {
+            new_violations = new_violations.saturating_sub(1);
+        }
+        if replacement.is_some_and(|att| Self::counted_violation(e, att)) {
+            new_violations = new_violations.saturating_add(1);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::TotalViolations, &new_violations);
+    }
+
+    /// Configured score impact for `attestation_type`, falling back to the defaults.
+    fn attestation_weight(e: &Env, attestation_type: &String) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::AttestationWeight(attestation_type.clone()))
+            .unwrap_or_else(|| {
+                if *attestation_type == String::from_str(e, "violation") {
+                    DEFAULT_VIOLATION_WEIGHT
+                } else {
+                    DEFAULT_COMPLIANT_WEIGHT
+                }
+            })
+    }
+
     fn aggregate_attestation_metrics(
         e: &Env,
         attestations: &Vec<Attestation>,
@@ -732,14 +1162,22 @@ impl AttestationEngineContract {
         let drawdown_type = String::from_str(e, "drawdown");
         let fee_amount_key = String::from_str(e, "fee_amount");
         let drawdown_percent_key = String::from_str(e, "drawdown_percent");
+        let drawdown_bps_key = String::from_str(e, "drawdown_bps");
+        let volatility_type = String::from_str(e, "volatility");
+        let volatility_bps_key = String::from_str(e, "volatility_bps");
 
         let mut fees_generated = 0i128;
-        let mut latest_drawdown_percent = None;
-        let mut previous_drawdown_percent = None;
-        let mut volatility_exposure = 0i128;
+        let mut latest_drawdown_bps = None;
+        let mut max_drawdown_bps = 0i128;
+        let mut drawdown_change_bps = 0i128;
+        let mut drawdown_changes = 0i128;
+        let mut latest_volatility_bps = None;
         let mut last_attestation = 0u64;
 
         for attestation in attestations.iter() {
+            if attestation.is_revoked {
+                continue;
+            }
             if attestation.timestamp > last_attestation {
                 last_attestation = attestation.timestamp;
             }
@@ -755,22 +1193,37 @@ impl AttestationEngineContract {
                 continue;
             }
 
+            if attestation.attestation_type == volatility_type {
+                if let Some(bps) = attestation
+                    .data
+                    .get(volatility_bps_key.clone())
+                    .and_then(|bps_str| Self::parse_i128_from_string(e, &bps_str))
+                {
+                    latest_volatility_bps = Some(bps.max(0));
+                }
+                continue;
+            }
+
             if attestation.attestation_type == drawdown_type {
                 if let Some(drawdown_str) = attestation.data.get(drawdown_percent_key.clone()) {
                     if let Some(drawdown_percent) = Self::parse_i128_from_string(e, &drawdown_str)
                     {
-                        if let Some(previous) = previous_drawdown_percent {
-                            if let Some(delta) =
-                                Self::absolute_difference(drawdown_percent, previous)
+                        let previous_drawdown_bps = latest_drawdown_bps;
+                        // Prefer the precise bps value when the attestation carries one.
+                        latest_drawdown_bps = attestation
+                            .data
+                            .get(drawdown_bps_key.clone())
+                            .and_then(|bps_str| Self::parse_i128_from_string(e, &bps_str))
+                            .or_else(|| drawdown_percent.checked_mul(100));
+                        if let Some(bps) = latest_drawdown_bps {
+                            max_drawdown_bps = max_drawdown_bps.max(bps);
+                            if let Some(delta) = previous_drawdown_bps
+                                .and_then(|previous| Self::absolute_difference(bps, previous))
                             {
-                                volatility_exposure = volatility_exposure
-                                    .checked_add(delta)
-                                    .unwrap_or(volatility_exposure);
+                                drawdown_change_bps = drawdown_change_bps.saturating_add(delta);
+                                drawdown_changes += 1;
                             }
                         }
-
-                        previous_drawdown_percent = Some(drawdown_percent);
-                        latest_drawdown_percent = Some(drawdown_percent);
                     }
                 }
             }
@@ -778,12 +1231,48 @@ impl AttestationEngineContract {
 
         AttestationMetricAggregate {
             fees_generated,
-            latest_drawdown_percent,
-            volatility_exposure,
+            latest_drawdown_bps,
+            max_drawdown_bps,
+            volatility_exposure: if drawdown_changes == 0 {
+                0
+            } else {
+                drawdown_change_bps / drawdown_changes
+            },
+            latest_volatility_bps,
             last_attestation,
         }
     }
 
+    /// Signed drawdown of `current_value` relative to `initial_value`, in basis points.
+    /// Negative when the commitment has appreciated, which can never exceed a loss
+    /// threshold. Returns 0 when `initial_value` is not positive or the math overflows.
+    fn drawdown_bps_from_values(initial_value: i128, current_value: i128) -> i128 {
+        if initial_value <= 0 {
+            return 0;
+        }
+        initial_value
+            .checked_sub(current_value)
+            .and_then(|diff| diff.checked_mul(10_000))
+            .and_then(|scaled| scaled.checked_div(initial_value))
+            .unwrap_or(0)
+    }
+
+    /// Drawdown as reported by health metrics and the compliance score, in basis points.
+    ///
+    /// The latest attested drawdown wins, falling back to core's values. Unlike the
+    /// signed figure used for violation checks, a gain is clamped to 0 here so
+    /// appreciation reads as "no drawdown" on the 0-100 compliance scale.
+    fn compliance_drawdown_bps(
+        aggregates: &AttestationMetricAggregate,
+        initial_value: i128,
+        current_value: i128,
+    ) -> i128 {
+        aggregates
+            .latest_drawdown_bps
+            .unwrap_or_else(|| Self::drawdown_bps_from_values(initial_value, current_value))
+            .max(0)
+    }
+
     fn absolute_difference(left: i128, right: i128) -> Option<i128> {
         if left >= right {
             left.checked_sub(right)
@@ -894,7 +1383,8 @@ impl AttestationEngineContract {
             attestation_type,
             data,
             is_compliant,
-        );
+        )
+        .map(|_| ());
 
         // Clear reentrancy guard regardless of outcome
         e.storage().instance().remove(&DataKey::ReentrancyGuard);
@@ -902,15 +1392,34 @@ impl AttestationEngineContract {
         result
     }
 
-    /// Internal helper: persist an attestation record, update counters, and emit event.
-    /// Callers are responsible for auth and reentrancy guard management.
-    fn write_attestation(
+    /// Append `commitment_id` to the attested-commitments index. Callers invoke this
+    /// only on a commitment's first attestation.
+    fn index_attested_commitment(e: &Env, commitment_id: &String) {
+        let count: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::AttestedCommitmentCount)
+            .unwrap_or(0);
+        let chunk_key = DataKey::AllCommitmentIdsWithAttestations(count / ID_INDEX_CHUNK_SIZE);
+        let mut chunk: Vec<String> = e
+            .storage()
+            .persistent()
+            .get(&chunk_key)
+            .unwrap_or_else(|| Vec::new(e));
+        chunk.push_back(commitment_id.clone());
+        e.storage().persistent().set(&chunk_key, &chunk);
+        Storage::extend_persistent_ttl(e, &chunk_key);
+        e.storage()
+            .instance()
+            .set(&DataKey::AttestedCommitmentCount, &(count + 1));
+    }
+
+    /// Validate the commitment, attestation type and data shared by every write path.
+    fn validate_attestation_input(
         e: &Env,
-        caller: &Address,
-        commitment_id: String,
-        attestation_type: String,
-        data: Map<String, String>,
-        is_compliant: bool,
+        commitment_id: &String,
+        attestation_type: &String,
+        data: &Map<String, String>,
     ) -> Result<(), AttestationError> {
         // 4. Validate commitment_id is not empty
         if commitment_id.len() == 0 {
@@ -918,21 +1427,61 @@ impl AttestationEngineContract {
         }
 
         // 5. Validate commitment exists in core contract
-        if !Self::commitment_exists(e, &commitment_id) {
+        if !Self::commitment_exists(e, commitment_id) {
             return Err(AttestationError::CommitmentNotFound);
         }
 
         // 6. Validate attestation type
-        if !Self::is_valid_attestation_type(e, &attestation_type) {
+        if !Self::is_valid_attestation_type(e, attestation_type) {
             return Err(AttestationError::InvalidAttestationType);
         }
 
         // 7. Validate data format for the attestation type
-        if !Self::validate_attestation_data(e, &attestation_type, &data) {
+        if !Self::validate_attestation_data(e, attestation_type, data) {
             return Err(AttestationError::InvalidAttestationData);
         }
 
-        // 7b. Collect attestation verification fee if configured
+        Ok(())
+    }
+
+    /// Compliance flag to store for an attestation.
+    ///
+    /// A verifier can only downgrade the result: `"violation"` is always
+    /// non-compliant, and a `"drawdown"` past the commitment's `max_loss_percent`
+    /// (`drawdown_bps` if present, else `drawdown_percent`) is non-compliant.
+    /// Otherwise the caller's `is_compliant` is kept, including when core's rules
+    /// cannot be read.
+    fn derive_compliance(
+        e: &Env,
+        commitment_id: &String,
+        attestation_type: &String,
+        data: &Map<String, String>,
+        is_compliant: bool,
+    ) -> bool {
+        if *attestation_type == String::from_str(e, "violation") {
+            return false;
+        }
+        if !is_compliant || *attestation_type != String::from_str(e, "drawdown") {
+            return is_compliant;
+        }
+
+        let drawdown_bps = match data.get(String::from_str(e, "drawdown_bps")) {
+            Some(bps) => Self::parse_i128_from_string(e, &bps),
+            None => data
+                .get(String::from_str(e, "drawdown_percent"))
+                .and_then(|percent| Self::parse_i128_from_string(e, &percent))
+                .and_then(|percent| percent.checked_mul(100)),
+        };
+        match (drawdown_bps, Self::read_core_commitment(e, commitment_id)) {
+            (Some(bps), Some(commitment)) => {
+                bps <= (commitment.rules.max_loss_percent as i128) * 100
+            }
+            _ => true,
+        }
+    }
+
+    /// Collect the attestation verification fee from `caller`, if one is configured.
+    fn collect_attestation_fee(e: &Env, caller: &Address) -> Result<(), AttestationError> {
         let fee_amount: i128 = e
             .storage()
             .instance()
@@ -955,38 +1504,242 @@ impl AttestationEngineContract {
                 e.storage().instance().set(&key, &new_total);
             }
         }
+        Ok(())
+    }
 
-        // 8. Create attestation record
-        let timestamp = e.ledger().timestamp();
-        let attestation = Attestation {
-            commitment_id: commitment_id.clone(),
-            timestamp,
-            attestation_type: attestation_type.clone(),
-            data,
-            is_compliant,
-            verified_by: caller.clone(),
-        };
+    /// Whether one more signature on the violation `data` reaches `get_required_signers`.
+    fn completes_quorum(e: &Env, commitment_id: &String, data: &Map<String, String>) -> bool {
+        let required = Self::get_required_signers(e.clone());
+        if required <= 1 {
+            return true;
+        }
+        Self::prune_expired_pending(e, commitment_id);
+        let pending: Vec<PendingViolation> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingViolations(commitment_id.clone()))
+            .unwrap_or_else(|| Vec::new(e));
+        let signed = pending
+            .iter()
+            .find(|p| p.data == *data && !p.is_compliant)
+            .map_or(0, |p| p.signers.len());
+        signed + 1 >= required
+    }
+
+    /// Add `caller` as a signer of the matching pending violation. Returns `Ok(true)`
+    /// while more signers are needed; `Ok(false)` once the attestation should be stored.
+    fn awaiting_cosigners(
+        e: &Env,
+        caller: &Address,
+        commitment_id: &String,
+        attestation_type: &String,
+        data: &Map<String, String>,
+        is_compliant: bool,
+    ) -> Result<bool, AttestationError> {
+        let required = Self::get_required_signers(e.clone());
+        if required <= 1 || *attestation_type != String::from_str(e, "violation") {
+            return Ok(false);
+        }
 
-        // 9. Store attestation in commitment's list
-        let key = DataKey::Attestations(commitment_id.clone());
-        let mut attestations: Vec<Attestation> = e
+        let key = DataKey::PendingViolations(commitment_id.clone());
+        let mut pending: Vec<PendingViolation> = e
             .storage()
             .persistent()
             .get(&key)
             .unwrap_or_else(|| Vec::new(e));
+        let index = (0..pending.len()).find(|i| {
+            let p = pending.get_unchecked(*i);
+            p.data == *data && p.is_compliant == is_compliant
+        });
+        if index.is_none() && pending.len() >= MAX_PENDING_VIOLATIONS {
+            return Err(AttestationError::TooManyPendingAttestations);
+        }
+        let mut entry = match index {
+            Some(i) => pending.get_unchecked(i),
+            None => PendingViolation {
+                data: data.clone(),
+                is_compliant,
+                signers: Vec::new(e),
+                created_at: e.ledger().timestamp(),
+            },
+        };
+        if entry.signers.contains(caller) {
+            return Err(AttestationError::AlreadySigned);
+        }
+        entry.signers.push_back(caller.clone());
+        let signed = entry.signers.len();
 
-        attestations.push_back(attestation.clone());
-        e.storage().persistent().set(&key, &attestations);
+        if signed >= required {
+            if let Some(i) = index {
+                pending.remove(i);
+            }
+        } else {
+            match index {
+                Some(i) => pending.set(i, entry),
+                None => pending.push_back(entry),
+            }
+        }
+        if pending.is_empty() {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &pending);
+        }
 
-        // 10. Update health metrics
-        Self::update_health_metrics(e, &commitment_id, &attestation);
+        if signed >= required {
+            return Ok(false);
+        }
+        e.events().publish(
+            (
+                Symbol::new(e, "ViolationPending"),
+                commitment_id.clone(),
+                caller.clone(),
+            ),
+            (
+                commitment_id.clone(),
+                signed,
+                required,
+                e.ledger().timestamp(),
+            ),
+        );
+        Ok(true)
+    }
 
-        // 11. Increment attestation counter
-        let counter_key = DataKey::AttestationCounter(commitment_id.clone());
-        let counter: u64 = e.storage().persistent().get(&counter_key).unwrap_or(0);
-        e.storage().persistent().set(&counter_key, &(counter + 1));
+    /// Drop pending violations older than the TTL, emitting `PendingExpired` for each.
+    fn prune_expired_pending(e: &Env, commitment_id: &String) {
+        let ttl = Self::get_pending_ttl(e.clone());
+        let key = DataKey::PendingViolations(commitment_id.clone());
+        if ttl == 0 || !e.storage().persistent().has(&key) {
+            return;
+        }
+        let pending: Vec<PendingViolation> = e.storage().persistent().get(&key).unwrap();
+        let now = e.ledger().timestamp();
+        let mut kept = Vec::new(e);
+        for entry in pending.iter() {
+            if now.saturating_sub(entry.created_at) > ttl {
+                e.events().publish(
+                    (Symbol::new(e, "PendingExpired"), commitment_id.clone()),
+                    (entry.signers.len(), entry.created_at, now),
+                );
+            } else {
+                kept.push_back(entry);
+            }
+        }
+        if kept.len() == pending.len() {
+            return;
+        }
+        if kept.is_empty() {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &kept);
+        }
+    }
 
-        // 11b. Batch update analytics counters
+    /// Set how long a pending violation may wait for co-signers before the next
+    /// attestation on its commitment drops it (admin only). `0` disables expiry.
+    pub fn set_pending_ttl(e: Env, caller: Address, ttl_secs: u64) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::PendingTtlSecs, &ttl_secs);
+        Ok(())
+    }
+
+    /// Lifetime of a pending violation in seconds; defaults to `DEFAULT_PENDING_TTL_SECS`.
+    pub fn get_pending_ttl(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::PendingTtlSecs)
+            .unwrap_or(DEFAULT_PENDING_TTL_SECS)
+    }
+
+    /// Set how many distinct verifiers must attest a violation before it is stored
+    /// and affects scoring (admin only). Values of 0 or 1 disable co-signing.
+    pub fn set_required_signers(
+        e: Env,
+        caller: Address,
+        required_signers: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::RequiredSigners, &required_signers);
+        Ok(())
+    }
+
+    /// Distinct verifiers required to activate a violation attestation.
+    pub fn get_required_signers(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::RequiredSigners)
+            .unwrap_or(1)
+    }
+
+    /// Violation attestations for `commitment_id` still waiting for co-signers.
+    pub fn get_pending_violations(e: Env, commitment_id: String) -> Vec<PendingViolation> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::PendingViolations(commitment_id))
+            .unwrap_or_else(|| Vec::new(&e))
+    }
+
+    /// Internal helper: persist an attestation record, update counters, and emit event.
+    /// Callers are responsible for auth and reentrancy guard management.
+    ///
+    /// Returns `Ok(false)` when a violation was only added to the pending
+    /// co-signer set (see `set_required_signers`) and nothing was stored yet.
+    fn write_attestation(
+        e: &Env,
+        caller: &Address,
+        commitment_id: String,
+        attestation_type: String,
+        data: Map<String, String>,
+        is_compliant: bool,
+    ) -> Result<bool, AttestationError> {
+        Self::validate_attestation_input(e, &commitment_id, &attestation_type, &data)?;
+        let is_compliant =
+            Self::derive_compliance(e, &commitment_id, &attestation_type, &data, is_compliant);
+
+        // 7. Violations may need co-signers before they take effect
+        Self::prune_expired_pending(e, &commitment_id);
+        if Self::awaiting_cosigners(
+            e,
+            caller,
+            &commitment_id,
+            &attestation_type,
+            &data,
+            is_compliant,
+        )? {
+            return Ok(false);
+        }
+
+        // 7a. Keep the per-commitment list iterable
+        if Self::stored_attestation_count(e, &commitment_id) >= MAX_ATTESTATIONS_PER_COMMITMENT {
+            return Err(AttestationError::TooManyAttestations);
+        }
+
+        // 7b. Collect attestation verification fee if configured
+        Self::collect_attestation_fee(e, caller)?;
+
+        // 8. Create attestation record
+        let timestamp = e.ledger().timestamp();
+        let attestation = Attestation {
+            commitment_id: commitment_id.clone(),
+            timestamp,
+            attestation_type: attestation_type.clone(),
+            data,
+            is_compliant,
+            verified_by: caller.clone(),
+            is_revoked: false,
+        };
+
+        // 9. Store attestation in commitment's list and bump its counter
+        Self::append_attestation(e, &commitment_id, &attestation)?;
+
+        // 10. Update health metrics
+        Self::update_health_metrics(e, &commitment_id, &attestation);
+        extend_attestation_ttl(e, &commitment_id);
+
+        // 11b. Batch update analytics counters
         let total_att: u64 = e
             .storage()
             .instance()
@@ -1019,17 +1772,21 @@ impl AttestationEngineContract {
         e.events().publish(
             (
                 Symbol::new(e, "AttestationRecorded"),
-                commitment_id,
+                commitment_id.clone(),
                 caller.clone(),
             ),
-            (attestation_type, is_compliant, timestamp),
+            (commitment_id, attestation_type, is_compliant, timestamp),
         );
 
-        Ok(())
+        Ok(true)
     }
 
 
     /// Record a single attestation. Caller must be an authorized verifier.
+    ///
+    /// The stored `is_compliant` is derived from the type and data: `"violation"`
+    /// is always `false`, and a `"drawdown"` beyond the commitment's
+    /// `max_loss_percent` is `false` regardless of the flag passed in.
     pub fn attest(
         e: Env,
         caller: Address,
@@ -1049,24 +1806,319 @@ impl AttestationEngineContract {
         )
     }
 
-    /// Load the full attestation vector from storage (internal use only).
-    fn load_attestations_from_storage(e: &Env, commitment_id: &String) -> Vec<Attestation> {
-        let key = DataKey::Attestations(commitment_id.clone());
+    /// Record an attestation, correcting the caller's latest one of the same type if
+    /// it is still within the upsert window.
+    ///
+    /// When the most recent `attestation_type` attestation for `commitment_id` was
+    /// made by `caller` no more than `get_upsert_window` seconds ago, it is replaced
+    /// in place and `AttestationUpdated` is emitted. Otherwise this behaves exactly
+    /// like `attest` and appends. Replacing does not change the attestation counters;
+    /// health metrics are rebuilt and `TotalFees`/`TotalViolations` swap the old
+    /// attestation's contribution for the new one's.
+    pub fn upsert_attestation(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        attestation_type: String,
+        data: Map<String, String>,
+        is_compliant: bool,
+    ) -> Result<(), AttestationError> {
+        caller.require_auth();
+
+        if e.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+        Pausable::require_not_paused(&e);
+
+        if !Self::is_authorized_verifier(&e, &caller) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(AttestationError::Unauthorized);
+        }
+        RateLimiter::check(&e, &caller, &Symbol::new(&e, "attest"));
+
+        let result = match Self::replace_recent_attestation(
+            &e,
+            &caller,
+            &commitment_id,
+            &attestation_type,
+            &data,
+            is_compliant,
+        ) {
+            Ok(true) => Ok(()),
+            Ok(false) => Self::write_attestation(
+                &e,
+                &caller,
+                commitment_id,
+                attestation_type,
+                data,
+                is_compliant,
+            )
+            .map(|_| ()),
+            Err(err) => Err(err),
+        };
+
+        e.storage().instance().remove(&DataKey::ReentrancyGuard);
+        result
+    }
+
+    /// Replace the caller's latest `attestation_type` attestation if it is inside the
+    /// upsert window. Returns `Ok(false)` when there is nothing eligible to replace.
+    fn replace_recent_attestation(
+        e: &Env,
+        caller: &Address,
+        commitment_id: &String,
+        attestation_type: &String,
+        data: &Map<String, String>,
+        is_compliant: bool,
+    ) -> Result<bool, AttestationError> {
+        let window = Self::get_upsert_window(e.clone());
+        if window == 0 {
+            return Ok(false);
+        }
+        // A co-signed violation must not be rewritten by a single verifier.
+        if Self::get_required_signers(e.clone()) > 1
+            && *attestation_type == String::from_str(e, "violation")
+        {
+            return Ok(false);
+        }
+        Self::validate_attestation_input(e, commitment_id, attestation_type, data)?;
+        let is_compliant =
+            Self::derive_compliance(e, commitment_id, attestation_type, data, is_compliant);
+
+        let mut attestations = Self::load_attestations_from_storage(e, commitment_id);
+        let index = match (0..attestations.len())
+            .rev()
+            .find(|i| attestations.get_unchecked(*i).attestation_type == *attestation_type)
+        {
+            Some(index) => index,
+            None => return Ok(false),
+        };
+        let previous = attestations.get_unchecked(index);
+        let now = e.ledger().timestamp();
+        if previous.verified_by != *caller
+            || previous.is_revoked
+            || now.saturating_sub(previous.timestamp) > window
+        {
+            return Ok(false);
+        }
+
+        Self::collect_attestation_fee(e, caller)?;
+
+        let attestation = Attestation {
+            commitment_id: commitment_id.clone(),
+            timestamp: now,
+            attestation_type: attestation_type.clone(),
+            data: data.clone(),
+            is_compliant,
+            verified_by: caller.clone(),
+            is_revoked: false,
+        };
+        attestations.set(index, attestation.clone());
+        Self::store_attestation_at(e, commitment_id, index, &attestation);
+        Self::rebuild_health_metrics(e, commitment_id, &attestations);
+        Self::replace_global_totals(e, &previous, Some(&attestation));
+        extend_attestation_ttl(e, commitment_id);
+
+        e.events().publish(
+            (
+                Symbol::new(e, "AttestationUpdated"),
+                commitment_id.clone(),
+                caller.clone(),
+            ),
+            (
+                commitment_id.clone(),
+                attestation_type.clone(),
+                is_compliant,
+                now,
+            ),
+        );
+        Ok(true)
+    }
+
+    /// Set how long after an attestation `upsert_attestation` may replace it (admin only).
+    /// `0` (the default) makes every upsert append.
+    pub fn set_upsert_window(
+        e: Env,
+        caller: Address,
+        window_secs: u64,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::UpsertWindow, &window_secs);
+        Ok(())
+    }
+
+    /// Window in seconds during which `upsert_attestation` replaces instead of appending.
+    pub fn get_upsert_window(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::UpsertWindow)
+            .unwrap_or(0)
+    }
+
+    /// Move a list stored under the version 1 `DataKey::Attestations` layout into
+    /// `AttestationChunk` entries. The counter is reset to the list's length and the
+    /// commitment is added to the global index, which version 1 did not have.
+    fn rechunk_legacy_attestations(e: &Env, commitment_id: &String) {
+        let legacy_key = DataKey::Attestations(commitment_id.clone());
+        let legacy: Vec<LegacyAttestation> = match e.storage().persistent().get(&legacy_key) {
+            Some(legacy) => legacy,
+            None => return,
+        };
+        e.storage().persistent().remove(&legacy_key);
+
+        let mut chunk = Vec::new(e);
+        let mut chunk_index = 0;
+        for old in legacy.iter() {
+            chunk.push_back(Attestation {
+                commitment_id: old.commitment_id,
+                timestamp: old.timestamp,
+                attestation_type: old.attestation_type,
+                data: old.data,
+                is_compliant: old.is_compliant,
+                verified_by: old.verified_by,
+                is_revoked: false,
+            });
+            if chunk.len() == CHUNK_SIZE {
+                Self::write_rechunked(e, commitment_id, chunk_index, &chunk);
+                chunk = Vec::new(e);
+                chunk_index += 1;
+            }
+        }
+        if !chunk.is_empty() {
+            Self::write_rechunked(e, commitment_id, chunk_index, &chunk);
+        }
+
+        let counter_key = DataKey::AttestationCounter(commitment_id.clone());
         e.storage()
             .persistent()
-            .get(&key)
+            .set(&counter_key, &(legacy.len() as u64));
+        Storage::extend_persistent_ttl(e, &counter_key);
+        if !legacy.is_empty() {
+            Self::index_attested_commitment(e, commitment_id);
+        }
+    }
+
+    fn write_rechunked(
+        e: &Env,
+        commitment_id: &String,
+        chunk_index: u32,
+        chunk: &Vec<Attestation>,
+    ) {
+        let key = DataKey::AttestationChunk(commitment_id.clone(), chunk_index);
+        e.storage().persistent().set(&key, chunk);
+        Storage::extend_persistent_ttl(e, &key);
+    }
+
+    /// Number of attestations stored for `commitment_id`.
+    fn stored_attestation_count(e: &Env, commitment_id: &String) -> u32 {
+        Self::get_attestation_count(e.clone(), commitment_id.clone()) as u32
+    }
+
+    /// Chunk `chunk_index` of `commitment_id`'s attestations; empty if it does not exist.
+    fn load_attestation_chunk(
+        e: &Env,
+        commitment_id: &String,
+        chunk_index: u32,
+    ) -> Vec<Attestation> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::AttestationChunk(
+                commitment_id.clone(),
+                chunk_index,
+            ))
             .unwrap_or_else(|| Vec::new(e))
     }
 
-    /// Get attestations for a commitment (capped at [`MAX_PAGE_SIZE`]).
+    /// Attestations `start..end` (oldest first), reading only the chunks that hold them.
+    fn load_attestation_range(
+        e: &Env,
+        commitment_id: &String,
+        start: u32,
+        end: u32,
+    ) -> Vec<Attestation> {
+        let mut out = Vec::new(e);
+        if start >= end {
+            return out;
+        }
+        for chunk_index in (start / CHUNK_SIZE)..=((end - 1) / CHUNK_SIZE) {
+            let chunk = Self::load_attestation_chunk(e, commitment_id, chunk_index);
+            let chunk_start = chunk_index * CHUNK_SIZE;
+            let from = start.saturating_sub(chunk_start);
+            let to = (end - chunk_start).min(chunk.len());
+            for i in from..to {
+                out.push_back(chunk.get_unchecked(i));
+            }
+        }
+        out
+    }
+
+    /// Load the full attestation history from storage (internal use only).
+    fn load_attestations_from_storage(e: &Env, commitment_id: &String) -> Vec<Attestation> {
+        let count = Self::stored_attestation_count(e, commitment_id);
+        Self::load_attestation_range(e, commitment_id, 0, count)
+    }
+
+    /// Append `attestation` to the last chunk, starting a new chunk every `CHUNK_SIZE`
+    /// entries, and bump the commitment's counter. The first attestation also adds the
+    /// commitment to the global index.
+    fn append_attestation(
+        e: &Env,
+        commitment_id: &String,
+        attestation: &Attestation,
+    ) -> Result<(), AttestationError> {
+        let count = Self::stored_attestation_count(e, commitment_id);
+        if count >= MAX_ATTESTATIONS_PER_COMMITMENT {
+            return Err(AttestationError::TooManyAttestations);
+        }
+        if count == 0 {
+            Self::index_attested_commitment(e, commitment_id);
+        }
+        let chunk_index = count / CHUNK_SIZE;
+        let mut chunk = Self::load_attestation_chunk(e, commitment_id, chunk_index);
+        chunk.push_back(attestation.clone());
+        e.storage().persistent().set(
+            &DataKey::AttestationChunk(commitment_id.clone(), chunk_index),
+            &chunk,
+        );
+        e.storage().persistent().set(
+            &DataKey::AttestationCounter(commitment_id.clone()),
+            &(count as u64 + 1),
+        );
+        Ok(())
+    }
+
+    /// Overwrite the stored attestation at `index`; the caller checked it exists.
+    fn store_attestation_at(
+        e: &Env,
+        commitment_id: &String,
+        index: u32,
+        attestation: &Attestation,
+    ) {
+        let chunk_index = index / CHUNK_SIZE;
+        let mut chunk = Self::load_attestation_chunk(e, commitment_id, chunk_index);
+        chunk.set(index % CHUNK_SIZE, attestation.clone());
+        e.storage().persistent().set(
+            &DataKey::AttestationChunk(commitment_id.clone(), chunk_index),
+            &chunk,
+        );
+    }
+
+    /// Get the most recent attestations for a commitment (capped at [`MAX_PAGE_SIZE`]).
     ///
-    /// **Deprecated:** Returns at most [`MAX_PAGE_SIZE`] attestations. For commitments
-    /// with more attestations, use [`Self::get_attestations_page`] and iterate using
-    /// `next_offset` until it returns 0.
+    /// **Deprecated:** Returns only the latest [`MAX_PAGE_SIZE`] attestations so the
+    /// result always fits in a single return value. Callers that need the full history
+    /// must use [`Self::get_attestations_page`] and iterate using `next_offset` until it
+    /// returns 0.
     ///
-    /// Ordering is oldest-first by timestamp, consistent with [`AttestationsPage`].
+    /// Ordering within the result is oldest-first by timestamp, consistent with
+    /// [`AttestationsPage`].
     pub fn get_attestations(e: Env, commitment_id: String) -> Vec<Attestation> {
-        Self::get_attestations_page(e, commitment_id, 0, MAX_PAGE_SIZE).attestations
+        let count = Self::stored_attestation_count(&e, &commitment_id);
+        let start = count.saturating_sub(MAX_PAGE_SIZE);
+        Self::load_attestation_range(&e, &commitment_id, start, count)
     }
 
     /// Get a paginated list of attestations for a commitment (ordered by timestamp, oldest first).
@@ -1096,7 +2148,8 @@ impl AttestationEngineContract {
     /// # Trust Boundaries
     /// - Caller: Any address (public function)
     /// - Storage Reads:
-    ///   - Local: Attestations(commitment_id) - Full attestation vector
+    ///   - Local: AttestationCounter(commitment_id) and the AttestationChunk entries
+    ///     overlapping the page
     /// - Storage Writes: None
     ///
     /// # Error Handling
@@ -1107,8 +2160,8 @@ impl AttestationEngineContract {
     /// - No panic conditions - always returns valid AttestationsPage
     ///
     /// # Gas Considerations
-    /// - Single persistent storage read (loads entire attestation vector)
-    /// - Vector slicing operations (O(limit) complexity)
+    /// - Reads only the `CHUNK_SIZE` chunks that overlap the page
+    /// - Copying is O(limit)
     /// - Memory usage proportional to limit size
     /// - Recommended: Use reasonable page sizes (10-100 attestations)
     ///
@@ -1146,14 +2199,14 @@ impl AttestationEngineContract {
     /// - **Mobile applications**: Reduce payload sizes for better performance
     ///
     /// # Related Functions
-    /// - `get_attestations` - Convenience wrapper (capped at MAX_PAGE_SIZE; deprecated for large datasets)
+    /// - `get_attestations` - Latest MAX_PAGE_SIZE attestations only (deprecated for large datasets)
     /// - `get_attestation_count` - Get total count before pagination
     /// - `get_verifier_statistics` - Per-verifier attestation analytics
     ///
     /// # Storage Details
-    /// - Storage Key: DataKey::Attestations(commitment_id)
+    /// - Storage Key: DataKey::AttestationChunk(commitment_id, chunk_index)
     /// - Storage Type: Persistent storage
-    /// - Value Type: Vec<Attestation>
+    /// - Value Type: Vec<Attestation>, up to CHUNK_SIZE per chunk
     /// - Ordering: Chronological (oldest attestations first)
     /// - Pagination: Zero-based indexing with configurable page sizes
     pub fn get_attestations_page(
@@ -1162,15 +2215,8 @@ impl AttestationEngineContract {
         offset: u32,
         limit: u32,
     ) -> AttestationsPage {
-        let key = DataKey::Attestations(commitment_id.clone());
-        let all: Vec<Attestation> = e
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| Vec::new(&e));
-
         let cap = limit.min(MAX_PAGE_SIZE);
-        let len = all.len();
+        let len = Self::stored_attestation_count(&e, &commitment_id);
 
         if offset >= len || cap == 0 {
             return AttestationsPage {
@@ -1179,13 +2225,8 @@ impl AttestationEngineContract {
             };
         }
 
-        let end = (offset + cap).min(len);
-        let mut page = Vec::new(&e);
-        let mut i = offset;
-        while i < end {
-            page.push_back(all.get(i).unwrap());
-            i += 1;
-        }
+        let end = offset.saturating_add(cap).min(len);
+        let page = Self::load_attestation_range(&e, &commitment_id, offset, end);
         let next_offset = if end < len { end } else { 0 };
 
         AttestationsPage {
@@ -1194,6 +2235,125 @@ impl AttestationEngineContract {
         }
     }
 
+    /// Get the attestation at `index`, the same zero-based, oldest-first position
+    /// used by `get_attestations_page`. Indices are stable: attestations are only
+    /// appended or replaced in place.
+    pub fn get_attestation(
+        e: Env,
+        commitment_id: String,
+        index: u32,
+    ) -> Result<Attestation, AttestationError> {
+        if index >= Self::stored_attestation_count(&e, &commitment_id) {
+            return Err(AttestationError::AttestationIndexOutOfRange);
+        }
+        Self::load_attestation_chunk(&e, &commitment_id, index / CHUNK_SIZE)
+            .get(index % CHUNK_SIZE)
+            .ok_or(AttestationError::AttestationIndexOutOfRange)
+    }
+
+    /// Like `get_attestations`, but without revoked attestations.
+    pub fn get_unrevoked_attestations(e: Env, commitment_id: String) -> Vec<Attestation> {
+        Self::filter_attestations(&e, &commitment_id, |att| !att.is_revoked)
+    }
+
+    /// Revoke the attestation at `index` (oldest first) for a mistaken record.
+    ///
+    /// The attestation stays in storage with `is_revoked` set, so the trail remains
+    /// auditable, but it is excluded from health metrics and the compliance score;
+    /// cached metrics are rebuilt from the remaining attestations and its fee and
+    /// violation are taken back out of `TotalFees`/`TotalViolations`.
+    /// `TotalAttestations` stays a lifetime count.
+    ///
+    /// # Errors
+    /// - [`AttestationError::AttestationIndexOutOfRange`] if no attestation exists at `index`.
+    /// - [`AttestationError::Unauthorized`] if `caller` is neither the attestation's
+    ///   `verified_by` nor the admin.
+    /// - [`AttestationError::AttestationRevoked`] if it is already revoked.
+    pub fn revoke_attestation(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        index: u32,
+    ) -> Result<(), AttestationError> {
+        caller.require_auth();
+        Pausable::require_not_paused(&e);
+
+        let mut attestation = Self::get_attestation(e.clone(), commitment_id.clone(), index)?;
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(AttestationError::NotInitialized)?;
+        if caller != attestation.verified_by && caller != admin {
+            return Err(AttestationError::Unauthorized);
+        }
+        if attestation.is_revoked {
+            return Err(AttestationError::AttestationRevoked);
+        }
+
+        attestation.is_revoked = true;
+        Self::store_attestation_at(&e, &commitment_id, index, &attestation);
+        let attestations = Self::load_attestations_from_storage(&e, &commitment_id);
+        Self::rebuild_health_metrics(&e, &commitment_id, &attestations);
+        Self::replace_global_totals(&e, &attestation, None);
+
+        e.events().publish(
+            (
+                Symbol::new(&e, "AttestationRevoked"),
+                commitment_id.clone(),
+                caller,
+            ),
+            (commitment_id, index, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the attestations of one `attestation_type`, oldest first.
+    ///
+    /// Like `get_attestations`, at most the latest [`MAX_PAGE_SIZE`] matches are
+    /// returned; use `get_attestations_page` to walk the full history.
+    pub fn get_attestations_by_type(
+        e: Env,
+        commitment_id: String,
+        attestation_type: String,
+    ) -> Vec<Attestation> {
+        Self::filter_attestations(&e, &commitment_id, |att| {
+            att.attestation_type == attestation_type
+        })
+    }
+
+    /// Get the attestations recorded between `start_ts` and `end_ts` (both
+    /// inclusive), oldest first. Empty when nothing falls in the window.
+    ///
+    /// Like `get_attestations`, at most the latest [`MAX_PAGE_SIZE`] matches are
+    /// returned; narrow the window to see earlier ones.
+    pub fn get_attestations_in_range(
+        e: Env,
+        commitment_id: String,
+        start_ts: u64,
+        end_ts: u64,
+    ) -> Vec<Attestation> {
+        Self::filter_attestations(&e, &commitment_id, |att| {
+            att.timestamp >= start_ts && att.timestamp <= end_ts
+        })
+    }
+
+    /// Matching attestations in storage order, keeping the latest [`MAX_PAGE_SIZE`].
+    fn filter_attestations(
+        e: &Env,
+        commitment_id: &String,
+        matches: impl Fn(&Attestation) -> bool,
+    ) -> Vec<Attestation> {
+        let mut out = Vec::new(e);
+        for att in Self::load_attestations_from_storage(e, commitment_id).iter() {
+            if matches(&att) {
+                out.push_back(att);
+            }
+        }
+        let start = out.len().saturating_sub(MAX_PAGE_SIZE);
+        out.slice(start..)
+    }
+
     /// Get attestation count for a specific commitment.
     ///
     /// # Summary
@@ -1263,10 +2423,56 @@ impl AttestationEngineContract {
     /// - Initialization: Counter starts at 0, incremented per attestation
     /// - Persistence: Survives contract upgrades and migrations
     pub fn get_attestation_count(e: Env, commitment_id: String) -> u64 {
+        Self::rechunk_legacy_attestations(&e, &commitment_id);
         let key = DataKey::AttestationCounter(commitment_id);
         e.storage().persistent().get(&key).unwrap_or(0)
     }
 
+    /// Page over every commitment ID that has at least one attestation
+    /// (`DataKey::AllCommitmentIdsWithAttestations`).
+    ///
+    /// Intended for full compliance exports: page through IDs here, then fetch
+    /// each commitment's attestations with `get_attestations_page`. IDs are
+    /// returned in the order they were first attested; at most
+    /// `min(limit, MAX_PAGE_SIZE)` are returned per call, and only the index
+    /// chunks covering that range are loaded.
+    ///
+    /// (`get_commitment_ids_with_attestations` would exceed Soroban's 32-character
+    /// limit on contract function names.)
+    pub fn get_attested_commitment_ids(e: Env, offset: u32, limit: u32) -> Vec<String> {
+        let mut page = Vec::new(&e);
+        let len: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::AttestedCommitmentCount)
+            .unwrap_or(0);
+        let effective_limit = limit.min(MAX_PAGE_SIZE);
+        if offset >= len || effective_limit == 0 {
+            return page;
+        }
+        let end = offset.saturating_add(effective_limit).min(len);
+        for chunk_index in (offset / ID_INDEX_CHUNK_SIZE)..=((end - 1) / ID_INDEX_CHUNK_SIZE) {
+            let chunk: Vec<String> = e
+                .storage()
+                .persistent()
+                .get(&DataKey::AllCommitmentIdsWithAttestations(chunk_index))
+                .unwrap_or_else(|| Vec::new(&e));
+            let chunk_start = chunk_index * ID_INDEX_CHUNK_SIZE;
+            let from = offset.saturating_sub(chunk_start);
+            let to = (end - chunk_start).min(chunk.len());
+            for id in chunk.slice(from..to).iter() {
+                page.push_back(id);
+            }
+        }
+        page
+    }
+
+    /// Whether `commitment_id` has at least one recorded attestation, i.e. whether
+    /// `get_health_metrics` reflects real attestations rather than zeroed defaults.
+    pub fn has_health_metrics(e: Env, commitment_id: String) -> bool {
+        Self::stored_attestation_count(&e, &commitment_id) > 0
+    }
+
     /// Get current health metrics for a commitment.
     ///
     /// Summary:
@@ -1279,104 +2485,250 @@ impl AttestationEngineContract {
     ///
     /// Panics:
     /// - If the contract is not initialized.
-    /// - If `commitment_core` does not return a decodable `Commitment`.
+    ///
+    /// `volatility_exposure` is in basis points. It is the realized volatility of
+    /// core's value history when at least two snapshots exist: the mean of
+    /// `|v[i] - v[i-1]| * 10_000 / v[i-1]` over consecutive snapshots. Otherwise it is
+    /// the latest `record_volatility` report, and failing that the mean absolute
+    /// change between consecutive drawdown attestations. It is never negative.
+    /// Core snapshots `top_up` and `partial_withdraw` too, so each flow counts as one
+    /// step of the series.
+    ///
+    /// If `commitment_core` cannot return the commitment, value fields are
+    /// reported as 0 and only attestation-derived metrics are populated. Use
+    /// `has_health_metrics` to tell a never-attested commitment from one whose
+    /// attested metrics are genuinely zero.
     pub fn get_health_metrics(e: Env, commitment_id: String) -> HealthMetrics {
-        let commitment_core: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::CoreContract)
-            .unwrap_or_else(|| panic!("Contract not initialized"));
+        if !e.storage().instance().has(&DataKey::CoreContract) {
+            panic!("Contract not initialized");
+        }
 
-        let mut args = Vec::new(&e);
-        args.push_back(commitment_id.clone().into_val(&e));
-        let commitment_val: Val =
-            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
-        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
-
-        let initial_value = commitment.amount;
-        let current_value = commitment.current_value;
-        let drawdown_percent = if initial_value > 0 {
-            let diff = initial_value.checked_sub(current_value).unwrap_or(0);
-            diff.checked_mul(100)
-                .unwrap_or(0)
-                .checked_div(initial_value)
-                .unwrap_or(0)
-        } else {
-            0
-        };
+        let (initial_value, current_value, core_max_drawdown_percent) =
+            match Self::read_core_commitment(&e, &commitment_id) {
+                Some(commitment) => (
+                    commitment.amount,
+                    commitment.current_value,
+                    commitment.max_drawdown_percent as i128,
+                ),
+                None => (0, 0, 0),
+            };
 
         let attestations = Self::load_attestations_from_storage(&e, &commitment_id);
         let aggregates = Self::aggregate_attestation_metrics(&e, &attestations);
+        let drawdown_bps = Self::compliance_drawdown_bps(&aggregates, initial_value, current_value);
 
+        // Peak across core's value-update history, attested drawdowns and the current one.
+        let max_drawdown_percent = core_max_drawdown_percent
+            .max(aggregates.max_drawdown_bps / 100)
+            .max(drawdown_bps / 100);
+
+        let volatility_exposure = Self::volatility_exposure(&e, &commitment_id, &aggregates);
         let compliance_score = Self::calculate_compliance_score(e.clone(), commitment_id.clone());
 
         HealthMetrics {
             commitment_id,
             current_value,
             initial_value,
-            drawdown_percent: aggregates
-                .latest_drawdown_percent
-                .unwrap_or(drawdown_percent),
+            drawdown_percent: drawdown_bps / 100,
+            drawdown_bps,
+            max_drawdown_percent,
             fees_generated: aggregates.fees_generated,
-            volatility_exposure: aggregates.volatility_exposure,
+            volatility_exposure,
             last_attestation: aggregates.last_attestation,
             compliance_score,
         }
     }
 
-    /// Verify commitment compliance
     /// Verify commitment compliance
     ///
     /// Returns compliance status based on commitment state:
-    /// - "settled": true (compliant until settlement)
-    /// - "violated": false (rule violation occurred)
-    /// - "early_exit": false (exited before maturity)
-    /// - "active": checks current metrics against rules
+    /// - `Settled`: true (compliant until settlement)
+    /// - `Violated`: false (rule violation occurred)
+    /// - `EarlyExit`: false (exited before maturity)
+    /// - `Active`: false if core reports a rule violation (see `check_violations`), otherwise
+    ///   checks current metrics against rules (a commitment with no attestations yet
+    ///   is judged on core's values alone); a value older than core's
+    ///   `max_value_age_secs` also emits `ValueStale` without affecting the result
+    ///
+    /// Returns false when the commitment cannot be read from `commitment_core`.
     pub fn verify_compliance(e: Env, commitment_id: String) -> bool {
-        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
-            Some(addr) => addr,
+        let commitment = match Self::read_core_commitment(&e, &commitment_id) {
+            Some(c) => c,
             None => return false,
         };
 
-        let mut args = Vec::new(&e);
-        args.push_back(commitment_id.clone().into_val(&e));
-        let commitment_val: Val = match e.try_invoke_contract::<Val, soroban_sdk::Error>(
-            &commitment_core,
-            &Symbol::new(&e, "get_commitment"),
-            args,
-        ) {
-            Ok(Ok(val)) => val,
-            _ => return false,
-        };
-        let commitment: Commitment = match commitment_val.try_into_val(&e) {
-            Ok(c) => c,
-            Err(_) => return false,
-        };
-
-        // Check commitment status
-        let status_settled = String::from_str(&e, "settled");
-        let status_violated = String::from_str(&e, "violated");
-        let status_early_exit = String::from_str(&e, "early_exit");
-        let status_active = String::from_str(&e, "active");
-
-        if commitment.status == status_settled {
+        match commitment.status {
             // Settled commitments are considered compliant (they were compliant until settlement)
-            return true;
-        } else if commitment.status == status_violated {
+            CommitmentStatus::Settled => true,
             // Violated commitments are non-compliant
-            return false;
-        } else if commitment.status == status_early_exit {
+            CommitmentStatus::Violated => false,
             // Early exit commitments are non-compliant (didn't complete term)
-            return false;
-        } else if commitment.status == status_active {
-            // For active commitments, check current metrics
-            let metrics = Self::get_health_metrics(e.clone(), commitment_id);
-            let max_loss = commitment.rules.max_loss_percent as i128;
-            return metrics.drawdown_percent <= max_loss && metrics.compliance_score >= 50;
+            CommitmentStatus::EarlyExit => false,
+            CommitmentStatus::Active => {
+                // A stale value is flagged for keepers but does not change the verdict.
+                let max_age = Self::read_core_max_value_age(&e);
+                let now = e.ledger().timestamp();
+                if max_age > 0 && now.saturating_sub(commitment.last_update_ts) > max_age {
+                    e.events().publish(
+                        (Symbol::new(&e, "ValueStale"), commitment_id.clone()),
+                        (commitment.last_update_ts, now),
+                    );
+                }
+
+                if Self::read_core_violated(&e, &commitment_id) {
+                    return false;
+                }
+
+                // For active commitments, check current metrics
+                let metrics = Self::get_health_metrics(e.clone(), commitment_id.clone());
+                let max_loss_bps = (commitment.rules.max_loss_percent as i128) * 100;
+                let min_score = Self::get_compliance_threshold(e.clone(), commitment_id.clone())
+                    .unwrap_or(DEFAULT_MIN_COMPLIANCE_SCORE);
+                metrics.drawdown_bps <= max_loss_bps && metrics.compliance_score >= min_score
+            }
+        }
+    }
+
+    /// Override the minimum compliance score `verify_compliance` requires for
+    /// one commitment. Restricted to admin; `threshold` must be 0-100.
+    pub fn set_compliance_threshold(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        threshold: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if threshold > 100 {
+            return Err(AttestationError::InvalidComplianceThreshold);
+        }
+        e.storage().persistent().set(
+            &DataKey::ComplianceThreshold(commitment_id.clone()),
+            &threshold,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "ComplianceThresholdSet"), commitment_id),
+            (threshold, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Remove a per-commitment compliance threshold so the global default applies again.
+    pub fn clear_compliance_threshold(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        e.storage()
+            .persistent()
+            .remove(&DataKey::ComplianceThreshold(commitment_id.clone()));
+        e.events().publish(
+            (Symbol::new(&e, "ComplianceThresholdCleared"), commitment_id),
+            e.ledger().timestamp(),
+        );
+        Ok(())
+    }
+
+    /// Per-commitment compliance threshold override, if one is set.
+    ///
+    /// `None` means `verify_compliance` uses `DEFAULT_MIN_COMPLIANCE_SCORE`.
+    pub fn get_compliance_threshold(e: Env, commitment_id: String) -> Option<u32> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::ComplianceThreshold(commitment_id))
+    }
+
+    /// Set how strongly attestations of `attestation_type` move the cached
+    /// compliance score. Restricted to admin.
+    ///
+    /// For `violation` the weight is the score penalty for a medium-severity
+    /// violation (high = 1.5x, low = 0.5x). For other types it is the bonus
+    /// applied when the attestation is compliant.
+    pub fn set_attestation_weight(
+        e: Env,
+        caller: Address,
+        attestation_type: String,
+        weight: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if !Self::is_valid_attestation_type(&e, &attestation_type) {
+            return Err(AttestationError::InvalidAttestationType);
+        }
+        if weight > MAX_ATTESTATION_WEIGHT {
+            return Err(AttestationError::InvalidAttestationWeight);
+        }
+        e.storage().instance().set(
+            &DataKey::AttestationWeight(attestation_type.clone()),
+            &weight,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "AttestationWeightSet"), attestation_type),
+            (weight, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Effective score weight for `attestation_type` (configured or default).
+    pub fn get_attestation_weight(e: Env, attestation_type: String) -> u32 {
+        Self::attestation_weight(&e, &attestation_type)
+    }
+
+    /// Turn informational events on or off (admin only).
+    ///
+    /// Only the `ScoreUpd` metric event is informational. Attestation, violation,
+    /// fee and admin events are always emitted.
+    pub fn set_events_enabled(
+        e: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::EventsEnabled, &enabled);
+        e.events().publish(
+            (Symbol::new(&e, "EventsToggled"),),
+            (caller, enabled, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Set how many attestations a single `batch_attest` call may carry (admin only).
+    ///
+    /// Larger batches are rejected with the shared "batch too large" code, so
+    /// operators can tune against network limits without a redeploy.
+    pub fn set_max_batch_size(
+        e: Env,
+        caller: Address,
+        max_batch_size: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if max_batch_size == 0 {
+            return Err(AttestationError::InvalidBatchSize);
         }
+        BatchProcessor::set_contract_limit(
+            &e,
+            String::from_str(&e, BATCH_CONTRACT_NAME),
+            max_batch_size,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "MaxBatchSizeSet"),),
+            (max_batch_size, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Attestations a single `batch_attest` call may carry.
+    pub fn get_max_batch_size(e: Env) -> u32 {
+        BatchProcessor::get_contract_limit(&e, String::from_str(&e, BATCH_CONTRACT_NAME))
+    }
 
-        // Unknown status defaults to false
-        false
+    /// Whether informational events are emitted.
+    pub fn is_events_enabled(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::EventsEnabled)
+            .unwrap_or(true)
     }
 
     /// Convenience wrapper for fee_generation attestations
@@ -1416,12 +2768,76 @@ impl AttestationEngineContract {
         Ok(())
     }
 
+    /// Report a commitment's volatility exposure in basis points (verifier only).
+    ///
+    /// Recorded as a `volatility` attestation. `get_health_metrics` only uses it
+    /// while core has fewer than two value snapshots to derive volatility from.
+    pub fn record_volatility(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        exposure_bps: i128,
+    ) -> Result<(), AttestationError> {
+        caller.require_auth();
+
+        if exposure_bps < 0 {
+            return Err(AttestationError::InvalidAttestationData);
+        }
+
+        let mut data = Map::new(&e);
+        data.set(
+            String::from_str(&e, "volatility_bps"),
+            Self::i128_to_string(&e, exposure_bps),
+        );
+
+        Self::_attest_internal(
+            e.clone(),
+            caller,
+            commitment_id.clone(),
+            String::from_str(&e, "volatility"),
+            data,
+            true,
+        )?;
+
+        e.events().publish(
+            (Symbol::new(&e, "VolatilityRecorded"), commitment_id),
+            (exposure_bps, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
     /// Convenience wrapper for drawdown attestations
+    ///
+    /// `drawdown_percent` is a whole percentage; use `record_drawdown_bps` to
+    /// report sub-percent drawdowns.
     pub fn record_drawdown(
         e: Env,
         caller: Address,
         commitment_id: String,
         drawdown_percent: i128,
+    ) -> Result<(), AttestationError> {
+        let drawdown_bps = drawdown_percent
+            .checked_mul(100)
+            .ok_or(AttestationError::InvalidAttestationData)?;
+        Self::record_drawdown_bps(e, caller, commitment_id, drawdown_bps)
+    }
+
+    /// Drawdown attestation with basis-point precision (1 bps = 0.01%).
+    ///
+    /// The attestation data carries both `drawdown_bps` and the truncated
+    /// `drawdown_percent`, and compliance is checked against
+    /// `max_loss_percent * 100` bps. Events keep reporting whole percent.
+    ///
+    /// A breach also writes a `violation` attestation. While `get_required_signers`
+    /// is above 1 that violation waits for that many distinct verifiers to report
+    /// the breach, and `ViolationRecorded` and the violation history only update
+    /// once it is confirmed. Until then the drawdown attestation is not stored either,
+    /// so an unconfirmed breach costs no compliance points.
+    pub fn record_drawdown_bps(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        drawdown_bps: i128,
     ) -> Result<(), AttestationError> {
         // Reentrancy protection
         if e.storage().instance().has(&DataKey::ReentrancyGuard) {
@@ -1438,53 +2854,59 @@ impl AttestationEngineContract {
             return Err(AttestationError::Unauthorized);
         }
 
-        let commitment_core: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::CoreContract)
-            .ok_or_else(|| {
+        if !e.storage().instance().has(&DataKey::CoreContract) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(AttestationError::NotInitialized);
+        }
+
+        let commitment = match Self::read_core_commitment(&e, &commitment_id) {
+            Some(c) => c,
+            None => {
                 e.storage().instance().remove(&DataKey::ReentrancyGuard);
-                AttestationError::NotInitialized
-            })?;
-
-        let mut args = Vec::new(&e);
-        args.push_back(commitment_id.clone().into_val(&e));
-        let commitment_val: Val =
-            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
-        let commitment: Commitment = commitment_val
-            .try_into_val(&e)
-            .map_err(|_| AttestationError::CommitmentNotFound)?;
+                return Err(AttestationError::CommitmentNotFound);
+            }
+        };
         let max_loss = commitment.rules.max_loss_percent as i128;
-        let is_compliant = drawdown_percent <= max_loss;
+        let is_compliant = drawdown_bps <= max_loss * 100;
+        let drawdown_percent = drawdown_bps / 100;
 
         let mut data = Map::new(&e);
         data.set(
             String::from_str(&e, "drawdown_percent"),
             Self::i128_to_string(&e, drawdown_percent),
         );
+        data.set(
+            String::from_str(&e, "drawdown_bps"),
+            Self::i128_to_string(&e, drawdown_bps),
+        );
 
-        // Use write_attestation (no auth) for both calls to avoid double require_auth
-        Self::write_attestation(
-            &e,
-            &caller,
-            commitment_id.clone(),
-            String::from_str(&e, "drawdown"),
-            data,
-            is_compliant,
-        )?;
-
-        if !is_compliant {
-            let mut violation_data = Map::new(&e);
-            violation_data.set(
-                String::from_str(&e, "violation_type"),
-                String::from_str(&e, "max_loss_exceeded"),
-            );
-            violation_data.set(
-                String::from_str(&e, "severity"),
-                String::from_str(&e, "high"),
-            );
+        let mut violation_data = Map::new(&e);
+        violation_data.set(
+            String::from_str(&e, "violation_type"),
+            String::from_str(&e, "max_loss_exceeded"),
+        );
+        violation_data.set(
+            String::from_str(&e, "severity"),
+            String::from_str(&e, "high"),
+        );
 
+        // With co-signing enabled the breach only counts once enough verifiers agree;
+        // until then the drawdown is not stored either, so it costs no score.
+        // Use write_attestation (no auth) for both calls to avoid double require_auth.
+        if is_compliant || Self::completes_quorum(&e, &commitment_id, &violation_data) {
             Self::write_attestation(
+                &e,
+                &caller,
+                commitment_id.clone(),
+                String::from_str(&e, "drawdown"),
+                data,
+                is_compliant,
+            )?;
+        }
+
+        let mut breach_confirmed = false;
+        if !is_compliant {
+            breach_confirmed = Self::write_attestation(
                 &e,
                 &caller,
                 commitment_id.clone(),
@@ -1493,12 +2915,27 @@ impl AttestationEngineContract {
                 false,
             )?;
 
-            e.events().publish(
-                (Symbol::new(&e, "ViolationRecorded"), commitment_id.clone()),
-                (drawdown_percent, max_loss, e.ledger().timestamp()),
-            );
+            if breach_confirmed {
+                e.events().publish(
+                    (Symbol::new(&e, "ViolationRecorded"), commitment_id.clone()),
+                    (
+                        commitment_id.clone(),
+                        drawdown_percent,
+                        max_loss,
+                        e.ledger().timestamp(),
+                    ),
+                );
+            }
         }
 
+        Self::track_drawdown_violation(
+            &e,
+            &commitment_id,
+            !breach_confirmed,
+            drawdown_percent,
+            commitment.rules.max_loss_percent,
+        );
+
         e.events().publish(
             (Symbol::new(&e, "DrawdownRecorded"), commitment_id),
             (drawdown_percent, is_compliant, e.ledger().timestamp()),
@@ -1509,6 +2946,42 @@ impl AttestationEngineContract {
     }
 
 
+    /// Append to the violation history when a drawdown first breaches max loss.
+    /// Repeated breaches count again only after a compliant drawdown in between.
+    fn track_drawdown_violation(
+        e: &Env,
+        commitment_id: &String,
+        is_compliant: bool,
+        drawdown_percent: i128,
+        max_loss_percent: u32,
+    ) {
+        let flag_key = DataKey::InDrawdownViolation(commitment_id.clone());
+        let was_violated: bool = e.storage().persistent().get(&flag_key).unwrap_or(false);
+        e.storage().persistent().set(&flag_key, &!is_compliant);
+        if is_compliant || was_violated {
+            return;
+        }
+
+        Storage::push_bounded(
+            e,
+            &DataKey::ViolationHistory(commitment_id.clone()),
+            (e.ledger().timestamp(), drawdown_percent, max_loss_percent),
+            MAX_VIOLATION_HISTORY,
+        );
+    }
+
+    /// Drawdown violations detected by `record_drawdown`, oldest first, as
+    /// `(timestamp, drawdown_percent, max_loss_percent)`. Keeps the last `MAX_VIOLATION_HISTORY`.
+    ///
+    /// Violations the core detects itself in `update_value` are listed by the core's
+    /// `get_violation_history`, not here.
+    pub fn get_violation_history(e: Env, commitment_id: String) -> Vec<(u64, i128, u32)> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::ViolationHistory(commitment_id))
+            .unwrap_or_else(|| Vec::new(&e))
+    }
+
     /// Convert i128 to String (helper function)
     fn i128_to_string(e: &Env, value: i128) -> String {
         if value == 0 {
@@ -1550,6 +3023,30 @@ impl AttestationEngineContract {
 
     /// Calculate a compliance score in the range 0-100.
     ///
+    /// # Formula
+    /// Once a commitment has been attested, its cached score is returned. That score
+    /// starts at 100 and each attestation adjusts it as it is recorded:
+    /// - `violation`: minus the configured weight (default 20), scaled by `severity`
+    ///   (`high` x1.5, `medium` x1, anything else x0.5, missing x1);
+    /// - any other compliant attestation: plus its weight (default 1), capped at 100.
+    ///
+    /// Without a cached score it is derived from history and the commitment's rules
+    /// as read from `commitment_core` (rule terms are skipped if core can't be read):
+    /// 1. `100 - 20 * violations`, where a violation is a `violation` attestation or
+    ///    any attestation with `is_compliant == false`;
+    /// 2. minus one point per whole percent the drawdown exceeds `max_loss_percent`.
+    ///    The drawdown is the latest `drawdown` attestation, else core's
+    ///    `amount`/`current_value`;
+    /// 3. plus `min(100, fees_generated * 100 / min_fee_threshold)` when both are
+    ///    positive;
+    /// 4. plus 10 while the commitment has not passed `expires_at`;
+    /// 5. clamp to 0-100.
+    ///
+    /// Both paths then subtract one point per whole percent `volatility_exposure`
+    /// exceeds the volatility threshold, if one is set (see `set_volatility_threshold`),
+    /// and `points_per_day` per full day since the last attestation, never going
+    /// below the decay floor (see `set_score_decay`).
+    ///
     /// # Parameters
     /// - `commitment_id`: Commitment identifier whose attestations should be
     ///   evaluated.
@@ -1569,22 +3066,15 @@ impl AttestationEngineContract {
             .persistent()
             .get::<DataKey, HealthMetrics>(&metrics_key)
         {
-            return stored_metrics.compliance_score;
+            let score = stored_metrics
+                .compliance_score
+                .saturating_sub(Self::volatility_penalty(
+                    &e,
+                    stored_metrics.volatility_exposure,
+                ));
+            return Self::apply_score_decay(&e, score, stored_metrics.last_attestation);
         }
 
-        // Get commitment from core contract
-        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract).unwrap();
-
-        // Call get_commitment on commitment_core contract
-        // Using Symbol::new() for function name longer than 9 characters
-        let mut args = Vec::new(&e);
-        args.push_back(commitment_id.clone().into_val(&e));
-        let commitment_val: Val =
-            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
-
-        // Convert Val to Commitment
-        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
-
         let attestations = Self::load_attestations_from_storage(&e, &commitment_id);
         let aggregates = Self::aggregate_attestation_metrics(&e, &attestations);
 
@@ -1595,86 +3085,163 @@ impl AttestationEngineContract {
         let violation_count = attestations
             .iter()
             .filter(|att| {
-                !att.is_compliant || att.attestation_type == String::from_str(&e, "violation")
+                !att.is_revoked
+                    && (!att.is_compliant
+                        || att.attestation_type == String::from_str(&e, "violation"))
             })
             .count() as i32;
         score = score
             .checked_sub(violation_count.checked_mul(20).unwrap_or(0))
             .unwrap_or(0);
 
-        // Calculate drawdown vs threshold: -1 per % over threshold
-        let initial_value = commitment.amount;
-        let current_value = commitment.current_value;
-        let max_loss_percent = commitment.rules.max_loss_percent as i128;
-        let commitment_drawdown_percent = if initial_value > 0 {
-            let diff = initial_value.checked_sub(current_value).unwrap_or(0);
-            diff.checked_mul(100)
-                .unwrap_or(0)
-                .checked_div(initial_value)
-                .unwrap_or(0)
-        } else {
-            0
-        };
-        let effective_drawdown_percent = aggregates
-            .latest_drawdown_percent
-            .unwrap_or(commitment_drawdown_percent);
-
-        if effective_drawdown_percent > max_loss_percent {
-            let over_threshold = effective_drawdown_percent
-                .checked_sub(max_loss_percent)
-                .unwrap_or(0);
-            score = score.checked_sub(over_threshold as i32).unwrap_or(0);
-        }
-
-        // Calculate fee generation vs expectations: +1 per % of expected fees
-        let min_fee_threshold = commitment.rules.min_fee_threshold;
-        let total_fees = aggregates.fees_generated;
-
-        // Only add fee bonus if we have fees and a threshold
-        if min_fee_threshold > 0 && total_fees > 0 {
-            let fee_percent = total_fees
-                .checked_mul(100)
-                .unwrap_or(0)
-                .checked_div(min_fee_threshold)
-                .unwrap_or(0);
-            // Cap the bonus to prevent excessive score inflation
-            let bonus = if fee_percent > 100 { 100 } else { fee_percent };
-            score = score.checked_add(bonus as i32).unwrap_or(100);
-        }
-
-        // Duration adherence: +10 if on track
-        let current_time = e.ledger().timestamp();
-        let expires_at = commitment.expires_at;
-        let created_at = commitment.created_at;
-
-        if expires_at > created_at {
-            let total_duration = expires_at.checked_sub(created_at).unwrap_or(1);
-            let elapsed = current_time.saturating_sub(created_at);
-
-            // Check if we're on track (not too far behind or ahead)
-            // Simplified: if elapsed is within reasonable bounds of expected progress
-            let expected_progress = (elapsed as u128)
-                .checked_mul(100)
-                .unwrap_or(0)
-                .checked_div(total_duration as u128)
-                .unwrap_or(0);
-
-            // Consider "on track" if between 0-100% of expected time
-            if expected_progress <= 100 {
-                score = score.checked_add(10).unwrap_or(100);
+        // Rule-based adjustments need the commitment from core; when it can't be
+        // read the score is based on attestation history alone.
+        if let Some(commitment) = Self::read_core_commitment(&e, &commitment_id) {
+            // Calculate drawdown vs threshold: -1 per % over threshold
+            let initial_value = commitment.amount;
+            let current_value = commitment.current_value;
+            let max_loss_bps = (commitment.rules.max_loss_percent as i128) * 100;
+            let effective_drawdown_bps =
+                Self::compliance_drawdown_bps(&aggregates, initial_value, current_value);
+
+            if effective_drawdown_bps > max_loss_bps {
+                // Only whole percentage points over the threshold are penalised.
+                let over_threshold = effective_drawdown_bps
+                    .checked_sub(max_loss_bps)
+                    .unwrap_or(0)
+                    / 100;
+                score = score.checked_sub(over_threshold as i32).unwrap_or(0);
+            }
+
+            // Calculate fee generation vs expectations: +1 per % of expected fees
+            let min_fee_threshold = commitment.rules.min_fee_threshold;
+            let total_fees = aggregates.fees_generated;
+
+            // Only add fee bonus if we have fees and a threshold
+            if min_fee_threshold > 0 && total_fees > 0 {
+                let fee_percent = total_fees
+                    .checked_mul(100)
+                    .unwrap_or(0)
+                    .checked_div(min_fee_threshold)
+                    .unwrap_or(0);
+                // Cap the bonus to prevent excessive score inflation
+                let bonus = if fee_percent > 100 { 100 } else { fee_percent };
+                score = score.checked_add(bonus as i32).unwrap_or(100);
+            }
+
+            // Duration adherence: +10 if on track
+            let current_time = e.ledger().timestamp();
+            let expires_at = commitment.expires_at;
+            let created_at = commitment.created_at;
+
+            if expires_at > created_at {
+                let total_duration = expires_at.checked_sub(created_at).unwrap_or(1);
+                let elapsed = current_time.saturating_sub(created_at);
+
+                // Check if we're on track (not too far behind or ahead)
+                // Simplified: if elapsed is within reasonable bounds of expected progress
+                let expected_progress = (elapsed as u128)
+                    .checked_mul(100)
+                    .unwrap_or(0)
+                    .checked_div(total_duration as u128)
+                    .unwrap_or(0);
+
+                // Consider "on track" if between 0-100% of expected time
+                if expected_progress <= 100 {
+                    score = score.checked_add(10).unwrap_or(100);
+                }
             }
         }
 
+        let volatility = Self::volatility_exposure(&e, &commitment_id, &aggregates);
+        score = score
+            .checked_sub(Self::volatility_penalty(&e, volatility) as i32)
+            .unwrap_or(0);
+
         // Clamp between 0 and 100
         score = score.clamp(0, 100);
+        let score = Self::apply_score_decay(&e, score as u32, aggregates.last_attestation);
 
-        // Emit compliance score update event
-        e.events().publish(
-            (symbol_short!("ScoreUpd"), commitment_id),
-            (score as u32, e.ledger().timestamp()),
+        // Emit compliance score update event (informational)
+        if Self::is_events_enabled(e.clone()) {
+            e.events().publish(
+                (symbol_short!("ScoreUpd"), commitment_id),
+                (score, e.ledger().timestamp()),
+            );
+        }
+
+        score
+    }
+
+    /// Lower `score` by the configured points per day elapsed since `last_attestation`,
+    /// stopping at the floor. Commitments that were never attested are not decayed.
+    fn apply_score_decay(e: &Env, score: u32, last_attestation: u64) -> u32 {
+        let decay = Self::get_score_decay(e.clone());
+        if decay.points_per_day == 0 || last_attestation == 0 || score <= decay.floor {
+            return score;
+        }
+        let days = e.ledger().timestamp().saturating_sub(last_attestation) / 86_400;
+        let penalty = days.saturating_mul(decay.points_per_day as u64);
+        let decayed = (score as u64).saturating_sub(penalty) as u32;
+        decayed.max(decay.floor)
+    }
+
+    /// Configure compliance score decay for unattested commitments (admin only).
+    /// `points_per_day == 0` turns decay off; `floor` must be within 0-100.
+    pub fn set_score_decay(
+        e: Env,
+        caller: Address,
+        points_per_day: u32,
+        floor: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if floor > 100 {
+            return Err(AttestationError::InvalidComplianceThreshold);
+        }
+        e.storage().instance().set(
+            &DataKey::ScoreDecay,
+            &ScoreDecayConfig {
+                points_per_day,
+                floor,
+            },
         );
+        Ok(())
+    }
+
+    /// Current score decay configuration; decay is off by default.
+    pub fn get_score_decay(e: Env) -> ScoreDecayConfig {
+        e.storage()
+            .instance()
+            .get(&DataKey::ScoreDecay)
+            .unwrap_or(ScoreDecayConfig {
+                points_per_day: 0,
+                floor: 0,
+            })
+    }
+
+    /// Set the volatility, in bps, above which the compliance score loses one point
+    /// per whole percent (admin only). `0` turns the penalty off (the default).
+    pub fn set_volatility_threshold(
+        e: Env,
+        caller: Address,
+        threshold_bps: i128,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if threshold_bps < 0 {
+            return Err(AttestationError::InvalidAttestationData);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::VolatilityThreshold, &threshold_bps);
+        Ok(())
+    }
 
-        score as u32
+    /// Current volatility threshold in bps; `0` means volatility does not affect the score.
+    pub fn get_volatility_threshold(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::VolatilityThreshold)
+            .unwrap_or(0)
     }
 
     /// Get high-level protocol analytics combining commitment and attestation data.
@@ -1879,7 +3446,7 @@ impl AttestationEngineContract {
 
         // Validate batch size
         let batch_size = params_list.len();
-        let contract_name = String::from_str(&e, "attestation_engine");
+        let contract_name = String::from_str(&e, BATCH_CONTRACT_NAME);
         if let Err(error_code) =
             BatchProcessor::enforce_batch_limits(&e, batch_size, Some(contract_name))
         {
@@ -2007,27 +3574,33 @@ impl AttestationEngineContract {
                 data: params.data.clone(),
                 timestamp,
                 verified_by: caller.clone(),
-                is_compliant: params.is_compliant,
+                is_compliant: Self::derive_compliance(
+                    &e,
+                    &params.commitment_id,
+                    &params.attestation_type,
+                    &params.data,
+                    params.is_compliant,
+                ),
+                is_revoked: false,
             };
 
-            // Store attestation
-            let key = DataKey::Attestations(params.commitment_id.clone());
-            let mut attestations: Vec<Attestation> = e
-                .storage()
-                .persistent()
-                .get(&key)
-                .unwrap_or_else(|| Vec::new(&e));
-            attestations.push_back(attestation.clone());
-            e.storage().persistent().set(&key, &attestations);
+            // Store attestation and bump the commitment's counter
+            if let Err(err) = Self::append_attestation(&e, &params.commitment_id, &attestation) {
+                errors.push_back(BatchError {
+                    index: i,
+                    error_code: err as u32,
+                    context: String::from_str(&e, "attestation_cap"),
+                });
+                if mode == BatchMode::Atomic {
+                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                    return BatchResultVoid::failure(&e, errors);
+                }
+                continue;
+            }
 
             // Update health metrics
             Self::update_health_metrics(&e, &params.commitment_id, &attestation);
 
-            // Increment attestation counter
-            let counter_key = DataKey::AttestationCounter(params.commitment_id.clone());
-            let counter: u64 = e.storage().persistent().get(&counter_key).unwrap_or(0);
-            e.storage().persistent().set(&counter_key, &(counter + 1));
-
             // Update analytics counters (in memory)
             total_attestations = total_attestations.checked_add(1).unwrap();
             verifier_count = verifier_count.checked_add(1).unwrap();
@@ -2045,6 +3618,7 @@ impl AttestationEngineContract {
                     caller.clone(),
                 ),
                 (
+                    params.commitment_id.clone(),
                     params.attestation_type.clone(),
                     params.is_compliant,
                     timestamp,
@@ -2255,6 +3829,26 @@ fn read_version(e: &Env) -> u32 {
         .unwrap_or(0)
 }
 
+/// Keep a commitment's attestation state from being archived while it is still attested.
+fn extend_attestation_ttl(e: &Env, commitment_id: &String) {
+    Storage::extend_instance_ttl(e);
+    let count = AttestationEngineContract::stored_attestation_count(e, commitment_id);
+    for chunk_index in 0..count.div_ceil(CHUNK_SIZE) {
+        Storage::extend_persistent_ttl(
+            e,
+            &DataKey::AttestationChunk(commitment_id.clone(), chunk_index),
+        );
+    }
+    for key in [
+        DataKey::HealthMetrics(commitment_id.clone()),
+        DataKey::AttestationCounter(commitment_id.clone()),
+    ] {
+        if e.storage().persistent().has(&key) {
+            Storage::extend_persistent_ttl(e, &key);
+        }
+    }
+}
+
 fn require_admin(e: &Env, caller: &Address) -> Result<(), AttestationError> {
     caller.require_auth();
     let admin: Address = e
@@ -29,7 +29,7 @@ fn test_attest_invalid_types() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "commitment_invalid_type",
-        "active",
+        CommitmentStatus::Active,
         1_000,
         1_000,
         10,
@@ -85,7 +85,7 @@ fn test_attest_invalid_types() {
 fn create_mock_commitment_with_status(
     e: &Env,
     commitment_id: &str,
-    status: &str,
+    status: CommitmentStatus,
     amount: i128,
     current_value: i128,
     max_loss_percent: u32,
@@ -103,7 +103,7 @@ fn create_mock_commitment_with_status(
 fn create_mock_commitment_with_status_internal(
     e: &Env,
     commitment_id: &str,
-    status: &str,
+    status: CommitmentStatus,
     amount: i128,
     current_value: i128,
     max_loss_percent: u32,
@@ -128,7 +128,14 @@ fn create_mock_commitment_with_status_internal(
         created_at: 1000,
         expires_at: 1000 + (30 * 86400),
         current_value,
-        status: String::from_str(e, status),
+        status,
+        memo: String::from_str(e, ""),
+        settled_amount: 0,
+        settled_at: 0,
+        max_drawdown_percent: 0,
+        last_update_ts: 0,
+        realized_pnl: 0,
+        beneficiary: None,
     }
 }
 
@@ -234,8 +241,14 @@ fn test_get_health_metrics_cross_reads_commitment_core_state() {
     let (attestation_id, core_id) = setup_initialized_engine_with_core(&e);
     let commitment_id = String::from_str(&e, "cross_read_core_metrics");
 
-    let commitment =
-        create_mock_commitment_with_status(&e, "cross_read_core_metrics", "active", 2_000, 1_700, 20);
+    let commitment = create_mock_commitment_with_status(
+        &e,
+        "cross_read_core_metrics",
+        CommitmentStatus::Active,
+        2_000,
+        1_700,
+        20,
+    );
     e.as_contract(&core_id, || {
         e.storage().instance().set(
             &commitment_core::DataKey::Commitment(commitment_id.clone()),
@@ -264,7 +277,7 @@ fn test_get_health_metrics_ignores_stale_cached_values_for_core_read_fields() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "cross_read_with_cached_metrics",
-        "active",
+        CommitmentStatus::Active,
         1_500,
         1_200,
         25,
@@ -288,6 +301,7 @@ fn test_get_health_metrics_ignores_stale_cached_values_for_core_read_fields() {
         data,
         is_compliant: true,
         verified_by: verifier,
+        is_revoked: false,
     });
 
     e.as_contract(&attestation_id, || {
@@ -298,15 +312,18 @@ fn test_get_health_metrics_ignores_stale_cached_values_for_core_read_fields() {
                 current_value: 999,
                 initial_value: 999,
                 drawdown_percent: 99,
+                drawdown_bps: 9_900,
+                max_drawdown_percent: 99,
                 fees_generated: 999,
                 volatility_exposure: 99,
                 last_attestation: 999,
                 compliance_score: 88,
             },
         );
-        e.storage()
-            .persistent()
-            .set(&DataKey::Attestations(commitment_id.clone()), &attestations);
+        for attestation in attestations.iter() {
+            AttestationEngineContract::append_attestation(&e, &commitment_id, &attestation)
+                .unwrap();
+        }
     });
 
     let metrics = e.as_contract(&attestation_id, || {
@@ -365,7 +382,7 @@ fn test_verify_compliance_settled_commitment_returns_true() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "test_commitment_settled",
-        "settled",
+        CommitmentStatus::Settled,
         1000,
         1050,
         10,
@@ -397,7 +414,7 @@ fn test_verify_compliance_violated_commitment_returns_false() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "test_commitment_violated",
-        "violated",
+        CommitmentStatus::Violated,
         1000,
         850,
         10,
@@ -429,7 +446,7 @@ fn test_verify_compliance_early_exit_returns_false() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "test_commitment_early_exit",
-        "early_exit",
+        CommitmentStatus::EarlyExit,
         1000,
         980,
         10,
@@ -461,7 +478,7 @@ fn test_verify_compliance_active_commitment_within_rules_returns_true() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "test_commitment_active_compliant",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -477,6 +494,22 @@ fn test_verify_compliance_active_commitment_within_rules_returns_true() {
     assert!(is_compliant);
 }
 
+#[test]
+fn test_verify_compliance_false_when_core_reports_violation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _) = setup_weighted_engine(&e, &["matured"]);
+    let commitment_id = String::from_str(&e, "matured");
+    let expires_at = 1000 + (30 * 86400);
+
+    // No loss and no attestations: only core's duration check can object.
+    e.ledger().with_mut(|l| l.timestamp = expires_at - 1);
+    assert!(client.verify_compliance(&commitment_id));
+    e.ledger().with_mut(|l| l.timestamp = expires_at);
+    assert!(!client.verify_compliance(&commitment_id));
+    assert_eq!(event_count(&e, Symbol::new(&e, "Violated")), 0);
+}
+
 #[test]
 fn test_verify_compliance_active_commitment_exceeds_loss_returns_false() {
     let e = Env::default();
@@ -493,7 +526,7 @@ fn test_verify_compliance_active_commitment_exceeds_loss_returns_false() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "test_commitment_active_noncompliant",
-        "active",
+        CommitmentStatus::Active,
         1000,
         850,
         10,
@@ -526,6 +559,93 @@ fn test_verify_compliance_nonexistent_commitment_returns_false() {
     assert!(!is_compliant);
 }
 
+#[test]
+fn test_verify_compliance_uses_per_commitment_threshold_override() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    let core_id = e.register_contract(None, commitment_core::CommitmentCoreContract);
+    let client = AttestationEngineContractClient::new(&e, &attestation_id);
+
+    let admin = Address::generate(&e);
+    client.initialize(&admin, &core_id);
+
+    let default_id = String::from_str(&e, "threshold_default");
+    let vip_id = String::from_str(&e, "threshold_vip");
+    for (id, raw) in [
+        (&default_id, "threshold_default"),
+        (&vip_id, "threshold_vip"),
+    ] {
+        let commitment = create_mock_commitment_with_status_internal(
+            &e,
+            raw,
+            CommitmentStatus::Active,
+            1000,
+            1000,
+            10,
+        );
+        e.as_contract(&core_id, || {
+            e.storage().instance().set(
+                &commitment_core::DataKey::Commitment(id.clone()),
+                &commitment,
+            );
+        });
+        // Same score for both commitments.
+        e.as_contract(&attestation_id, || {
+            e.storage().persistent().set(
+                &DataKey::HealthMetrics(id.clone()),
+                &HealthMetrics {
+                    commitment_id: id.clone(),
+                    current_value: 1000,
+                    initial_value: 1000,
+                    drawdown_percent: 0,
+                    drawdown_bps: 0,
+                    max_drawdown_percent: 0,
+                    fees_generated: 0,
+                    volatility_exposure: 0,
+                    last_attestation: 0,
+                    compliance_score: 60,
+                },
+            );
+        });
+    }
+
+    assert_eq!(client.get_compliance_threshold(&vip_id), None);
+    client.set_compliance_threshold(&admin, &vip_id, &75);
+    assert_eq!(client.get_compliance_threshold(&vip_id), Some(75));
+
+    assert!(client.verify_compliance(&default_id));
+    assert!(!client.verify_compliance(&vip_id));
+
+    client.clear_compliance_threshold(&admin, &vip_id);
+    assert_eq!(client.get_compliance_threshold(&vip_id), None);
+    assert!(client.verify_compliance(&vip_id));
+}
+
+#[test]
+fn test_set_compliance_threshold_rejects_non_admin_and_out_of_range() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    let core_id = e.register_contract(None, commitment_core::CommitmentCoreContract);
+    let client = AttestationEngineContractClient::new(&e, &attestation_id);
+
+    let admin = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let commitment_id = String::from_str(&e, "threshold_guarded");
+    client.initialize(&admin, &core_id);
+
+    assert_eq!(
+        client.try_set_compliance_threshold(&stranger, &commitment_id, &70),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_compliance_threshold(&admin, &commitment_id, &101),
+        Err(Ok(AttestationError::InvalidComplianceThreshold))
+    );
+    assert_eq!(client.get_compliance_threshold(&commitment_id), None);
+}
+
 #[test]
 fn test_attest_without_initialize_fails() {
     let e = Env::default();
@@ -542,6 +662,164 @@ fn test_attest_without_initialize_fails() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_attest_stores_record_at_ledger_time() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["stored"]);
+    let commitment_id = String::from_str(&e, "stored");
+    e.ledger().with_mut(|l| l.timestamp = 7_777);
+
+    client.attest(
+        &admin,
+        &commitment_id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &true,
+    );
+
+    let attestations = client.get_attestations(&commitment_id);
+    assert_eq!(attestations.len(), 1);
+    let stored = attestations.get(0).unwrap();
+    assert_eq!(stored.timestamp, 7_777);
+    assert_eq!(stored.verified_by, admin);
+    assert_eq!(
+        stored.attestation_type,
+        String::from_str(&e, "health_check")
+    );
+    assert_eq!(client.get_attestation_count(&commitment_id), 1);
+
+    assert_eq!(event_count(&e, Symbol::new(&e, "AttestationRecorded")), 1);
+    let recorded = {
+        use soroban_sdk::{testutils::Events, TryFromVal};
+        let recorded_symbol = Symbol::new(&e, "AttestationRecorded");
+        e.events()
+            .all()
+            .iter()
+            .find(|ev| {
+                ev.1.get(0)
+                    .map(|t| Symbol::try_from_val(&e, &t).ok() == Some(recorded_symbol.clone()))
+                    .unwrap_or(false)
+            })
+            .unwrap()
+    };
+    let data: (String, String, bool, u64) = recorded.2.into_val(&e);
+    assert_eq!(
+        data,
+        (
+            commitment_id,
+            String::from_str(&e, "health_check"),
+            true,
+            7_777
+        )
+    );
+}
+
+#[test]
+fn test_attest_extends_attestation_ttl() {
+    use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["long_lived"]);
+    let commitment_id = String::from_str(&e, "long_lived");
+
+    client.attest(
+        &admin,
+        &commitment_id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &true,
+    );
+
+    e.as_contract(&client.address, || {
+        let expected = shared_utils::storage::TTL_EXTEND_TO_LEDGERS.min(e.storage().max_ttl());
+        assert_eq!(e.storage().instance().get_ttl(), expected);
+        assert_eq!(
+            e.storage()
+                .persistent()
+                .get_ttl(&DataKey::AttestationChunk(commitment_id.clone(), 0)),
+            expected
+        );
+    });
+}
+
+#[test]
+fn test_revoke_attestation_keeps_record_but_restores_score() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["revoked"]);
+    let id = String::from_str(&e, "revoked");
+    let verifier = Address::generate(&e);
+    client.add_verifier(&admin, &verifier);
+
+    client.attest(
+        &verifier,
+        &id,
+        &String::from_str(&e, "violation"),
+        &violation_data(&e, "medium"),
+        &false,
+    );
+    client.record_fees(&verifier, &id, &40);
+    assert_eq!(client.calculate_compliance_score(&id), 81);
+    assert_eq!(client.get_protocol_statistics(), (0, 2, 1, 40));
+
+    assert_eq!(
+        client.try_revoke_attestation(&Address::generate(&e), &id, &0),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_revoke_attestation(&verifier, &id, &5),
+        Err(Ok(AttestationError::AttestationIndexOutOfRange))
+    );
+
+    client.revoke_attestation(&verifier, &id, &0);
+    assert!(client.get_attestation(&id, &0).is_revoked);
+    assert_eq!(client.get_attestations(&id).len(), 2);
+    assert_eq!(client.get_unrevoked_attestations(&id).len(), 1);
+    assert_eq!(client.calculate_compliance_score(&id), 100);
+    assert_eq!(client.get_health_metrics(&id).fees_generated, 40);
+    assert_eq!(client.get_protocol_statistics(), (0, 2, 0, 40));
+
+    // The admin may revoke too, but only once.
+    client.revoke_attestation(&admin, &id, &1);
+    assert_eq!(client.get_health_metrics(&id).fees_generated, 0);
+    assert_eq!(client.get_protocol_statistics(), (0, 2, 0, 0));
+    assert_eq!(
+        client.try_revoke_attestation(&admin, &id, &1),
+        Err(Ok(AttestationError::AttestationRevoked))
+    );
+}
+
+#[test]
+fn test_unauthorized_verifier_gets_error_code_from_writers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _) = setup_weighted_engine(&e, &["c_unauth"]);
+    let stranger = Address::generate(&e);
+    let commitment_id = String::from_str(&e, "c_unauth");
+
+    assert_eq!(
+        client.try_attest(
+            &stranger,
+            &commitment_id,
+            &String::from_str(&e, "health_check"),
+            &Map::new(&e),
+            &true,
+        ),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_record_fees(&stranger, &commitment_id, &100),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_record_drawdown(&stranger, &commitment_id, &5),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(client.get_attestations(&commitment_id).len(), 0);
+}
+
 #[test]
 fn test_record_fees_records_attestation_and_metrics() {
     let e = Env::default();
@@ -559,7 +837,7 @@ fn test_record_fees_records_attestation_and_metrics() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "commitment_fee",
-        "active",
+        CommitmentStatus::Active,
         1_000,
         1_000,
         10,
@@ -584,6 +862,64 @@ fn test_record_fees_records_attestation_and_metrics() {
     assert_eq!(metrics.fees_generated, 250);
 }
 
+#[test]
+fn test_volatility_exposure_prefers_core_value_history() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (attestation_id, core_id) = setup_initialized_engine_with_core(&e);
+    let client = AttestationEngineContractClient::new(&e, &attestation_id);
+    let admin = client.get_admin();
+    client.add_verifier(&admin, &admin);
+
+    let commitment_id = String::from_str(&e, "commitment_vol");
+    let commitment = create_mock_commitment_with_status_internal(
+        &e,
+        "commitment_vol",
+        CommitmentStatus::Active,
+        1_000,
+        990,
+        10,
+    );
+    // +10% then -10%: realized volatility is 1000 bps.
+    let history_key = commitment_core::DataKey::ValueHistory(commitment_id.clone());
+    e.as_contract(&core_id, || {
+        e.storage().instance().set(
+            &commitment_core::DataKey::Commitment(commitment_id.clone()),
+            &commitment,
+        );
+        e.storage().persistent().set(
+            &history_key,
+            &soroban_sdk::vec![&e, (1u64, 1_000i128), (2u64, 1_100i128), (3u64, 990i128)],
+        );
+    });
+
+    assert_eq!(
+        client.try_record_volatility(&admin, &commitment_id, &-1),
+        Err(Ok(AttestationError::InvalidAttestationData))
+    );
+    client.record_volatility(&admin, &commitment_id, &250);
+    assert_eq!(
+        client
+            .get_health_metrics(&commitment_id)
+            .volatility_exposure,
+        1_000
+    );
+
+    // One point per whole percent above the threshold.
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 100);
+    client.set_volatility_threshold(&admin, &500);
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 95);
+
+    // Without enough history the verifier's report is used.
+    e.as_contract(&core_id, || e.storage().persistent().remove(&history_key));
+    assert_eq!(
+        client
+            .get_health_metrics(&commitment_id)
+            .volatility_exposure,
+        250
+    );
+}
+
 #[test]
 fn test_record_drawdown_within_max_loss_records_drawdown() {
     let e = Env::default();
@@ -601,7 +937,7 @@ fn test_record_drawdown_within_max_loss_records_drawdown() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "commitment_drawdown",
-        "active",
+        CommitmentStatus::Active,
         1_000,
         1_000,
         10,
@@ -624,6 +960,124 @@ fn test_record_drawdown_within_max_loss_records_drawdown() {
 
     let metrics = client.get_stored_health_metrics(&commitment_id).unwrap();
     assert_eq!(metrics.drawdown_percent, 5);
+    assert_eq!(metrics.drawdown_bps, 500);
+}
+
+#[test]
+fn test_record_drawdown_bps_surfaces_sub_percent_drawdown() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    let core_id = e.register_contract(None, commitment_core::CommitmentCoreContract);
+    let client = AttestationEngineContractClient::new(&e, &attestation_id);
+
+    let admin = Address::generate(&e);
+    let commitment_id = String::from_str(&e, "commitment_half_pct");
+
+    client.initialize(&admin, &core_id);
+    client.add_verifier(&admin, &admin);
+
+    let commitment = create_mock_commitment_with_status_internal(
+        &e,
+        "commitment_half_pct",
+        CommitmentStatus::Active,
+        1_000,
+        995,
+        10,
+    );
+    e.as_contract(&core_id, || {
+        e.storage().instance().set(
+            &commitment_core::DataKey::Commitment(commitment_id.clone()),
+            &commitment,
+        );
+    });
+
+    // 0.5% drawdown computed from the core values, before any attestation.
+    let metrics = client.get_health_metrics(&commitment_id);
+    assert_eq!(metrics.drawdown_bps, 50);
+    assert_eq!(metrics.drawdown_percent, 0);
+
+    client.record_drawdown_bps(&admin, &commitment_id, &50);
+
+    let attestation = client.get_attestations(&commitment_id).get(0).unwrap();
+    assert!(attestation.is_compliant);
+    assert_eq!(
+        attestation.data.get(String::from_str(&e, "drawdown_bps")),
+        Some(String::from_str(&e, "50"))
+    );
+
+    let stored = client.get_stored_health_metrics(&commitment_id).unwrap();
+    assert_eq!(stored.drawdown_bps, 50);
+    assert_eq!(stored.drawdown_percent, 0);
+    assert_eq!(client.get_health_metrics(&commitment_id).drawdown_bps, 50);
+}
+
+#[test]
+fn test_record_drawdown_bps_just_over_max_loss_is_violation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    let core_id = e.register_contract(None, commitment_core::CommitmentCoreContract);
+    let client = AttestationEngineContractClient::new(&e, &attestation_id);
+
+    let admin = Address::generate(&e);
+    let commitment_id = String::from_str(&e, "commitment_over_bps");
+
+    client.initialize(&admin, &core_id);
+    client.add_verifier(&admin, &admin);
+
+    let commitment = create_mock_commitment_with_status_internal(
+        &e,
+        "commitment_over_bps",
+        CommitmentStatus::Active,
+        1_000,
+        1_000,
+        10,
+    );
+    e.as_contract(&core_id, || {
+        e.storage().instance().set(
+            &commitment_core::DataKey::Commitment(commitment_id.clone()),
+            &commitment,
+        );
+    });
+
+    // 10.01% truncates to 10 whole percent but is still over a 10% limit.
+    client.record_drawdown_bps(&admin, &commitment_id, &1_001);
+
+    let attestations = client.get_attestations(&commitment_id);
+    assert_eq!(attestations.len(), 2);
+    assert!(!attestations.get(0).unwrap().is_compliant);
+    assert_eq!(
+        attestations.get(1).unwrap().attestation_type,
+        String::from_str(&e, "violation")
+    );
+}
+
+#[test]
+fn test_record_drawdown_over_max_loss_flags_violation_at_ledger_time() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["breach"]);
+    let commitment_id = String::from_str(&e, "breach");
+    e.ledger().with_mut(|l| l.timestamp = 5_000);
+
+    client.record_drawdown(&admin, &commitment_id, &25);
+
+    let attestations = client.get_attestations(&commitment_id);
+    assert_eq!(attestations.len(), 2);
+    let violation = attestations.get(1).unwrap();
+    assert_eq!(
+        violation.attestation_type,
+        String::from_str(&e, "violation")
+    );
+    assert!(!violation.is_compliant);
+    assert_eq!(violation.timestamp, 5_000);
+
+    let metrics = client.get_stored_health_metrics(&commitment_id).unwrap();
+    assert_eq!(metrics.drawdown_percent, 25);
+    assert_eq!(metrics.last_attestation, 5_000);
+    assert_eq!(event_count(&e, Symbol::new(&e, "ViolationRecorded")), 1);
+    assert_eq!(event_count(&e, Symbol::new(&e, "DrawdownRecorded")), 1);
 }
 
 #[test]
@@ -644,7 +1098,7 @@ fn test_get_attestations_page_logic() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "test_commitment_pagination",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -728,7 +1182,7 @@ fn test_get_attestations_bounded_empty() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "bounded_empty",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -763,7 +1217,7 @@ fn test_get_attestations_bounded_matches_first_page() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "bounded_first_page",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -811,7 +1265,7 @@ fn test_get_attestations_bounded_at_cap() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "bounded_at_cap",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -852,7 +1306,7 @@ fn test_get_attestations_bounded_above_cap_paging_continuation() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "bounded_above_cap",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -885,7 +1339,7 @@ fn test_get_attestations_bounded_above_cap_paging_continuation() {
     assert_eq!(page2.next_offset, 0);
 
     let mut collected = Vec::new(&e);
-    for att in bounded.iter() {
+    for att in page1.attestations.iter() {
         collected.push_back(att.clone());
     }
     for att in page2.attestations.iter() {
@@ -897,6 +1351,13 @@ fn test_get_attestations_bounded_above_cap_paging_continuation() {
         let att = collected.get(i).unwrap();
         assert_eq!(att.timestamp, start_ts + (i as u64) + 1);
     }
+
+    // The capped read keeps the most recent attestations, oldest-first.
+    assert_eq!(bounded, collected.slice(total - MAX_PAGE_SIZE..));
+    assert_eq!(
+        bounded.get(MAX_PAGE_SIZE - 1).unwrap().timestamp,
+        start_ts + total as u64
+    );
 }
 
 #[test]
@@ -916,7 +1377,7 @@ fn test_batch_attest_unaffected_by_bounded_get_attestations() {
     let commitment = create_mock_commitment_with_status_internal(
         &e,
         "batch_bounded",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -944,6 +1405,10 @@ fn test_batch_attest_unaffected_by_bounded_get_attestations() {
     assert_eq!(client.get_attestation_count(&commitment_id), 3);
     let attestations = client.get_attestations(&commitment_id);
     assert_eq!(attestations.len(), 3);
+    assert_eq!(
+        client.get_attested_commitment_ids(&0, &10),
+        soroban_sdk::vec![&e, commitment_id]
+    );
 }
 
 // ============================================
@@ -1214,7 +1679,7 @@ fn test_attestation_types_health_check_validation() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "health_check_test",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -1274,7 +1739,7 @@ fn test_attestation_types_violation_validation() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "violation_test",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -1335,7 +1800,7 @@ fn test_attestation_types_violation_missing_required_data_fails() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "violation_missing_data",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -1382,14 +1847,8 @@ fn test_attestation_types_fee_generation_validation() {
         AttestationEngineContract::add_verifier(e.clone(), admin.clone(), verifier.clone()).unwrap();
     });
 
-    let commitment = create_mock_commitment_with_status(
-        &e,
-        "fee_test",
-        "active",
-        1000,
-        950,
-        10,
-    );
+    let commitment =
+        create_mock_commitment_with_status(&e, "fee_test", CommitmentStatus::Active, 1000, 950, 10);
     e.as_contract(&core_id, || {
         e.storage().instance().set(
             &commitment_core::DataKey::Commitment(commitment_id.clone()),
@@ -1445,7 +1904,7 @@ fn test_attestation_types_drawdown_validation() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "drawdown_test",
-        "active",
+        CommitmentStatus::Active,
         1000,
         850,
         10,
@@ -1505,7 +1964,7 @@ fn test_attestation_types_invalid_type_fails() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "invalid_type_test",
-        "active",
+        CommitmentStatus::Active,
         1000,
         950,
         10,
@@ -1547,7 +2006,7 @@ fn setup_compliance_score_case(
     let commitment = create_mock_commitment_with_status(
         e,
         commitment_id_str,
-        "active",
+        CommitmentStatus::Active,
         1_000,
         current_value,
         max_loss_percent,
@@ -1578,6 +2037,7 @@ fn attestation_with_data(
         data,
         is_compliant,
         verified_by: Address::generate(e),
+        is_revoked: false,
     }
 }
 
@@ -1588,9 +2048,9 @@ fn store_attestations(
     attestations: Vec<Attestation>,
 ) {
     e.as_contract(attestation_id, || {
-        e.storage()
-            .persistent()
-            .set(&DataKey::Attestations(commitment_id.clone()), &attestations);
+        for attestation in attestations.iter() {
+            AttestationEngineContract::append_attestation(e, commitment_id, &attestation).unwrap();
+        }
     });
 }
 
@@ -1757,28 +2217,71 @@ fn test_calculate_compliance_score_single_drawdown_attestation_drives_verificati
 }
 
 #[test]
-fn test_compliance_scoring_perfect_score() {
+fn test_compliance_drawdown_clamps_gains_to_zero() {
     let e = Env::default();
-    e.mock_all_auths();
-    let attestation_id = e.register_contract(None, AttestationEngineContract);
-    let core_id = e.register_contract(None, commitment_core::CommitmentCoreContract);
+    // 1_000 committed, now worth 1_500: a signed drawdown of -50%.
+    let (attestation_id, _, commitment_id) =
+        setup_compliance_score_case(&e, "score_appreciated", 1_500, 10);
 
-    let admin = Address::generate(&e);
-    let verifier = Address::generate(&e);
-    let commitment_id = String::from_str(&e, "perfect_score");
+    let read = || {
+        e.as_contract(&attestation_id, || {
+            (
+                AttestationEngineContract::get_health_metrics(e.clone(), commitment_id.clone()),
+                AttestationEngineContract::verify_compliance(e.clone(), commitment_id.clone()),
+            )
+        })
+    };
 
-    // Setup
-    e.as_contract(&attestation_id, || {
-        AttestationEngineContract::initialize(e.clone(), admin.clone(), core_id.clone()).unwrap();
-        AttestationEngineContract::add_verifier(e.clone(), admin.clone(), verifier.clone()).unwrap();
-    });
+    let (metrics, compliant) = read();
+    assert_eq!(metrics.drawdown_bps, 0);
+    assert_eq!(metrics.drawdown_percent, 0);
+    assert_eq!(metrics.max_drawdown_percent, 0);
+    assert_eq!(metrics.compliance_score, 100);
+    assert!(compliant);
 
-    let commitment = create_mock_commitment_with_status(
+    // An attested gain is clamped the same way.
+    let mut data = Map::new(&e);
+    data.set(ts(&e, "drawdown_percent"), ts(&e, "-20"));
+    let mut attestations = Vec::new(&e);
+    attestations.push_back(attestation_with_data(
         &e,
-        "perfect_score",
-        "active",
-        1000,
-        1000, // No loss
+        &commitment_id,
+        4_000,
+        "drawdown",
+        true,
+        data,
+    ));
+    store_attestations(&e, &attestation_id, &commitment_id, attestations);
+
+    let (metrics, compliant) = read();
+    assert_eq!(metrics.drawdown_bps, 0);
+    assert_eq!(metrics.compliance_score, 100);
+    assert!(compliant);
+}
+
+#[test]
+fn test_compliance_scoring_perfect_score() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    let core_id = e.register_contract(None, commitment_core::CommitmentCoreContract);
+
+    let admin = Address::generate(&e);
+    let verifier = Address::generate(&e);
+    let commitment_id = String::from_str(&e, "perfect_score");
+
+    // Setup
+    e.as_contract(&attestation_id, || {
+        AttestationEngineContract::initialize(e.clone(), admin.clone(), core_id.clone()).unwrap();
+        AttestationEngineContract::add_verifier(e.clone(), admin.clone(), verifier.clone()).unwrap();
+    });
+
+    let commitment = create_mock_commitment_with_status(
+        &e,
+        "perfect_score",
+        CommitmentStatus::Active,
+        1000,
+        1000, // No loss
         10,
     );
     e.as_contract(&core_id, || {
@@ -1833,7 +2336,7 @@ fn test_compliance_scoring_with_violations() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "violations_score",
-        "active",
+        CommitmentStatus::Active,
         1000,
         1000,
         10,
@@ -1906,7 +2409,7 @@ fn test_compliance_scoring_with_drawdown_exceeding_threshold() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "drawdown_score",
-        "active",
+        CommitmentStatus::Active,
         1000,
         800, // 20% loss
         10,
@@ -1946,7 +2449,7 @@ fn test_compliance_scoring_with_fee_performance() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "fee_performance_score",
-        "active",
+        CommitmentStatus::Active,
         1000,
         1000,
         10,
@@ -1998,7 +2501,7 @@ fn test_compliance_scoring_minimum_score() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "minimum_score",
-        "active",
+        CommitmentStatus::Active,
         1000,
         500, // 50% loss
         10,
@@ -2056,7 +2559,7 @@ fn test_compliance_scoring_stored_metrics_priority() {
     let commitment = create_mock_commitment_with_status(
         &e,
         "stored_metrics_test",
-        "active",
+        CommitmentStatus::Active,
         1000,
         1000,
         10,
@@ -2095,6 +2598,8 @@ fn test_compliance_scoring_stored_metrics_priority() {
         current_value: 1000,
         initial_value: 1000,
         drawdown_percent: 0,
+        drawdown_bps: 0,
+        max_drawdown_percent: 0,
         fees_generated: 0,
         volatility_exposure: 0,
         last_attestation: e.ledger().timestamp(),
@@ -2114,3 +2619,1195 @@ fn test_compliance_scoring_stored_metrics_priority() {
     assert_eq!(stored_score, 25);
     assert_ne!(stored_score, initial_score);
 }
+
+#[test]
+fn test_bogus_core_address_degrades_gracefully() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    // A live contract that does not implement `get_commitment`.
+    let bogus_core = e.register_contract(None, AttestationEngineContract);
+    let client = AttestationEngineContractClient::new(&e, &attestation_id);
+
+    let admin = Address::generate(&e);
+    let commitment_id = String::from_str(&e, "bogus_core_commitment");
+    client.initialize(&admin, &bogus_core);
+    client.add_verifier(&admin, &admin);
+
+    assert!(!client.verify_compliance(&commitment_id));
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 100);
+
+    let metrics = client.get_health_metrics(&commitment_id);
+    assert_eq!(metrics.initial_value, 0);
+    assert_eq!(metrics.current_value, 0);
+    assert_eq!(metrics.drawdown_bps, 0);
+
+    assert_eq!(
+        client.try_record_drawdown(&admin, &commitment_id, &5),
+        Err(Ok(AttestationError::CommitmentNotFound))
+    );
+    // The reentrancy guard must not be left behind by the failed read.
+    assert_eq!(
+        client.try_record_drawdown(&admin, &commitment_id, &5),
+        Err(Ok(AttestationError::CommitmentNotFound))
+    );
+}
+
+#[test]
+fn test_non_contract_core_address_degrades_gracefully() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    let client = AttestationEngineContractClient::new(&e, &attestation_id);
+
+    let admin = Address::generate(&e);
+    let not_a_contract = Address::generate(&e);
+    let commitment_id = String::from_str(&e, "missing_core_commitment");
+    client.initialize(&admin, &not_a_contract);
+
+    assert!(!client.verify_compliance(&commitment_id));
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 100);
+}
+
+fn setup_weighted_engine(
+    e: &Env,
+    ids: &[&str],
+) -> (AttestationEngineContractClient<'static>, Address) {
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    let core_id = e.register_contract(None, commitment_core::CommitmentCoreContract);
+    let client = AttestationEngineContractClient::new(e, &attestation_id);
+    let admin = Address::generate(e);
+
+    client.initialize(&admin, &core_id);
+    client.add_verifier(&admin, &admin);
+
+    for id in ids {
+        let commitment = create_mock_commitment_with_status_internal(
+            e,
+            id,
+            CommitmentStatus::Active,
+            1_000,
+            1_000,
+            10,
+        );
+        e.as_contract(&core_id, || {
+            e.storage().instance().set(
+                &commitment_core::DataKey::Commitment(String::from_str(e, id)),
+                &commitment,
+            );
+        });
+    }
+
+    (client, admin)
+}
+
+fn violation_data(e: &Env, severity: &str) -> Map<String, String> {
+    let mut data = Map::new(e);
+    data.set(
+        String::from_str(e, "violation_type"),
+        String::from_str(e, "max_loss_exceeded"),
+    );
+    data.set(
+        String::from_str(e, "severity"),
+        String::from_str(e, severity),
+    );
+    data
+}
+
+#[test]
+fn test_violation_attestation_lowers_score_more_than_health_check() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["weight_health", "weight_violation"]);
+    let health_id = String::from_str(&e, "weight_health");
+    let violation_id = String::from_str(&e, "weight_violation");
+
+    client.attest(
+        &admin,
+        &health_id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &false,
+    );
+    client.attest(
+        &admin,
+        &violation_id,
+        &String::from_str(&e, "violation"),
+        &violation_data(&e, "medium"),
+        &false,
+    );
+
+    let health_score = client
+        .get_stored_health_metrics(&health_id)
+        .unwrap()
+        .compliance_score;
+    let violation_score = client
+        .get_stored_health_metrics(&violation_id)
+        .unwrap()
+        .compliance_score;
+    assert_eq!(health_score, 100);
+    assert_eq!(violation_score, 100 - DEFAULT_VIOLATION_WEIGHT);
+    assert!(violation_score < health_score);
+}
+
+#[test]
+fn test_configured_attestation_weights_drive_score_updates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["weight_custom"]);
+    let commitment_id = String::from_str(&e, "weight_custom");
+    let violation = String::from_str(&e, "violation");
+    let health_check = String::from_str(&e, "health_check");
+
+    assert_eq!(
+        client.get_attestation_weight(&violation),
+        DEFAULT_VIOLATION_WEIGHT
+    );
+    assert_eq!(
+        client.get_attestation_weight(&health_check),
+        DEFAULT_COMPLIANT_WEIGHT
+    );
+
+    client.set_attestation_weight(&admin, &violation, &40);
+    client.set_attestation_weight(&admin, &health_check, &5);
+
+    client.attest(
+        &admin,
+        &commitment_id,
+        &violation,
+        &violation_data(&e, "high"),
+        &false,
+    );
+    let after_violation = client.get_stored_health_metrics(&commitment_id).unwrap();
+    assert_eq!(after_violation.compliance_score, 40);
+
+    client.attest(&admin, &commitment_id, &health_check, &Map::new(&e), &true);
+    let after_health = client.get_stored_health_metrics(&commitment_id).unwrap();
+    assert_eq!(after_health.compliance_score, 45);
+}
+
+#[test]
+fn test_set_attestation_weight_validation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &[]);
+    let stranger = Address::generate(&e);
+    let violation = String::from_str(&e, "violation");
+
+    assert_eq!(
+        client.try_set_attestation_weight(&stranger, &violation, &10),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_attestation_weight(&admin, &String::from_str(&e, "bogus"), &10),
+        Err(Ok(AttestationError::InvalidAttestationType))
+    );
+    assert_eq!(
+        client.try_set_attestation_weight(&admin, &violation, &(MAX_ATTESTATION_WEIGHT + 1)),
+        Err(Ok(AttestationError::InvalidAttestationWeight))
+    );
+    assert_eq!(
+        client.get_attestation_weight(&violation),
+        DEFAULT_VIOLATION_WEIGHT
+    );
+}
+
+#[test]
+fn test_attested_commitment_ids_index_each_commitment_once() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["export_a", "export_b", "export_c"]);
+    let health_check = String::from_str(&e, "health_check");
+    let a = String::from_str(&e, "export_a");
+    let b = String::from_str(&e, "export_b");
+    let c = String::from_str(&e, "export_c");
+
+    assert_eq!(client.get_attested_commitment_ids(&0, &10).len(), 0);
+
+    client.attest(&admin, &a, &health_check, &Map::new(&e), &true);
+    client.attest(&admin, &b, &health_check, &Map::new(&e), &true);
+    client.attest(&admin, &a, &health_check, &Map::new(&e), &true);
+    client.record_fees(&admin, &b, &10);
+
+    assert_eq!(
+        client.get_attested_commitment_ids(&0, &10),
+        soroban_sdk::vec![&e, a.clone(), b.clone()]
+    );
+
+    client.attest(&admin, &c, &health_check, &Map::new(&e), &true);
+    assert_eq!(
+        client.get_attested_commitment_ids(&0, &10),
+        soroban_sdk::vec![&e, a.clone(), b.clone(), c.clone()]
+    );
+
+    // Paging.
+    assert_eq!(
+        client.get_attested_commitment_ids(&1, &1),
+        soroban_sdk::vec![&e, b]
+    );
+    assert_eq!(
+        client.get_attested_commitment_ids(&2, &10),
+        soroban_sdk::vec![&e, c]
+    );
+    assert_eq!(client.get_attested_commitment_ids(&3, &10).len(), 0);
+    assert_eq!(client.get_attested_commitment_ids(&0, &0).len(), 0);
+}
+
+#[test]
+fn test_attested_commitment_index_is_chunked() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _) = setup_weighted_engine(&e, &[]);
+    let total = ID_INDEX_CHUNK_SIZE * 2 + 5;
+    let id_at = |i: u32| String::from_str(&e, if i.is_multiple_of(2) { "even" } else { "odd" });
+
+    e.as_contract(&client.address, || {
+        for i in 0..total {
+            AttestationEngineContract::index_attested_commitment(&e, &id_at(i));
+        }
+        // No chunk outgrows the chunk size.
+        for chunk in 0..3 {
+            let ids: Vec<String> = e
+                .storage()
+                .persistent()
+                .get(&DataKey::AllCommitmentIdsWithAttestations(chunk))
+                .unwrap();
+            assert!(ids.len() <= ID_INDEX_CHUNK_SIZE);
+        }
+    });
+
+    // A page straddling a chunk boundary is stitched together in order.
+    let start = ID_INDEX_CHUNK_SIZE - 3;
+    let page = client.get_attested_commitment_ids(&start, &6);
+    assert_eq!(page.len(), 6);
+    for (i, id) in page.iter().enumerate() {
+        assert_eq!(id, id_at(start + i as u32));
+    }
+    assert_eq!(
+        client.get_attested_commitment_ids(&(total - 2), &10).len(),
+        2
+    );
+}
+
+fn fill_attestations(e: &Env, client: &AttestationEngineContractClient, id: &str, count: u32) {
+    let commitment_id = String::from_str(e, id);
+    let verifier = Address::generate(e);
+    let mut attestations = Vec::new(e);
+    for i in 0..count {
+        attestations.push_back(Attestation {
+            commitment_id: commitment_id.clone(),
+            timestamp: i as u64,
+            attestation_type: String::from_str(e, "health_check"),
+            data: Map::new(e),
+            is_compliant: true,
+            verified_by: verifier.clone(),
+            is_revoked: false,
+        });
+    }
+    // Write whole chunks directly; appending one by one would exhaust the test budget.
+    e.as_contract(&client.address, || {
+        let mut start = 0;
+        while start < count {
+            let end = (start + CHUNK_SIZE).min(count);
+            e.storage().persistent().set(
+                &DataKey::AttestationChunk(commitment_id.clone(), start / CHUNK_SIZE),
+                &attestations.slice(start..end),
+            );
+            start = end;
+        }
+        e.storage().persistent().set(
+            &DataKey::AttestationCounter(commitment_id.clone()),
+            &(count as u64),
+        );
+        AttestationEngineContract::index_attested_commitment(e, &commitment_id);
+    });
+}
+
+fn stored_attestations(e: &Env, client: &AttestationEngineContractClient, id: &str) -> u32 {
+    e.as_contract(&client.address, || {
+        AttestationEngineContract::load_attestations_from_storage(e, &String::from_str(e, id)).len()
+    })
+}
+
+#[test]
+fn test_attest_rejected_at_attestation_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["capped"]);
+    let id = String::from_str(&e, "capped");
+    let health_check = String::from_str(&e, "health_check");
+
+    fill_attestations(&e, &client, "capped", MAX_ATTESTATIONS_PER_COMMITMENT - 1);
+    client.attest(&admin, &id, &health_check, &Map::new(&e), &true);
+    assert_eq!(
+        stored_attestations(&e, &client, "capped"),
+        MAX_ATTESTATIONS_PER_COMMITMENT
+    );
+
+    assert_eq!(
+        client.try_attest(&admin, &id, &health_check, &Map::new(&e), &true),
+        Err(Ok(AttestationError::TooManyAttestations))
+    );
+    assert_eq!(
+        stored_attestations(&e, &client, "capped"),
+        MAX_ATTESTATIONS_PER_COMMITMENT
+    );
+}
+
+#[test]
+fn test_batch_attest_reports_attestation_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["capped", "open"]);
+    fill_attestations(&e, &client, "capped", MAX_ATTESTATIONS_PER_COMMITMENT);
+
+    let mut params = Vec::new(&e);
+    for id in ["capped", "open"] {
+        params.push_back(AttestParams {
+            commitment_id: String::from_str(&e, id),
+            attestation_type: String::from_str(&e, "health_check"),
+            data: Map::new(&e),
+            is_compliant: true,
+        });
+    }
+
+    let result = client.batch_attest(&admin, &params, &BatchMode::BestEffort);
+    assert!(!result.success);
+    assert_eq!(result.errors.len(), 1);
+    let error = result.errors.get(0).unwrap();
+    assert_eq!(error.index, 0);
+    assert_eq!(
+        error.error_code,
+        AttestationError::TooManyAttestations as u32
+    );
+    assert_eq!(
+        stored_attestations(&e, &client, "capped"),
+        MAX_ATTESTATIONS_PER_COMMITMENT
+    );
+    assert_eq!(stored_attestations(&e, &client, "open"), 1);
+}
+
+#[test]
+fn test_attestations_span_chunks_in_order() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["chunked"]);
+    let id = String::from_str(&e, "chunked");
+
+    fill_attestations(&e, &client, "chunked", CHUNK_SIZE + 4);
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &true,
+    );
+    let total = CHUNK_SIZE + 5;
+    assert_eq!(client.get_attestation_count(&id), total as u64);
+
+    e.as_contract(&client.address, || {
+        let first = AttestationEngineContract::load_attestation_chunk(&e, &id, 0);
+        let second = AttestationEngineContract::load_attestation_chunk(&e, &id, 1);
+        assert_eq!(first.len(), CHUNK_SIZE);
+        assert_eq!(second.len(), 5);
+    });
+
+    // A page straddling the boundary is stitched together oldest first.
+    let page = client.get_attestations_page(&id, &(CHUNK_SIZE - 2), &4);
+    assert_eq!(page.attestations.len(), 4);
+    for (i, attestation) in page.attestations.iter().enumerate() {
+        assert_eq!(attestation.timestamp, (CHUNK_SIZE - 2) as u64 + i as u64);
+    }
+    assert_eq!(page.next_offset, CHUNK_SIZE + 2);
+    assert_eq!(
+        client.get_attestation(&id, &CHUNK_SIZE).timestamp,
+        CHUNK_SIZE as u64
+    );
+    assert_eq!(
+        client.try_get_attestation(&id, &total),
+        Err(Ok(AttestationError::AttestationIndexOutOfRange))
+    );
+    assert_eq!(stored_attestations(&e, &client, "chunked"), total);
+}
+
+#[test]
+fn test_legacy_attestation_list_is_rechunked_on_first_access() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["legacy"]);
+    let id = String::from_str(&e, "legacy");
+    let legacy_len = CHUNK_SIZE + 3;
+
+    // Version 1 layout: one Vec without `is_revoked`, plus a matching counter.
+    let verifier = Address::generate(&e);
+    let mut legacy = Vec::new(&e);
+    for i in 0..legacy_len {
+        legacy.push_back(LegacyAttestation {
+            commitment_id: id.clone(),
+            timestamp: i as u64,
+            attestation_type: String::from_str(&e, "health_check"),
+            data: Map::new(&e),
+            is_compliant: true,
+            verified_by: verifier.clone(),
+        });
+    }
+    e.as_contract(&client.address, || {
+        e.storage()
+            .persistent()
+            .set(&DataKey::Attestations(id.clone()), &legacy);
+        e.storage().persistent().set(
+            &DataKey::AttestationCounter(id.clone()),
+            &(legacy_len as u64),
+        );
+    });
+
+    assert_eq!(client.get_attestation_count(&id), legacy_len as u64);
+    let last_legacy = client.get_attestation(&id, &(legacy_len - 1));
+    assert_eq!(last_legacy.timestamp, (legacy_len - 1) as u64);
+    assert!(!last_legacy.is_revoked);
+
+    // The next attestation lands after the legacy entries, not over them.
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &true,
+    );
+    assert_eq!(client.get_attestation_count(&id), legacy_len as u64 + 1);
+    assert_eq!(client.get_attestation(&id, &legacy_len).verified_by, admin);
+    assert_eq!(client.get_attestation(&id, &0).timestamp, 0);
+    e.as_contract(&client.address, || {
+        assert!(!e
+            .storage()
+            .persistent()
+            .has(&DataKey::Attestations(id.clone())));
+    });
+}
+
+#[test]
+fn test_attest_rejects_oversized_data() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["bounded"]);
+    let id = String::from_str(&e, "bounded");
+    let health_check = String::from_str(&e, "health_check");
+
+    let mut too_many_keys = Map::new(&e);
+    for i in 0..=MAX_ATTESTATION_DATA_KEYS {
+        let key = String::from_bytes(&e, &[b'a' + (i % 26) as u8, b'0' + (i / 26) as u8]);
+        too_many_keys.set(key, String::from_str(&e, "x"));
+    }
+    assert_eq!(
+        client.try_attest(&admin, &id, &health_check, &too_many_keys, &true),
+        Err(Ok(AttestationError::InvalidAttestationData))
+    );
+
+    let mut too_many_bytes = Map::new(&e);
+    let big = [b'x'; MAX_ATTESTATION_DATA_BYTES as usize];
+    too_many_bytes.set(String::from_str(&e, "note"), String::from_bytes(&e, &big));
+    assert_eq!(
+        client.try_attest(&admin, &id, &health_check, &too_many_bytes, &true),
+        Err(Ok(AttestationError::InvalidAttestationData))
+    );
+
+    let mut params = Vec::new(&e);
+    params.push_back(AttestParams {
+        commitment_id: id.clone(),
+        attestation_type: health_check.clone(),
+        data: too_many_keys,
+        is_compliant: true,
+    });
+    let result = client.batch_attest(&admin, &params, &BatchMode::BestEffort);
+    assert_eq!(
+        result.errors.get(0).unwrap().error_code,
+        AttestationError::InvalidAttestationData as u32
+    );
+    assert_eq!(client.get_attestation_count(&id), 0);
+}
+
+fn health_check_batch(e: &Env, id: &str, count: u32) -> Vec<AttestParams> {
+    let mut params = Vec::new(e);
+    for _ in 0..count {
+        params.push_back(AttestParams {
+            commitment_id: String::from_str(e, id),
+            attestation_type: String::from_str(e, "health_check"),
+            data: Map::new(e),
+            is_compliant: true,
+        });
+    }
+    params
+}
+
+#[test]
+fn test_batch_attest_enforces_configured_max_batch_size() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["batched"]);
+    assert_eq!(client.get_max_batch_size(), 50);
+
+    client.set_max_batch_size(&admin, &2);
+    assert_eq!(client.get_max_batch_size(), 2);
+
+    let below = client.batch_attest(
+        &admin,
+        &health_check_batch(&e, "batched", 1),
+        &BatchMode::Atomic,
+    );
+    assert!(below.success);
+    let at = client.batch_attest(
+        &admin,
+        &health_check_batch(&e, "batched", 2),
+        &BatchMode::Atomic,
+    );
+    assert!(at.success);
+    assert_eq!(stored_attestations(&e, &client, "batched"), 3);
+
+    let above = client.batch_attest(
+        &admin,
+        &health_check_batch(&e, "batched", 3),
+        &BatchMode::Atomic,
+    );
+    assert!(!above.success);
+    // Shared batch code for "batch too large".
+    assert_eq!(above.errors.get(0).unwrap().error_code, 2);
+    assert_eq!(stored_attestations(&e, &client, "batched"), 3);
+}
+
+#[test]
+fn test_set_max_batch_size_requires_admin_and_positive_value() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &[]);
+    let stranger = Address::generate(&e);
+
+    assert_eq!(
+        client.try_set_max_batch_size(&stranger, &10),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_max_batch_size(&admin, &0),
+        Err(Ok(AttestationError::InvalidBatchSize))
+    );
+    assert_eq!(client.get_max_batch_size(), 50);
+}
+
+#[test]
+fn test_health_metrics_max_drawdown_survives_recovery() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["peak"]);
+    let id = String::from_str(&e, "peak");
+
+    client.record_drawdown(&admin, &id, &5);
+    client.record_drawdown(&admin, &id, &8);
+    client.record_drawdown(&admin, &id, &2);
+
+    let metrics = client.get_health_metrics(&id);
+    assert_eq!(metrics.drawdown_percent, 2);
+    assert_eq!(metrics.max_drawdown_percent, 8);
+}
+
+#[test]
+fn test_health_metrics_max_drawdown_includes_core_peak() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let attestation_id = e.register_contract(None, AttestationEngineContract);
+    let core_id = e.register_contract(None, commitment_core::CommitmentCoreContract);
+    let client = AttestationEngineContractClient::new(&e, &attestation_id);
+    client.initialize(&Address::generate(&e), &core_id);
+
+    // Core saw a 9% drawdown earlier; the value has since recovered.
+    let mut commitment = create_mock_commitment_with_status_internal(
+        &e,
+        "core_peak",
+        CommitmentStatus::Active,
+        1_000,
+        990,
+        10,
+    );
+    commitment.max_drawdown_percent = 9;
+    let id = String::from_str(&e, "core_peak");
+    e.as_contract(&core_id, || {
+        e.storage().instance().set(
+            &commitment_core::DataKey::Commitment(id.clone()),
+            &commitment,
+        );
+    });
+
+    let metrics = client.get_health_metrics(&id);
+    assert_eq!(metrics.drawdown_percent, 1);
+    assert_eq!(metrics.max_drawdown_percent, 9);
+}
+
+fn event_count(e: &Env, topic: Symbol) -> usize {
+    use soroban_sdk::{testutils::Events, TryFromVal};
+    e.events()
+        .all()
+        .iter()
+        .filter(|ev| {
+            ev.1.get(0)
+                .map(|t| Symbol::try_from_val(e, &t).ok() == Some(topic.clone()))
+                .unwrap_or(false)
+        })
+        .count()
+}
+
+#[test]
+fn test_disabled_events_suppress_score_updates_only() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["quiet"]);
+    let id = String::from_str(&e, "quiet");
+
+    assert!(client.is_events_enabled());
+    client.calculate_compliance_score(&id);
+    let score_events = event_count(&e, symbol_short!("ScoreUpd"));
+    assert!(score_events > 0);
+
+    client.set_events_enabled(&admin, &false);
+    assert!(!client.is_events_enabled());
+
+    client.calculate_compliance_score(&id);
+    assert_eq!(event_count(&e, symbol_short!("ScoreUpd")), score_events);
+
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "violation"),
+        &violation_data(&e, "high"),
+        &false,
+    );
+    assert!(event_count(&e, Symbol::new(&e, "AttestationRecorded")) > 0);
+
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_set_events_enabled(&stranger, &true),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_verify_compliance_flags_stale_value_without_failing() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _) = setup_weighted_engine(&e, &["fresh", "stale"]);
+    let core_id = client.get_core_contract();
+    e.as_contract(&core_id, || {
+        e.storage()
+            .instance()
+            .set(&commitment_core::DataKey::MaxValueAgeSecs, &3_600u64);
+        let key = commitment_core::DataKey::Commitment(String::from_str(&e, "fresh"));
+        let mut fresh: commitment_core::Commitment = e.storage().instance().get(&key).unwrap();
+        fresh.last_update_ts = 10_000;
+        e.storage().instance().set(&key, &fresh);
+    });
+    e.ledger().with_mut(|l| {
+        l.timestamp = 10_000;
+    });
+
+    assert!(client.verify_compliance(&String::from_str(&e, "fresh")));
+    assert_eq!(event_count(&e, Symbol::new(&e, "ValueStale")), 0);
+
+    assert!(client.verify_compliance(&String::from_str(&e, "stale")));
+    assert_eq!(event_count(&e, Symbol::new(&e, "ValueStale")), 1);
+}
+
+#[test]
+fn test_upsert_attestation_replaces_within_window_and_appends_after() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["upsert"]);
+    let id = String::from_str(&e, "upsert");
+    let health_check = String::from_str(&e, "health_check");
+    client.set_upsert_window(&admin, &600);
+    assert_eq!(client.get_upsert_window(), 600);
+    e.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    client.upsert_attestation(&admin, &id, &health_check, &Map::new(&e), &true);
+    assert_eq!(stored_attestations(&e, &client, "upsert"), 1);
+
+    e.ledger().with_mut(|l| l.timestamp = 1_500);
+    client.upsert_attestation(&admin, &id, &health_check, &Map::new(&e), &false);
+    let attestations = client.get_attestations(&id);
+    assert_eq!(attestations.len(), 1);
+    assert_eq!(attestations.get(0).unwrap().timestamp, 1_500);
+    assert!(!attestations.get(0).unwrap().is_compliant);
+    assert_eq!(event_count(&e, Symbol::new(&e, "AttestationUpdated")), 1);
+
+    e.ledger().with_mut(|l| l.timestamp = 2_101);
+    client.upsert_attestation(&admin, &id, &health_check, &Map::new(&e), &true);
+    assert_eq!(stored_attestations(&e, &client, "upsert"), 2);
+
+    client.attest(&admin, &id, &health_check, &Map::new(&e), &true);
+    assert_eq!(stored_attestations(&e, &client, "upsert"), 3);
+}
+
+fn fee_data(e: &Env, amount: &str) -> Map<String, String> {
+    let mut data = Map::new(e);
+    data.set(
+        String::from_str(e, "fee_amount"),
+        String::from_str(e, amount),
+    );
+    data
+}
+
+#[test]
+fn test_upsert_fee_correction_matches_single_attestation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (corrected, admin) = setup_weighted_engine(&e, &["fees"]);
+    let (single, single_admin) = setup_weighted_engine(&e, &["fees"]);
+    let id = String::from_str(&e, "fees");
+    let fee_generation = String::from_str(&e, "fee_generation");
+    let health_check = String::from_str(&e, "health_check");
+    corrected.set_upsert_window(&admin, &600);
+
+    corrected.attest(&admin, &id, &fee_generation, &fee_data(&e, "500"), &true);
+    corrected.upsert_attestation(&admin, &id, &fee_generation, &fee_data(&e, "300"), &true);
+    corrected.attest(&admin, &id, &health_check, &Map::new(&e), &false);
+    corrected.upsert_attestation(&admin, &id, &health_check, &Map::new(&e), &true);
+
+    single.attest(
+        &single_admin,
+        &id,
+        &fee_generation,
+        &fee_data(&e, "300"),
+        &true,
+    );
+    single.attest(&single_admin, &id, &health_check, &Map::new(&e), &true);
+
+    assert_eq!(stored_attestations(&e, &corrected, "fees"), 2);
+    assert_eq!(corrected.get_protocol_statistics(), (0, 2, 0, 300));
+    assert_eq!(
+        corrected.get_protocol_statistics(),
+        single.get_protocol_statistics()
+    );
+    let metrics = corrected.get_health_metrics(&id);
+    let expected = single.get_health_metrics(&id);
+    assert_eq!(metrics.fees_generated, 300);
+    assert_eq!(metrics.fees_generated, expected.fees_generated);
+    assert_eq!(metrics.compliance_score, expected.compliance_score);
+    assert_eq!(
+        corrected.calculate_compliance_score(&id),
+        single.calculate_compliance_score(&id)
+    );
+}
+
+#[test]
+fn test_upsert_attestation_appends_when_window_unset() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["no_window"]);
+    let id = String::from_str(&e, "no_window");
+    let health_check = String::from_str(&e, "health_check");
+
+    client.upsert_attestation(&admin, &id, &health_check, &Map::new(&e), &true);
+    client.upsert_attestation(&admin, &id, &health_check, &Map::new(&e), &true);
+    assert_eq!(stored_attestations(&e, &client, "no_window"), 2);
+
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_set_upsert_window(&stranger, &60),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_violation_stays_pending_until_required_signers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["cosigned"]);
+    let id = String::from_str(&e, "cosigned");
+    let violation = String::from_str(&e, "violation");
+    let second = Address::generate(&e);
+    client.add_verifier(&admin, &second);
+    client.set_required_signers(&admin, &2);
+    assert_eq!(client.get_required_signers(), 2);
+
+    client.attest(&admin, &id, &violation, &violation_data(&e, "high"), &false);
+    assert_eq!(stored_attestations(&e, &client, "cosigned"), 0);
+    assert_eq!(client.calculate_compliance_score(&id), 100);
+    let pending = client.get_pending_violations(&id);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().signers.len(), 1);
+    assert_eq!(
+        client.try_attest(&admin, &id, &violation, &violation_data(&e, "high"), &false),
+        Err(Ok(AttestationError::AlreadySigned))
+    );
+
+    // Different data is a separate pending violation.
+    client.attest(&second, &id, &violation, &violation_data(&e, "low"), &false);
+    assert_eq!(client.get_pending_violations(&id).len(), 2);
+    assert_eq!(stored_attestations(&e, &client, "cosigned"), 0);
+
+    client.attest(
+        &second,
+        &id,
+        &violation,
+        &violation_data(&e, "high"),
+        &false,
+    );
+    assert_eq!(stored_attestations(&e, &client, "cosigned"), 1);
+    assert_eq!(client.get_pending_violations(&id).len(), 1);
+    assert!(client.calculate_compliance_score(&id) < 100);
+}
+
+#[test]
+fn test_record_drawdown_breach_needs_quorum_to_count() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["quorum"]);
+    let id = String::from_str(&e, "quorum");
+    let second = Address::generate(&e);
+    client.add_verifier(&admin, &second);
+    client.set_required_signers(&admin, &2);
+
+    let initial_score = client.get_health_metrics(&id).compliance_score;
+    client.record_drawdown(&admin, &id, &15);
+    assert_eq!(stored_attestations(&e, &client, "quorum"), 0);
+    assert_eq!(
+        client.get_health_metrics(&id).compliance_score,
+        initial_score
+    );
+    assert_eq!(client.get_pending_violations(&id).len(), 1);
+    assert_eq!(client.get_violation_history(&id).len(), 0);
+    assert_eq!(event_count(&e, Symbol::new(&e, "ViolationRecorded")), 0);
+    assert_eq!(
+        client.try_record_drawdown(&admin, &id, &15),
+        Err(Ok(AttestationError::AlreadySigned))
+    );
+
+    client.record_drawdown(&second, &id, &15);
+    assert_eq!(stored_attestations(&e, &client, "quorum"), 2);
+    let confirmed = client.get_attestations(&id);
+    assert_eq!(
+        confirmed.get(0).unwrap().attestation_type,
+        String::from_str(&e, "drawdown")
+    );
+    assert!(client.get_health_metrics(&id).compliance_score < initial_score);
+    assert_eq!(client.get_pending_violations(&id).len(), 0);
+    assert_eq!(client.get_violation_history(&id).len(), 1);
+    assert_eq!(event_count(&e, Symbol::new(&e, "ViolationRecorded")), 1);
+}
+
+#[test]
+fn test_set_required_signers_requires_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _) = setup_weighted_engine(&e, &[]);
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_set_required_signers(&stranger, &3),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(client.get_required_signers(), 1);
+}
+
+#[test]
+fn test_has_health_metrics_distinguishes_unattested_commitments() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["attested", "unattested"]);
+    let attested = String::from_str(&e, "attested");
+    let unattested = String::from_str(&e, "unattested");
+
+    client.attest(
+        &admin,
+        &attested,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &true,
+    );
+
+    assert!(client.has_health_metrics(&attested));
+    assert!(!client.has_health_metrics(&unattested));
+    assert!(!client.has_health_metrics(&String::from_str(&e, "unknown")));
+    assert_eq!(
+        client
+            .get_health_metrics(&String::from_str(&e, "unknown"))
+            .last_attestation,
+        0
+    );
+}
+
+#[test]
+fn test_compliance_score_decays_without_fresh_attestations() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["decay_stale", "decay_fresh"]);
+    let stale = String::from_str(&e, "decay_stale");
+    let fresh = String::from_str(&e, "decay_fresh");
+    let health_check = String::from_str(&e, "health_check");
+    e.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.attest(&admin, &stale, &health_check, &Map::new(&e), &true);
+    client.attest(&admin, &fresh, &health_check, &Map::new(&e), &true);
+
+    e.ledger().with_mut(|l| l.timestamp = 1_000 + 10 * 86_400);
+    // Decay is off by default.
+    assert_eq!(client.calculate_compliance_score(&stale), 100);
+
+    client.set_score_decay(&admin, &5, &40);
+    assert_eq!(
+        client.get_score_decay(),
+        ScoreDecayConfig {
+            points_per_day: 5,
+            floor: 40
+        }
+    );
+    client.attest(&admin, &fresh, &health_check, &Map::new(&e), &true);
+    assert_eq!(client.calculate_compliance_score(&stale), 50);
+    assert_eq!(client.calculate_compliance_score(&fresh), 100);
+
+    e.ledger().with_mut(|l| l.timestamp = 1_000 + 30 * 86_400);
+    assert_eq!(client.calculate_compliance_score(&stale), 40);
+}
+
+#[test]
+fn test_set_score_decay_validates_input() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &[]);
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_set_score_decay(&stranger, &1, &0),
+        Err(Ok(AttestationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_score_decay(&admin, &1, &101),
+        Err(Ok(AttestationError::InvalidComplianceThreshold))
+    );
+    assert_eq!(client.get_score_decay().points_per_day, 0);
+}
+
+#[test]
+fn test_violation_history_records_each_new_drawdown_breach_in_order() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["history"]);
+    let id = String::from_str(&e, "history");
+
+    for (ts, drawdown) in [(100u64, 5i128), (200, 15), (300, 20), (400, 5), (500, 30)] {
+        e.ledger().with_mut(|l| l.timestamp = ts);
+        client.record_drawdown(&admin, &id, &drawdown);
+    }
+
+    let history = client.get_violation_history(&id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap(), (200, 15, 10));
+    assert_eq!(history.get(1).unwrap(), (500, 30, 10));
+    assert_eq!(
+        client
+            .get_violation_history(&String::from_str(&e, "unknown"))
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_expired_pending_violation_is_dropped_on_next_attest() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["expiring"]);
+    let id = String::from_str(&e, "expiring");
+    client.set_required_signers(&admin, &2);
+    assert_eq!(client.get_pending_ttl(), DEFAULT_PENDING_TTL_SECS);
+    client.set_pending_ttl(&admin, &3_600);
+
+    e.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "violation"),
+        &violation_data(&e, "high"),
+        &false,
+    );
+    assert_eq!(client.get_pending_violations(&id).len(), 1);
+
+    // Still inside the TTL: kept.
+    e.ledger().with_mut(|l| l.timestamp = 4_600);
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &true,
+    );
+    assert_eq!(client.get_pending_violations(&id).len(), 1);
+
+    e.ledger().with_mut(|l| l.timestamp = 4_601);
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &true,
+    );
+    assert_eq!(client.get_pending_violations(&id).len(), 0);
+    assert_eq!(event_count(&e, Symbol::new(&e, "PendingExpired")), 1);
+}
+
+#[test]
+fn test_pending_violations_are_capped_per_commitment() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["crowded"]);
+    let id = String::from_str(&e, "crowded");
+    let violation = String::from_str(&e, "violation");
+    client.set_required_signers(&admin, &2);
+
+    for i in 0..MAX_PENDING_VIOLATIONS {
+        let mut data = violation_data(&e, "low");
+        data.set(
+            String::from_str(&e, "detail"),
+            AttestationEngineContract::i128_to_string(&e, i as i128),
+        );
+        client.attest(&admin, &id, &violation, &data, &false);
+    }
+    assert_eq!(
+        client.get_pending_violations(&id).len(),
+        MAX_PENDING_VIOLATIONS
+    );
+    assert_eq!(
+        client.try_attest(&admin, &id, &violation, &violation_data(&e, "high"), &false),
+        Err(Ok(AttestationError::TooManyPendingAttestations))
+    );
+}
+
+#[test]
+fn test_get_attestation_by_index_matches_page_order() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["indexed"]);
+    let id = String::from_str(&e, "indexed");
+    let health_check = String::from_str(&e, "health_check");
+
+    e.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.attest(&admin, &id, &health_check, &Map::new(&e), &true);
+    e.ledger().with_mut(|l| l.timestamp = 2_000);
+    client.attest(&admin, &id, &health_check, &Map::new(&e), &false);
+
+    let page = client.get_attestations_page(&id, &0, &10).attestations;
+    assert_eq!(client.get_attestation(&id, &0), page.get(0).unwrap());
+    let second = client.get_attestation(&id, &1);
+    assert_eq!(second, page.get(1).unwrap());
+    assert_eq!(second.timestamp, 2_000);
+    assert!(!second.is_compliant);
+}
+
+#[test]
+fn test_get_attestation_out_of_range_errors() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["sparse"]);
+    let id = String::from_str(&e, "sparse");
+
+    assert_eq!(
+        client.try_get_attestation(&id, &0),
+        Err(Ok(AttestationError::AttestationIndexOutOfRange))
+    );
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &true,
+    );
+    assert!(client.try_get_attestation(&id, &0).is_ok());
+    assert_eq!(
+        client.try_get_attestation(&id, &1),
+        Err(Ok(AttestationError::AttestationIndexOutOfRange))
+    );
+}
+
+#[test]
+fn test_get_attestations_by_type_and_in_range() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = setup_weighted_engine(&e, &["filtered"]);
+    let id = String::from_str(&e, "filtered");
+    let health_check = String::from_str(&e, "health_check");
+    let fee_generation = String::from_str(&e, "fee_generation");
+    let mut fee_data = Map::new(&e);
+    fee_data.set(
+        String::from_str(&e, "fee_amount"),
+        String::from_str(&e, "100"),
+    );
+
+    e.ledger().with_mut(|l| l.timestamp = 2_000);
+    client.attest(&admin, &id, &health_check, &Map::new(&e), &true);
+    e.ledger().with_mut(|l| l.timestamp = 3_000);
+    client.attest(&admin, &id, &fee_generation, &fee_data, &true);
+    e.ledger().with_mut(|l| l.timestamp = 4_000);
+    client.attest(&admin, &id, &health_check, &Map::new(&e), &true);
+
+    let checks = client.get_attestations_by_type(&id, &health_check);
+    assert_eq!(checks.len(), 2);
+    assert_eq!(checks.get(0).unwrap().timestamp, 2_000);
+    assert_eq!(checks.get(1).unwrap().timestamp, 4_000);
+    assert_eq!(
+        client.get_attestations_by_type(&id, &fee_generation).len(),
+        1
+    );
+    assert!(client
+        .get_attestations_by_type(&id, &String::from_str(&e, "drawdown"))
+        .is_empty());
+
+    // Both bounds are inclusive.
+    let window = client.get_attestations_in_range(&id, &3_000, &4_000);
+    assert_eq!(window.len(), 2);
+    assert_eq!(window.get(0).unwrap().attestation_type, fee_generation);
+    assert_eq!(
+        client.get_attestations_in_range(&id, &0, &u64::MAX).len(),
+        3
+    );
+    assert!(client
+        .get_attestations_in_range(&id, &4_001, &9_000)
+        .is_empty());
+    assert!(client
+        .get_attestations_in_range(&id, &4_000, &2_000)
+        .is_empty());
+}
+
+#[test]
+fn test_attest_derives_compliance_from_type_and_max_loss() {
+    let e = Env::default();
+    e.mock_all_auths();
+    // Mock commitments allow a 10% max loss.
+    let (client, admin) = setup_weighted_engine(&e, &["derived"]);
+    let id = String::from_str(&e, "derived");
+    let drawdown = String::from_str(&e, "drawdown");
+    let drawdown_data = |percent: &str| {
+        let mut data = Map::new(&e);
+        data.set(
+            String::from_str(&e, "drawdown_percent"),
+            String::from_str(&e, percent),
+        );
+        data
+    };
+
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "violation"),
+        &violation_data(&e, "low"),
+        &true,
+    );
+    client.attest(&admin, &id, &drawdown, &drawdown_data("10"), &true);
+    client.attest(&admin, &id, &drawdown, &drawdown_data("15"), &true);
+    let mut bps_data = drawdown_data("10");
+    bps_data.set(
+        String::from_str(&e, "drawdown_bps"),
+        String::from_str(&e, "1050"),
+    );
+    client.attest(&admin, &id, &drawdown, &bps_data, &true);
+    // A verifier can still report a within-limit drawdown as non-compliant.
+    client.attest(&admin, &id, &drawdown, &drawdown_data("2"), &false);
+
+    let flags: std::vec::Vec<bool> = client
+        .get_attestations(&id)
+        .iter()
+        .map(|att| att.is_compliant)
+        .collect();
+    assert_eq!(flags, std::vec![false, true, false, false, false]);
+}
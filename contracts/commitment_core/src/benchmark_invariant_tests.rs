@@ -16,7 +16,7 @@
 //! 5. **Violation predicate correctness** — `check_violations` returns `true` iff
 //!    `loss_percent > max_loss_percent` OR `now >= expires_at`; returns `false` for
 //!    non-active commitments (no false positives on settled/exited state).
-//! 6. **Settle post-conditions** — after `settle`: status == "settled", TVL decreases by
+//! 6. **Settle post-conditions** — after `settle`: status == Settled, TVL decreases by
 //!    `settlement_amount`, owner-commitment list no longer contains the id.
 //! 7. **Storage pattern equivalence** — sequential and batch reads of the same keys
 //!    return identical values (guards against any future refactor divergence).
@@ -65,7 +65,7 @@ fn seed_commitment(
     current_value: i128,
     max_loss_percent: u32,
     duration_days: u32,
-    status: &str,
+    status: CommitmentStatus,
 ) {
     let created_at: u64 = e.ledger().timestamp();
     let expires_at = created_at + (duration_days as u64) * 86_400;
@@ -86,7 +86,14 @@ fn seed_commitment(
         created_at,
         expires_at,
         current_value,
-        status: String::from_str(e, status),
+        status,
+        memo: String::from_str(e, ""),
+        settled_amount: 0,
+        settled_at: 0,
+        max_drawdown_percent: 0,
+        last_update_ts: 0,
+        realized_pnl: 0,
+        beneficiary: None,
     };
     e.as_contract(contract_id, || {
         set_commitment(e, &commitment);
@@ -135,7 +142,14 @@ fn invariant_total_commitments_increments_by_one_per_create() {
                 created_at,
                 expires_at: created_at + 30 * 86_400,
                 current_value: 1000,
-                status: String::from_str(&e, "active"),
+                status: CommitmentStatus::Active,
+                memo: String::from_str(&e, ""),
+                settled_amount: 0,
+                settled_at: 0,
+                max_drawdown_percent: 0,
+                last_update_ts: 0,
+                realized_pnl: 0,
+                beneficiary: None,
             };
             set_commitment(&e, &commitment);
             let mut list: soroban_sdk::Vec<String> = e
@@ -183,7 +197,17 @@ fn invariant_tvl_equals_sum_of_seeded_amounts() {
     let expected_tvl: i128 = amounts.iter().sum();
 
     for (&id, &amt) in ids.iter().zip(amounts.iter()) {
-        seed_commitment(&e, &contract_id, id, &owner, amt, amt, 20, 30, "active");
+        seed_commitment(
+            &e,
+            &contract_id,
+            id,
+            &owner,
+            amt,
+            amt,
+            20,
+            30,
+            CommitmentStatus::Active,
+        );
         e.as_contract(&contract_id, || {
             let prev: i128 = e
                 .storage()
@@ -286,7 +310,17 @@ fn invariant_check_violations_false_when_healthy() {
     });
 
     // amount=10_000, current_value=9_000 → 10% loss < 20% max_loss; not expired
-    seed_commitment(&e, &contract_id, "c_0", &owner, 10_000, 9_000, 20, 30, "active");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_0",
+        &owner,
+        10_000,
+        9_000,
+        20,
+        30,
+        CommitmentStatus::Active,
+    );
 
     let violated = e.as_contract(&contract_id, || {
         CommitmentCoreContract::check_violations(e.clone(), String::from_str(&e, "c_0"))
@@ -306,7 +340,17 @@ fn invariant_check_violations_true_on_loss_exceeded() {
     });
 
     // amount=10_000, current_value=7_000 → 30% loss > 20% max_loss
-    seed_commitment(&e, &contract_id, "c_0", &owner, 10_000, 7_000, 20, 30, "active");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_0",
+        &owner,
+        10_000,
+        7_000,
+        20,
+        30,
+        CommitmentStatus::Active,
+    );
 
     let violated = e.as_contract(&contract_id, || {
         CommitmentCoreContract::check_violations(e.clone(), String::from_str(&e, "c_0"))
@@ -326,7 +370,17 @@ fn invariant_check_violations_true_on_expiry() {
     });
 
     // Seed with 1-day duration, then advance time past expiry
-    seed_commitment(&e, &contract_id, "c_0", &owner, 10_000, 10_000, 20, 1, "active");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_0",
+        &owner,
+        10_000,
+        10_000,
+        20,
+        1,
+        CommitmentStatus::Active,
+    );
 
     e.ledger().with_mut(|l| {
         l.timestamp += 86_401; // 1 day + 1 second
@@ -351,7 +405,17 @@ fn invariant_check_violations_false_for_settled_commitment() {
     });
 
     // Seed as settled — even with 100% loss and past expiry, must return false
-    seed_commitment(&e, &contract_id, "c_0", &owner, 10_000, 0, 20, 1, "settled");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_0",
+        &owner,
+        10_000,
+        0,
+        20,
+        1,
+        CommitmentStatus::Settled,
+    );
     e.ledger().with_mut(|l| {
         l.timestamp += 86_401;
     });
@@ -373,7 +437,17 @@ fn invariant_check_violations_false_for_early_exit_commitment() {
         CommitmentCoreContract::initialize(e.clone(), admin.clone(), nft.clone());
     });
 
-    seed_commitment(&e, &contract_id, "c_0", &owner, 10_000, 0, 20, 1, "early_exit");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_0",
+        &owner,
+        10_000,
+        0,
+        20,
+        1,
+        CommitmentStatus::EarlyExit,
+    );
     e.ledger().with_mut(|l| {
         l.timestamp += 86_401;
     });
@@ -396,7 +470,17 @@ fn invariant_check_violations_zero_amount_no_panic() {
         CommitmentCoreContract::initialize(e.clone(), admin.clone(), nft.clone());
     });
 
-    seed_commitment(&e, &contract_id, "c_0", &owner, 0, 0, 20, 30, "active");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_0",
+        &owner,
+        0,
+        0,
+        20,
+        30,
+        CommitmentStatus::Active,
+    );
 
     // Must not panic; loss_percent path is guarded by `if commitment.amount > 0`
     let violated = e.as_contract(&contract_id, || {
@@ -410,7 +494,7 @@ fn invariant_check_violations_zero_amount_no_panic() {
 // Invariant 6: Settle post-conditions
 // ---------------------------------------------------------------------------
 
-/// Invariant: after settle, status == "settled", TVL decreases by settlement_amount,
+/// Invariant: after settle, status == Settled, TVL decreases by settlement_amount,
 /// and the owner-commitment list no longer contains the id.
 /// Mirrors `benchmark_settle_function`.
 #[test]
@@ -424,7 +508,17 @@ fn invariant_settle_post_conditions() {
     });
 
     let amount = 5_000i128;
-    seed_commitment(&e, &contract_id, "c_0", &owner, amount, amount, 20, 1, "active");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_0",
+        &owner,
+        amount,
+        amount,
+        20,
+        1,
+        CommitmentStatus::Active,
+    );
     e.as_contract(&contract_id, || {
         e.storage()
             .instance()
@@ -440,7 +534,7 @@ fn invariant_settle_post_conditions() {
     e.as_contract(&contract_id, || {
         let mut c = read_commitment(&e, &String::from_str(&e, "c_0")).unwrap();
         let settlement_amount = c.current_value;
-        c.status = String::from_str(&e, "settled");
+        c.status = CommitmentStatus::Settled;
         set_commitment(&e, &c);
         remove_from_owner_commitments(&e, &owner, &String::from_str(&e, "c_0"));
         let tvl: i128 = e
@@ -458,7 +552,11 @@ fn invariant_settle_post_conditions() {
     let c = e.as_contract(&contract_id, || {
         CommitmentCoreContract::get_commitment(e.clone(), String::from_str(&e, "c_0"))
     });
-    assert_eq!(c.status, String::from_str(&e, "settled"), "Status must be 'settled'");
+    assert_eq!(
+        c.status,
+        CommitmentStatus::Settled,
+        "Status must be 'settled'"
+    );
 
     let tvl = e.as_contract(&contract_id, || {
         CommitmentCoreContract::get_total_value_locked(e.clone())
@@ -652,9 +750,19 @@ fn invariant_get_violation_details_consistent_with_check_violations() {
     });
 
     // Case 1: healthy
-    seed_commitment(&e, &contract_id, "c_0", &owner, 10_000, 9_000, 20, 30, "active");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_0",
+        &owner,
+        10_000,
+        9_000,
+        20,
+        30,
+        CommitmentStatus::Active,
+    );
     let (has_v, _, _, _, _) = e.as_contract(&contract_id, || {
-        CommitmentCoreContract::get_violation_details(e.clone(), String::from_str(&e, "c_0"))
+        CommitmentCoreContract::get_violation_details_tuple(e.clone(), String::from_str(&e, "c_0"))
     });
     let check_v = e.as_contract(&contract_id, || {
         CommitmentCoreContract::check_violations(e.clone(), String::from_str(&e, "c_0"))
@@ -662,9 +770,19 @@ fn invariant_get_violation_details_consistent_with_check_violations() {
     assert_eq!(has_v, check_v, "get_violation_details must agree with check_violations (healthy)");
 
     // Case 2: loss exceeded
-    seed_commitment(&e, &contract_id, "c_1", &owner, 10_000, 7_000, 20, 30, "active");
+    seed_commitment(
+        &e,
+        &contract_id,
+        "c_1",
+        &owner,
+        10_000,
+        7_000,
+        20,
+        30,
+        CommitmentStatus::Active,
+    );
     let (has_v2, loss_v2, _, lp2, _) = e.as_contract(&contract_id, || {
-        CommitmentCoreContract::get_violation_details(e.clone(), String::from_str(&e, "c_1"))
+        CommitmentCoreContract::get_violation_details_tuple(e.clone(), String::from_str(&e, "c_1"))
     });
     let check_v2 = e.as_contract(&contract_id, || {
         CommitmentCoreContract::check_violations(e.clone(), String::from_str(&e, "c_1"))
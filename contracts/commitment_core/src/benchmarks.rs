@@ -76,7 +76,14 @@ fn build_benchmark_commitment(
         created_at: e.ledger().timestamp(),
         expires_at: e.ledger().timestamp() + (30 * 86400),
         current_value: amount,
-        status: String::from_str(e, "active"),
+        status: CommitmentStatus::Active,
+        memo: String::from_str(e, ""),
+        settled_amount: 0,
+        settled_at: 0,
+        max_drawdown_percent: 0,
+        last_update_ts: 0,
+        realized_pnl: 0,
+        beneficiary: None,
     }
 }
 
@@ -291,7 +291,7 @@ fn benchmark_storage_pattern_comparison() {
 /// Measures CPU and memory cost of the full settle path including TVL update.
 ///
 /// # Invariants exercised
-/// - After settle: `status == "settled"`.
+/// - After settle: `status == Settled`.
 /// - `TotalValueLocked` decreases by `settlement_amount`.
 /// - Owner commitment list no longer contains the settled id.
 ///
@@ -347,6 +347,119 @@ fn test_early_exit_with_creation_fee_and_penalty() {
     assert_eq!(token_client.balance(&user), expected_user_balance);
 }
 
+// ============================================================================
+// Settlement Fee Tests
+// ============================================================================
+
+#[test]
+fn test_settle_deducts_settlement_fee() {
+    let (e, admin, contract_id, user, token_address, client) = setup_test();
+    let token_client = TokenClient::new(&e, &token_address);
+
+    client.set_protocol_fee_bps(&admin, &250); // 2.5%
+    assert_eq!(client.get_protocol_fee_bps(), 250);
+
+    let amount = 1_000_000i128;
+    let rules = default_rules(&e);
+    let commitment_id =
+        create_commitment_direct(&e, &contract_id, &user, amount, &token_address, &rules);
+
+    e.ledger()
+        .with_mut(|li| li.timestamp = li.timestamp + (rules.duration_days as u64 * 86_400) + 1);
+    client.settle(&commitment_id);
+
+    let settlement_fee = 25_000i128;
+    assert_eq!(
+        client.get_accrued_fees().get(token_address.clone()),
+        Some(settlement_fee)
+    );
+    assert_eq!(client.get_collected_fees(&token_address), 0);
+    assert_eq!(client.get_protocol_revenue().2, settlement_fee);
+    assert_eq!(token_client.balance(&user), 10_000_000 - settlement_fee);
+    assert_eq!(
+        client.get_commitment(&commitment_id).settled_amount,
+        amount - settlement_fee
+    );
+
+    let to = Address::generate(&e);
+    client.withdraw_accrued_fees(&admin, &to);
+    assert_eq!(token_client.balance(&to), settlement_fee);
+    assert!(client.get_accrued_fees().is_empty());
+}
+
+#[test]
+fn test_settle_with_zero_settlement_fee_pays_full_value() {
+    let (e, _, contract_id, user, token_address, client) = setup_test();
+    let token_client = TokenClient::new(&e, &token_address);
+
+    let rules = default_rules(&e);
+    let commitment_id =
+        create_commitment_direct(&e, &contract_id, &user, 1_000_000, &token_address, &rules);
+
+    e.ledger()
+        .with_mut(|li| li.timestamp = li.timestamp + (rules.duration_days as u64 * 86_400) + 1);
+    client.settle(&commitment_id);
+
+    assert!(client.get_accrued_fees().is_empty());
+    assert_eq!(client.get_collected_fees(&token_address), 0);
+    assert_eq!(token_client.balance(&user), 10_000_000);
+}
+
+#[test]
+fn test_settlement_fee_on_tiny_value_rounds_down() {
+    let (e, admin, contract_id, user, token_address, client) = setup_test();
+    let token_client = TokenClient::new(&e, &token_address);
+
+    client.set_protocol_fee_bps(&admin, &9_999);
+
+    let rules = default_rules(&e);
+    let commitment_id =
+        create_commitment_direct(&e, &contract_id, &user, 1, &token_address, &rules);
+
+    e.ledger()
+        .with_mut(|li| li.timestamp = li.timestamp + (rules.duration_days as u64 * 86_400) + 1);
+    client.settle(&commitment_id);
+
+    // 1 * 9999 / 10000 rounds down to zero, so the owner keeps the whole unit.
+    assert!(client.get_accrued_fees().is_empty());
+    assert_eq!(token_client.balance(&user), 10_000_000);
+    assert_eq!(client.get_commitment(&commitment_id).settled_amount, 1);
+}
+
+#[test]
+#[should_panic(expected = "Invalid fee basis points")]
+fn test_set_protocol_fee_bps_invalid() {
+    let (_, admin, _, _, _, client) = setup_test();
+
+    client.set_protocol_fee_bps(&admin, &10_001);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_set_protocol_fee_bps_rejects_treasurer() {
+    let (e, admin, _, _, _, client) = setup_test();
+    let treasurer = Address::generate(&e);
+    client.add_treasurer(&admin, &treasurer);
+
+    client.set_protocol_fee_bps(&treasurer, &100);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_withdraw_accrued_fees_unauthorized() {
+    let (e, _, _, user, _, client) = setup_test();
+
+    client.withdraw_accrued_fees(&user, &Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Insufficient collected fees")]
+fn test_withdraw_accrued_fees_with_nothing_accrued() {
+    let (e, admin, _, _, _, client) = setup_test();
+
+    client.withdraw_accrued_fees(&admin, &Address::generate(&e));
+}
+
 // ============================================================================
 // Fee Recipient Tests
 // ============================================================================
@@ -17,14 +17,25 @@
 //!
 //! Formal verification planning placeholder:
 //! [`docs/COMMITMENT_CORE_FORMAL_VERIFICATION_SCOPE.md`](../../../docs/COMMITMENT_CORE_FORMAL_VERIFICATION_SCOPE.md)
+//!
+//! # Lifecycle events
+//! Topics are `(name, commitment_id, ..actors)`. Data is a tuple whose first element
+//! is the `commitment_id` and whose last element is the ledger timestamp.
+//!
+//! | Event      | Topics                               | Data                                                            |
+//! |------------|--------------------------------------|-----------------------------------------------------------------|
+//! | `Created`  | `(Created, commitment_id, owner)`    | `(commitment_id, amount, rules, nft_token_id, timestamp)`       |
+//! | `ValUpd`   | `(ValUpd, commitment_id)`            | `(commitment_id, new_value, timestamp)`                         |
+//! | `Violated` | `(Violated, commitment_id)`          | `(commitment_id, loss_percent, max_loss_percent, timestamp)` from `update_value`; `(commitment_id, RuleViol, timestamp)` from `check_violations` |
+//! | `Settled`  | `(Settled, commitment_id, owner)`    | `(commitment_id, payout, dust_swept, realized_pnl, timestamp)`  |
 
 use shared_utils::{
-    emit_error_event, fees, EmergencyControl, Pausable, RateLimiter, SafeMath, TimeUtils,
+    emit_error_event, fees, EmergencyControl, Pausable, RateLimiter, SafeMath, Storage, TimeUtils,
     Validation,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, log, symbol_short, token, Address, Env,
-    IntoVal, String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, log, panic_with_error, symbol_short,
+    token, xdr::ToXdr, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
 pub mod fuzzing;
@@ -32,6 +43,37 @@ pub mod fuzzing;
 /// Maximum page size for paginated owner-commitment queries.
 const MAX_PAGE_SIZE: u32 = 50;
 
+/// Maximum length, in bytes, of a commitment memo.
+pub const MAX_MEMO_LENGTH: u32 = 256;
+
+/// Maximum commitments tracked per owner. `OwnerCommitments` is a single Vec that
+/// `settle` scans linearly, so it is capped at insertion. Only active commitments are
+/// tracked: settling, exiting early or being marked violated frees a slot.
+pub const MAX_COMMITMENTS_PER_OWNER: u32 = 200;
+
+/// Maximum allocations recorded per commitment. `get_allocations` returns the whole
+/// Vec and `settle` walks it, so it is capped at insertion.
+pub const MAX_ALLOCATIONS_PER_COMMITMENT: u32 = 50;
+
+/// Default `max_batch_size` for batch entrypoints until the admin tunes it.
+pub const DEFAULT_MAX_BATCH_SIZE: u32 = 50;
+
+/// Entries kept in a commitment's violation history; the oldest is dropped beyond this.
+pub const MAX_VIOLATION_HISTORY: u32 = 50;
+
+/// Snapshots kept in a commitment's value history; the oldest is dropped beyond this.
+pub const MAX_VALUE_HISTORY: u32 = 100;
+
+/// `ViolationDetails::reason_code` values, in precedence order.
+pub const VIOLATION_REASON_NONE: u32 = 0;
+pub const VIOLATION_REASON_LOSS: u32 = 1;
+pub const VIOLATION_REASON_DURATION: u32 = 2;
+pub const VIOLATION_REASON_FEE: u32 = 3;
+
+/// Layout version of `Commitment`. Version 0 is `LegacyCommitment`.
+pub const COMMITMENT_SCHEMA_VERSION: u32 = 1;
+const LEGACY_COMMITMENT_FIELDS: u32 = 10;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -67,6 +109,36 @@ pub enum CommitmentError {
     ArithmeticOverflow = 24,
     /// Generated commitment ID already exists (counter/storage corruption guard)
     DuplicateCommitmentId = 25,
+    MemoTooLong = 26,
+    NotAllowed = 27,
+    TooManyCommitments = 28,
+    AssetPaused = 29,
+    /// The NFT records a different asset than the commitment
+    AssetMismatch = 30,
+    /// Another mutating call holds this commitment's lock
+    CommitmentBusy = 31,
+    /// The commitment reached `expires_at` and must be settled
+    Matured = 32,
+    /// A batch entrypoint received more items than `max_batch_size`
+    BatchTooLarge = 33,
+    /// Early-exit penalty outside 0-100 or below the commitment type's minimum
+    InvalidEarlyExitPenalty = 34,
+    /// A partial withdrawal would leave less than the minimum remaining balance
+    BelowMinimumBalance = 35,
+    /// `upgrade` was given the all-zero WASM hash
+    InvalidWasmHash = 36,
+    /// `migrate_commitment` found the commitment already in the current layout
+    AlreadyMigrated = 37,
+    /// A stored commitment matches no layout `migrate_commitment` knows
+    UnsupportedLayout = 38,
+    /// The contract is paused; only reads are allowed
+    Paused = 39,
+    /// The commitment already holds `MAX_ALLOCATIONS_PER_COMMITMENT` allocations
+    TooManyAllocations = 40,
+    /// Maximum batch size must be at least 1
+    InvalidBatchSize = 41,
+    /// The commitment is still in its cooling-off period
+    InCoolingOff = 42,
 }
 
 impl CommitmentError {
@@ -101,13 +173,39 @@ impl CommitmentError {
             CommitmentError::DuplicateCommitmentId => {
                 "Commitment ID already exists; counter or storage may be corrupted"
             }
+            CommitmentError::MemoTooLong => "Memo exceeds maximum length",
+            CommitmentError::NotAllowed => "Owner is not on the allowlist",
+            CommitmentError::TooManyCommitments => {
+                "Owner has reached the maximum number of commitments"
+            }
+            CommitmentError::AssetPaused => "Asset is paused",
+            CommitmentError::AssetMismatch => "NFT asset does not match commitment asset",
+            CommitmentError::CommitmentBusy => "Commitment is busy",
+            CommitmentError::Matured => "Commitment has matured; settle it instead",
+            CommitmentError::BatchTooLarge => "Batch exceeds the configured maximum size",
+            CommitmentError::InvalidEarlyExitPenalty => {
+                "Invalid early exit penalty for this commitment type"
+            }
+            CommitmentError::BelowMinimumBalance => {
+                "Withdrawal would leave less than the minimum remaining balance"
+            }
+            CommitmentError::InvalidWasmHash => "Invalid WASM hash",
+            CommitmentError::AlreadyMigrated => "Commitment already uses the current layout",
+            CommitmentError::UnsupportedLayout => "Stored commitment has an unsupported layout",
+            CommitmentError::Paused => "Contract is paused - operation not allowed",
+            CommitmentError::TooManyAllocations => {
+                "Commitment has reached the maximum number of allocations"
+            }
+            CommitmentError::InvalidBatchSize => "Maximum batch size must be at least 1",
+            CommitmentError::InCoolingOff => "Commitment is still in its cooling-off period",
         }
     }
 }
 
 fn fail(e: &Env, err: CommitmentError, context: &str) -> ! {
     emit_error_event(e, err as u32, context);
-    panic!("{}", err.message());
+    log!(e, "{}", err.message());
+    panic_with_error!(e, err)
 }
 
 #[contracttype]
@@ -119,6 +217,23 @@ pub struct CommitmentSettledEvent {
     pub timestamp: u64,
 }
 
+/// Static profile of a commitment, published once at creation under
+/// `commitment_registered` so indexers need no follow-up read. Later events carry deltas.
+/// `amount` is the stored principal, i.e. net of the creation fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentRegisteredEvent {
+    pub commitment_id: String,
+    pub owner: Address,
+    pub amount: i128,
+    pub asset_address: Address,
+    pub nft_token_id: u32,
+    pub rules: CommitmentRules,
+    pub memo: String,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct CommitmentCreatedEvent {
@@ -148,9 +263,47 @@ pub struct CommitmentRules {
     pub grace_period_days: u32,
 }
 
+/// Lifecycle state of a commitment. Only `Active` commitments can be updated,
+/// allocated from, settled or exited.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommitmentStatus {
+    Active,
+    Settled,
+    Violated,
+    EarlyExit,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Commitment {
+    pub commitment_id: String,
+    pub owner: Address,
+    pub nft_token_id: u32,
+    pub rules: CommitmentRules,
+    pub amount: i128,
+    pub asset_address: Address,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub current_value: i128,
+    pub status: CommitmentStatus,
+    pub memo: String,
+    pub settled_amount: i128,
+    pub settled_at: u64,
+    pub max_drawdown_percent: u32,
+    pub last_update_ts: u64,
+    pub realized_pnl: i128,
+    /// Receives settlement proceeds instead of the owner; `None` means the owner.
+    pub beneficiary: Option<Address>,
+}
+
+/// Stored layout of a commitment before `COMMITMENT_SCHEMA_VERSION` 1.
+///
+/// `status` was a lowercase string and the fields from `memo` onward did not
+/// exist. Kept only so `migrate_commitment` can decode such entries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyCommitment {
     pub commitment_id: String,
     pub owner: Address,
     pub nft_token_id: u32,
@@ -163,6 +316,34 @@ pub struct Commitment {
     pub status: String,
 }
 
+/// Named breakdown of a commitment's rule checks, returned by `get_violation_details`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ViolationDetails {
+    pub has_violations: bool,
+    pub loss_violated: bool,
+    pub duration_violated: bool,
+    /// Fee generation is tracked by the attestation engine, not core, so this is
+    /// currently always false.
+    pub fee_violated: bool,
+    pub loss_percent: i128,
+    /// Seconds until `expires_at`; saturates at 0 once expired.
+    pub time_remaining: u64,
+    /// First matching `VIOLATION_REASON_*` code, or `VIOLATION_REASON_NONE`.
+    pub reason_code: u32,
+    /// The value is older than the configured max age. Not a violation.
+    pub value_stale: bool,
+}
+
+/// Page of commitment IDs returned by `get_commitments_expiring_before`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentIdsPage {
+    pub ids: Vec<String>,
+    /// Position in `AllCommitmentIds` to resume scanning from; 0 means the scan is done.
+    pub next_offset: u32,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -173,8 +354,12 @@ pub enum DataKey {
     OwnerCommitments(Address),
     TotalCommitments,
     ReentrancyGuard,
+    /// Set while a mutating entrypoint is operating on this commitment.
+    CommitmentLock(String),
     TotalValueLocked,
     AuthorizedAllocator(Address),
+    /// Addresses added via `add_allocator`, in the order they were added (for enumeration).
+    AuthorizedAllocators,
     AuthorizedUpdater(Address),
     /// Ordered list of all authorized value updaters (for enumeration).
     AuthorizedUpdaters,
@@ -187,12 +372,111 @@ pub enum DataKey {
     FeeRecipient,
     /// Creation fee rate in basis points (0-10000)
     CreationFeeBps,
+    /// Protocol fee rate in basis points (0-10000), taken from `current_value` at `settle`
+    ProtocolFeeBps,
+    /// Protocol fees taken at `settle` and not yet withdrawn (Map<asset, i128>)
+    AccruedFees,
     /// Collected fees per asset (asset -> i128)
     CollectedFees(Address),
+    /// Lifetime early-exit penalties across all assets (i128)
+    TotalPenaltiesCollected,
+    /// Lifetime creation fees across all assets (i128)
+    TotalFeesCollected,
+    /// Lifetime settlement fees and dust swept to the protocol across all assets (i128)
+    TotalSettlementFees,
+    /// Owner allowlist membership (owner -> bool)
+    AllowedOwner(Address),
+    /// Whether `create_commitment` is restricted to allowlisted owners
+    OwnerAllowlistEnabled,
+    /// Seconds after expiry during which only the owner may settle (u64, default 0)
+    SettlementPriorityWindow,
+    /// Settlement payouts below this amount go to collected fees (i128, default 0)
+    SettlementDustThreshold,
+    /// Per-asset pause flag (asset -> bool)
+    AssetPaused(Address),
+    /// (NFT contract, token id) -> commitment id, written at creation
+    NftTokenCommitment(Address, u32),
+    /// Whether informational events are emitted (bool, default true)
+    EventsEnabled,
+    /// Seconds after the last value update before a commitment is stale (u64, 0 disables)
+    MaxValueAgeSecs,
+    /// Seconds after creation during which `early_exit` is penalty-free (u64, default 0)
+    CoolingOffSecs,
+    /// NFT contract that minted a commitment's token, written at creation
+    CommitmentNftContract(String),
+    /// Global NFT contract replaced by the first `set_nft_contract`, which minted every
+    /// commitment created before `CommitmentNftContract` was written (Address)
+    LegacyNftContract,
+    /// Allocations made from a commitment as (target_pool, amount), oldest first
+    Allocation(String),
+    /// Detected violations as (timestamp, loss_percent, max_loss_percent), oldest first
+    ViolationHistory(String),
+    /// Value snapshots from `update_value` as (timestamp, value), oldest first
+    ValueHistory(String),
+    /// What `update_value` does to the NFT of a violated commitment (NftViolationPolicy)
+    NftViolationPolicy,
+    /// Items accepted per call by batch entrypoints (u32, default `DEFAULT_MAX_BATCH_SIZE`)
+    MaxBatchSize,
+    /// Smallest `current_value` a `partial_withdraw` may leave behind (i128, default 1)
+    MinRemainingBalance,
+    /// Smallest penalty a penalised `partial_withdraw` is charged (i128, default 0)
+    MinPartialPenalty,
+    /// Schema version a commitment was migrated to by `migrate_commitment` (u32)
+    MigrationVersion(String),
+}
+
+/// What happens to a commitment's NFT when `update_value` detects a violation.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NftViolationPolicy {
+    /// Keep the NFT active (non-transferable) until the commitment is force-settled.
+    LockUntilForceSettle,
+    /// Mark the NFT inactive immediately so it becomes transferable.
+    UnlockOnViolation,
 }
 
 // --- Internal Helpers ---
 
+/// Append a `(timestamp, value)` snapshot, evicting the oldest beyond `MAX_VALUE_HISTORY`.
+fn record_snapshot(e: &Env, commitment_id: &String, value: i128) {
+    Storage::push_bounded(
+        e,
+        &DataKey::ValueHistory(commitment_id.clone()),
+        (e.ledger().timestamp(), value),
+        MAX_VALUE_HISTORY,
+    );
+}
+
+/// Mirror a changed principal onto the commitment's NFT via `update_initial_amount`.
+fn sync_nft_initial_amount(e: &Env, commitment: &Commitment, context: &str) {
+    let nft_contract = commitment_nft_contract(e, &commitment.commitment_id)
+        .unwrap_or_else(|| fail_guarded(e, CommitmentError::NotInitialized, context));
+    let mut args = Vec::new(e);
+    args.push_back(e.current_contract_address().into_val(e));
+    args.push_back(commitment.nft_token_id.into_val(e));
+    args.push_back(commitment.amount.into_val(e));
+    e.invoke_contract::<()>(
+        &nft_contract,
+        &Symbol::new(e, "update_initial_amount"),
+        args,
+    );
+}
+
+/// Keep a commitment from being archived: commitments live in instance storage, and their
+/// histories in persistent entries that are only extended if they exist.
+fn extend_commitment_ttl(e: &Env, commitment_id: &String) {
+    Storage::extend_instance_ttl(e);
+    for key in [
+        DataKey::ValueHistory(commitment_id.clone()),
+        DataKey::ViolationHistory(commitment_id.clone()),
+        DataKey::Allocation(commitment_id.clone()),
+    ] {
+        if e.storage().persistent().has(&key) {
+            Storage::extend_persistent_ttl(e, &key);
+        }
+    }
+}
+
 fn is_zero_address(e: &Env, address: &Address) -> bool {
     let zero_str = String::from_str(
         e,
@@ -248,6 +532,111 @@ fn call_nft_mint(
     e.invoke_contract::<u32>(nft_contract, &Symbol::new(e, "mint"), args)
 }
 
+/// Ask the NFT contract whether `token_id` still exists.
+///
+/// Only an explicit `false` counts as missing; if the query itself fails (for
+/// example an NFT contract without `token_exists`) the token is assumed to exist
+/// so the regular settle call still runs and surfaces any real error.
+fn nft_token_exists(e: &Env, nft_contract: &Address, token_id: u32) -> bool {
+    let mut args = Vec::new(e);
+    args.push_back(token_id.into_val(e));
+    !matches!(
+        e.try_invoke_contract::<bool, soroban_sdk::Error>(
+            nft_contract,
+            &Symbol::new(e, "token_exists"),
+            args,
+        ),
+        Ok(Ok(false))
+    )
+}
+
+/// Read the asset the NFT contract recorded for `token_id`.
+///
+/// Returns `None` when the NFT contract cannot answer (e.g. it predates
+/// `get_asset_address`), in which case the asset cannot be cross-checked.
+fn nft_asset_address(e: &Env, nft_contract: &Address, token_id: u32) -> Option<Address> {
+    let mut args = Vec::new(e);
+    args.push_back(e.current_contract_address().into_val(e));
+    args.push_back(token_id.into_val(e));
+    match e.try_invoke_contract::<Address, soroban_sdk::Error>(
+        nft_contract,
+        &Symbol::new(e, "get_asset_address"),
+        args,
+    ) {
+        Ok(Ok(asset)) => Some(asset),
+        _ => None,
+    }
+}
+
+fn events_enabled(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get::<_, bool>(&DataKey::EventsEnabled)
+        .unwrap_or(true)
+}
+
+/// Whether an active commitment's `current_value` is older than `MaxValueAgeSecs`.
+fn value_is_stale(e: &Env, commitment: &Commitment) -> bool {
+    let max_age = e
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::MaxValueAgeSecs)
+        .unwrap_or(0);
+    let age = e
+        .ledger()
+        .timestamp()
+        .saturating_sub(commitment.last_update_ts);
+    max_age > 0 && age > max_age && commitment.status == CommitmentStatus::Active
+}
+
+fn is_asset_paused(e: &Env, asset: &Address) -> bool {
+    e.storage()
+        .instance()
+        .get::<_, bool>(&DataKey::AssetPaused(asset.clone()))
+        .unwrap_or(false)
+}
+
+fn is_owner_allowlist_enabled(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get::<_, bool>(&DataKey::OwnerAllowlistEnabled)
+        .unwrap_or(false)
+}
+
+fn is_allowed_owner(e: &Env, owner: &Address) -> bool {
+    e.storage()
+        .instance()
+        .get::<_, bool>(&DataKey::AllowedOwner(owner.clone()))
+        .unwrap_or(false)
+}
+
+/// NFT contract holding `commitment_id`'s token.
+///
+/// Commitments created before `set_nft_contract` existed have no per-commitment
+/// entry. They fall back to `LegacyNftContract` once the global contract has been
+/// switched, and to the current global NFT contract before that.
+fn commitment_nft_contract(e: &Env, commitment_id: &String) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<_, Address>(&DataKey::CommitmentNftContract(commitment_id.clone()))
+        .or_else(|| {
+            e.storage()
+                .instance()
+                .get::<_, Address>(&DataKey::LegacyNftContract)
+        })
+        .or_else(|| {
+            e.storage()
+                .instance()
+                .get::<_, Address>(&DataKey::NftContract)
+        })
+}
+
+/// Whether `commitment` can still be cancelled penalty-free (see `set_cooling_off_period`).
+fn in_cooling_off(e: &Env, commitment: &Commitment) -> bool {
+    let period = CommitmentCoreContract::get_cooling_off_period(e.clone());
+    period > 0 && e.ledger().timestamp() < commitment.created_at.saturating_add(period)
+}
+
 fn read_commitment(e: &Env, commitment_id: &String) -> Option<Commitment> {
     e.storage()
         .instance()
@@ -261,6 +650,44 @@ fn set_commitment(e: &Env, commitment: &Commitment) {
     );
 }
 
+/// Loss threshold actually enforced for `commitment`, without side effects.
+///
+/// `validate_rules` never admits `max_loss_percent > 100`, but a value that got past
+/// it (e.g. through a bad migration) would silently disable loss violations. Such a
+/// value is clamped to the type's cap (safe 10, balanced 30), or to 99 for any other
+/// type, since `checked_drawdown_percent` never reports more than 100.
+fn clamped_max_loss_percent(e: &Env, commitment: &Commitment) -> u32 {
+    let stored = commitment.rules.max_loss_percent;
+    if stored <= 100 {
+        return stored;
+    }
+
+    if commitment.rules.commitment_type == String::from_str(e, "safe") {
+        10
+    } else if commitment.rules.commitment_type == String::from_str(e, "balanced") {
+        30
+    } else {
+        99
+    }
+}
+
+/// [`clamped_max_loss_percent`], emitting a `max_loss_clamped` warning event when the
+/// stored value had to be clamped. Read-only views use the former directly.
+fn enforced_max_loss_percent(e: &Env, commitment: &Commitment) -> u32 {
+    let enforced = clamped_max_loss_percent(e, commitment);
+    let stored = commitment.rules.max_loss_percent;
+    if enforced != stored {
+        e.events().publish(
+            (
+                Symbol::new(e, "max_loss_clamped"),
+                commitment.commitment_id.clone(),
+            ),
+            (stored, enforced, e.ledger().timestamp()),
+        );
+    }
+    enforced
+}
+
 // fn has_commitment(e: &Env, commitment_id: &String) -> bool {
 //     e.storage().instance().has(&DataKey::Commitment(commitment_id.clone()))
 // }
@@ -285,6 +712,51 @@ fn set_reentrancy_guard(e: &Env, value: bool) {
         .set(&DataKey::ReentrancyGuard, &value);
 }
 
+/// Take the per-commitment mutation lock, failing with `CommitmentBusy` if it is held.
+/// A failed call reverts its storage writes, so the lock cannot leak on error.
+fn acquire_commitment_lock(e: &Env, commitment_id: &String) {
+    let key = DataKey::CommitmentLock(commitment_id.clone());
+    if e.storage().instance().has(&key) {
+        fail(e, CommitmentError::CommitmentBusy, "commitment_lock");
+    }
+    e.storage().instance().set(&key, &true);
+}
+
+fn release_commitment_lock(e: &Env, commitment_id: &String) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::CommitmentLock(commitment_id.clone()));
+}
+
+/// Fail with `CommitmentError::Paused` while the contract is paused.
+fn require_not_paused(e: &Env, context: &str) {
+    if Pausable::is_paused(e) {
+        fail(e, CommitmentError::Paused, context);
+    }
+}
+
+/// Release the reentrancy guard, then fail. Every error path taken while the
+/// guard is held must go through here so a failure can never leave it set.
+fn fail_guarded(e: &Env, err: CommitmentError, context: &str) -> ! {
+    set_reentrancy_guard(e, false);
+    fail(e, err, context)
+}
+
+/// Add `amount` to a lifetime revenue counter; overflow fails the whole call.
+fn add_protocol_revenue(e: &Env, key: &DataKey, amount: i128, context: &str) {
+    let total: i128 = e.storage().instance().get(key).unwrap_or(0);
+    let updated = total
+        .checked_add(amount)
+        .unwrap_or_else(|| fail_guarded(e, CommitmentError::ArithmeticOverflow, context));
+    e.storage().instance().set(key, &updated);
+}
+
+fn require_batch_size(e: &Env, batch_size: u32, context: &str) {
+    if batch_size > CommitmentCoreContract::get_max_batch_size(e.clone()) {
+        fail(e, CommitmentError::BatchTooLarge, context);
+    }
+}
+
 fn require_admin(e: &Env, caller: &Address) {
     caller.require_auth();
     let admin = e
@@ -361,32 +833,52 @@ pub struct CommitmentCoreContract;
 
 #[contractimpl]
 impl CommitmentCoreContract {
+    /// Enforce `validate_rules_check` inside `create_commitment`, failing with its error
+    /// so the two paths can never disagree.
     fn validate_rules(e: &Env, rules: &CommitmentRules) {
-        Validation::require_valid_duration(rules.duration_days);
-        Validation::require_valid_percent(rules.max_loss_percent);
-        let valid_types = ["safe", "balanced", "aggressive"];
-        Validation::require_valid_commitment_type(e, &rules.commitment_type, &valid_types);
-
-        // Enforce type-specific constraints
-        if rules.commitment_type == String::from_str(e, "safe") {
-            if rules.max_loss_percent > 10 {
-                panic!("Safe type: max_loss_percent must be <= 10");
-            }
-            if rules.early_exit_penalty < 15 {
-                panic!("Safe type: early_exit_penalty must be >= 15");
-            }
-        } else if rules.commitment_type == String::from_str(e, "balanced") {
-            if rules.max_loss_percent > 30 {
-                panic!("Balanced type: max_loss_percent must be <= 30");
-            }
-            if rules.early_exit_penalty < 10 {
-                panic!("Balanced type: early_exit_penalty must be >= 10");
-            }
-        } else if rules.commitment_type == String::from_str(e, "aggressive")
-            && rules.early_exit_penalty < 5
-        {
-            panic!("Aggressive type: early_exit_penalty must be >= 5");
+        if let Err(err) = Self::validate_rules_check(e.clone(), rules.clone()) {
+            fail_guarded(e, err, "create");
+        }
+    }
+
+    /// Check `rules` without submitting a commitment, for frontends to pre-validate.
+    ///
+    /// Applies the same rules as `create_commitment` but returns the first failure
+    /// instead of panicking:
+    /// - [`CommitmentError::InvalidDuration`] if `duration_days` is 0.
+    /// - [`CommitmentError::InvalidMaxLossPercent`] if `max_loss_percent` is above
+    ///   100 or above the type's cap (safe 10, balanced 30).
+    /// - [`CommitmentError::InvalidCommitmentType`] unless the type is safe,
+    ///   balanced or aggressive.
+    /// - [`CommitmentError::InvalidEarlyExitPenalty`] if `early_exit_penalty` is
+    ///   above 100 or below the type's minimum (safe 15, balanced 10, aggressive 5).
+    pub fn validate_rules_check(e: Env, rules: CommitmentRules) -> Result<(), CommitmentError> {
+        if rules.duration_days == 0 {
+            return Err(CommitmentError::InvalidDuration);
+        }
+        if rules.max_loss_percent > 100 {
+            return Err(CommitmentError::InvalidMaxLossPercent);
+        }
+        if rules.early_exit_penalty > 100 {
+            return Err(CommitmentError::InvalidEarlyExitPenalty);
+        }
+
+        let (max_loss_cap, min_penalty) = if rules.commitment_type == String::from_str(&e, "safe") {
+            (10, 15)
+        } else if rules.commitment_type == String::from_str(&e, "balanced") {
+            (30, 10)
+        } else if rules.commitment_type == String::from_str(&e, "aggressive") {
+            (100, 5)
+        } else {
+            return Err(CommitmentError::InvalidCommitmentType);
+        };
+        if rules.max_loss_percent > max_loss_cap {
+            return Err(CommitmentError::InvalidMaxLossPercent);
+        }
+        if rules.early_exit_penalty < min_penalty {
+            return Err(CommitmentError::InvalidEarlyExitPenalty);
         }
+        Ok(())
     }
 
     /// Generate a canonical commitment ID in the format `COMMIT_<counter>`.
@@ -485,30 +977,63 @@ impl CommitmentCoreContract {
         amount: i128,
         asset_address: Address,
         rules: CommitmentRules,
+    ) -> String {
+        let memo = String::from_str(&e, "");
+        Self::create_commitment_internal(e, owner, amount, asset_address, rules, memo)
+    }
+
+    /// Same as `create_commitment`, attaching an owner-supplied memo of at most
+    /// `MAX_MEMO_LENGTH` bytes.
+    pub fn create_commitment_with_memo(
+        e: Env,
+        owner: Address,
+        amount: i128,
+        asset_address: Address,
+        rules: CommitmentRules,
+        memo: String,
+    ) -> String {
+        Self::create_commitment_internal(e, owner, amount, asset_address, rules, memo)
+    }
+
+    fn create_commitment_internal(
+        e: Env,
+        owner: Address,
+        amount: i128,
+        asset_address: Address,
+        rules: CommitmentRules,
+        memo: String,
     ) -> String {
         require_no_reentrancy(&e);
         set_reentrancy_guard(&e, true);
-        Pausable::require_not_paused(&e);
+        require_not_paused(&e, "create");
         EmergencyControl::require_not_emergency(&e);
         owner.require_auth();
         if is_zero_address(&e, &owner) {
             set_reentrancy_guard(&e, false);
             fail(&e, CommitmentError::ZeroAddress, "create");
         }
+        if is_owner_allowlist_enabled(&e) && !is_allowed_owner(&e, &owner) {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::NotAllowed, "create");
+        }
+        if is_asset_paused(&e, &asset_address) {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::AssetPaused, "create");
+        }
         RateLimiter::check(&e, &owner, &symbol_short!("create"));
         Validation::require_positive(amount);
         Self::validate_rules(&e, &rules);
+        if memo.len() > MAX_MEMO_LENGTH {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::MemoTooLong, "create");
+        }
 
         let creation_fee_bps: u32 = e
             .storage()
             .instance()
             .get(&DataKey::CreationFeeBps)
             .unwrap_or(0);
-        let creation_fee = if creation_fee_bps > 0 {
-            fees::fee_from_bps(amount, creation_fee_bps)
-        } else {
-            0
-        };
+        let creation_fee = SafeMath::apply_bps(amount, creation_fee_bps);
         let net_amount = amount.checked_sub(creation_fee).unwrap_or_else(|| {
             set_reentrancy_guard(&e, false);
             fail(&e, CommitmentError::ArithmeticOverflow, "create");
@@ -559,7 +1084,14 @@ impl CommitmentCoreContract {
             created_at: TimeUtils::now(&e),
             expires_at,
             current_value: net_amount,
-            status: String::from_str(&e, "active"),
+            status: CommitmentStatus::Active,
+            memo,
+            settled_amount: 0,
+            settled_at: 0,
+            max_drawdown_percent: 0,
+            last_update_ts: TimeUtils::now(&e),
+            realized_pnl: 0,
+            beneficiary: None,
         };
 
         set_commitment(&e, &commitment);
@@ -568,6 +1100,10 @@ impl CommitmentCoreContract {
             .instance()
             .get::<_, Vec<String>>(&DataKey::OwnerCommitments(owner.clone()))
             .unwrap_or(Vec::new(&e));
+        if owner_commitments.len() >= MAX_COMMITMENTS_PER_OWNER {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::TooManyCommitments, "create");
+        }
         owner_commitments.push_back(commitment_id.clone());
         e.storage().instance().set(
             &DataKey::OwnerCommitments(owner.clone()),
@@ -611,6 +1147,7 @@ impl CommitmentCoreContract {
             e.storage()
                 .instance()
                 .set(&fee_key, &updated_fees);
+            add_protocol_revenue(&e, &DataKey::TotalFeesCollected, creation_fee, "create");
         }
 
         let nft_token_id = call_nft_mint(
@@ -625,15 +1162,56 @@ impl CommitmentCoreContract {
             &asset_address,
             rules.early_exit_penalty,
         );
+        // Token ids start at 0, so existence (not a nonzero id) is what proves the mint
+        // landed; a misconfigured NFT contract must not leave a dangling reference.
+        if !nft_token_exists(&e, &nft_contract, nft_token_id) {
+            fail_guarded(&e, CommitmentError::MintingFailed, "create");
+        }
+        if let Some(nft_asset) = nft_asset_address(&e, &nft_contract, nft_token_id) {
+            if nft_asset != asset_address {
+                fail_guarded(&e, CommitmentError::AssetMismatch, "create");
+            }
+        }
 
         let mut updated_commitment = commitment;
         updated_commitment.nft_token_id = nft_token_id;
         set_commitment(&e, &updated_commitment);
+        e.storage().instance().set(
+            &DataKey::NftTokenCommitment(nft_contract.clone(), nft_token_id),
+            &commitment_id,
+        );
+        e.storage().instance().set(
+            &DataKey::CommitmentNftContract(commitment_id.clone()),
+            &nft_contract,
+        );
         set_reentrancy_guard(&e, false);
 
+        e.events().publish(
+            (
+                Symbol::new(&e, "commitment_registered"),
+                commitment_id.clone(),
+            ),
+            CommitmentRegisteredEvent {
+                commitment_id: commitment_id.clone(),
+                owner: owner.clone(),
+                amount: updated_commitment.amount,
+                asset_address: asset_address.clone(),
+                nft_token_id,
+                rules: rules.clone(),
+                memo: updated_commitment.memo.clone(),
+                created_at: updated_commitment.created_at,
+                expires_at: updated_commitment.expires_at,
+            },
+        );
         e.events().publish(
             (symbol_short!("Created"), commitment_id.clone(), owner),
-            (amount, rules, nft_token_id, e.ledger().timestamp()),
+            (
+                commitment_id.clone(),
+                amount,
+                rules,
+                nft_token_id,
+                e.ledger().timestamp(),
+            ),
         );
         commitment_id
     }
@@ -643,17 +1221,90 @@ impl CommitmentCoreContract {
     /// This is the read API consumed by `attestation_engine` for compliance checks,
     /// health metrics, and commitment-existence validation. It intentionally does not
     /// perform auth checks so downstream contracts can read commitment state.
+    ///
+    /// Reading also extends the contract instance TTL, so commitments that are still being
+    /// looked at are not archived.
     pub fn get_commitment(e: Env, commitment_id: String) -> Commitment {
+        let commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "get_commitment"));
+        Storage::extend_instance_ttl(&e);
+        commitment
+    }
+
+    /// Extend the storage TTL of a dormant commitment and its histories (admin only).
+    ///
+    /// `update_value` and `get_commitment` extend TTLs as a side effect; this covers
+    /// long-lived commitments nobody touches for months.
+    pub fn bump_commitment_ttl(e: Env, caller: Address, commitment_id: String) {
+        require_admin(&e, &caller);
+        if read_commitment(&e, &commitment_id).is_none() {
+            fail(
+                &e,
+                CommitmentError::CommitmentNotFound,
+                "bump_commitment_ttl",
+            );
+        }
+        extend_commitment_ttl(&e, &commitment_id);
+    }
+
+    /// Worst loss percent `update_value` has ever observed for a commitment.
+    ///
+    /// A high-water mark: it never decreases when the value recovers, so it shows
+    /// whether a currently healthy commitment once came close to or past its limit.
+    pub fn get_max_drawdown(e: Env, commitment_id: String) -> u32 {
         read_commitment(&e, &commitment_id)
-            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "get_commitment"))
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "get_max_drawdown"))
+            .max_drawdown_percent
+    }
+
+    /// Return the commitment backed by token `token_id` of `nft_contract`.
+    ///
+    /// Lets NFT-centric callers (e.g. marketplace views) resolve commitment data in one call.
+    /// The contract is part of the key because token ids restart when the admin switches
+    /// to a new NFT contract via `set_nft_contract`.
+    pub fn get_commitment_by_nft(e: Env, nft_contract: Address, token_id: u32) -> Commitment {
+        e.storage()
+            .instance()
+            .get::<_, String>(&DataKey::NftTokenCommitment(nft_contract, token_id))
+            .and_then(|commitment_id| read_commitment(&e, &commitment_id))
+            .unwrap_or_else(|| {
+                fail(
+                    &e,
+                    CommitmentError::CommitmentNotFound,
+                    "get_commitment_by_nft",
+                )
+            })
+    }
+
+    /// SHA-256 over the commitment's material state: `amount`, `current_value`,
+    /// `status` and `expires_at`, XDR-encoded as a tuple in that order.
+    ///
+    /// Off-chain consumers can compare hashes to detect state changes without
+    /// fetching and diffing the full record. Identical state always yields the same hash.
+    pub fn commitment_state_hash(e: Env, commitment_id: String) -> BytesN<32> {
+        let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail(
+                &e,
+                CommitmentError::CommitmentNotFound,
+                "commitment_state_hash",
+            )
+        });
+        let material = (
+            commitment.amount,
+            commitment.current_value,
+            commitment.status,
+            commitment.expires_at,
+        )
+            .to_xdr(&e);
+        e.crypto().sha256(&material).to_bytes()
     }
 
-    /// List all commitment IDs owned by the given address.
+    /// List the active commitment IDs owned by the given address.
     pub fn list_commitments_by_owner(e: Env, owner: Address) -> Vec<String> {
         Self::get_owner_commitments(e, owner, 0, MAX_PAGE_SIZE)
     }
 
-    /// Return a paginated slice of commitment IDs owned by `owner`.
+    /// Return a paginated slice of the active commitment IDs owned by `owner`.
     ///
     /// # Parameters
     /// - `owner`  – The address whose commitments are queried. No auth required; this is a
@@ -728,6 +1379,52 @@ impl CommitmentCoreContract {
         out
     }
 
+    /// Return a page of active commitment IDs whose `expires_at <= timestamp`.
+    ///
+    /// Intended for keepers pre-staging settlements. Scans at most `MAX_PAGE_SIZE`
+    /// entries of `AllCommitmentIds`, in creation order, starting at `offset`, and
+    /// returns up to `min(limit, MAX_PAGE_SIZE)` matches from them. The scan stops
+    /// early once the page is full. A page may be empty while `next_offset` is non-zero
+    /// when no scanned commitment matched, so keep paging with `next_offset` until it
+    /// is 0.
+    pub fn get_commitments_expiring_before(
+        e: Env,
+        timestamp: u64,
+        offset: u32,
+        limit: u32,
+    ) -> CommitmentIdsPage {
+        let mut page = CommitmentIdsPage {
+            ids: Vec::new(&e),
+            next_offset: 0,
+        };
+        let all_ids = e
+            .storage()
+            .instance()
+            .get::<_, Vec<String>>(&DataKey::AllCommitmentIds)
+            .unwrap_or(Vec::new(&e));
+        let total = all_ids.len();
+        if limit == 0 || offset >= total {
+            return page;
+        }
+
+        let effective_limit = limit.min(MAX_PAGE_SIZE);
+        let scan_end = offset.saturating_add(MAX_PAGE_SIZE).min(total);
+        let mut index = offset;
+        while index < scan_end && page.ids.len() < effective_limit {
+            let id = all_ids.get_unchecked(index);
+            index += 1;
+            if let Some(c) = read_commitment(&e, &id) {
+                if c.status == CommitmentStatus::Active && c.expires_at <= timestamp {
+                    page.ids.push_back(id);
+                }
+            }
+        }
+        if index < total {
+            page.next_offset = index;
+        }
+        page
+    }
+
     /// Get admin address
     pub fn get_admin(e: Env) -> Address {
         e.storage()
@@ -744,15 +1441,194 @@ impl CommitmentCoreContract {
             .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "get_nft_contract"))
     }
 
-    pub fn pause(e: Env, caller: Address) {
-        caller.require_auth();
-        if !Self::is_operator(e.clone(), caller.clone()) {
-            fail(&e, CommitmentError::Unauthorized, "pause");
+    /// Point new commitments at a replacement NFT contract (admin only).
+    ///
+    /// Existing commitments keep settling and exiting against the NFT contract that
+    /// minted their token, so switching does not orphan them. The first switch records
+    /// the outgoing contract as `LegacyNftContract` for commitments created before
+    /// per-commitment pins were written.
+    pub fn set_nft_contract(e: Env, caller: Address, new_nft_contract: Address) {
+        require_admin(&e, &caller);
+        let old_nft_contract = Self::get_nft_contract(e.clone());
+        if !e.storage().instance().has(&DataKey::LegacyNftContract) {
+            e.storage()
+                .instance()
+                .set(&DataKey::LegacyNftContract, &old_nft_contract);
         }
-        Pausable::pause(&e);
+        e.storage()
+            .instance()
+            .set(&DataKey::NftContract, &new_nft_contract);
+        e.events().publish(
+            (Symbol::new(&e, "NftContractSet"),),
+            (old_nft_contract, new_nft_contract, e.ledger().timestamp()),
+        );
     }
 
-    /// Unpauses the contract, re-enabling normal operations.
+    /// Replace this contract's WASM with an already-uploaded build (admin only).
+    ///
+    /// Storage is kept as-is, so a new build that changes stored types must ship a
+    /// migration alongside it. Emits `upgraded` with the new hash.
+    pub fn upgrade(e: Env, caller: Address, new_wasm_hash: BytesN<32>) {
+        require_admin(&e, &caller);
+        if new_wasm_hash == BytesN::from_array(&e, &[0; 32]) {
+            fail(&e, CommitmentError::InvalidWasmHash, "upgrade");
+        }
+        e.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        e.events().publish(
+            (symbol_short!("upgraded"), caller),
+            (new_wasm_hash, e.ledger().timestamp()),
+        );
+    }
+
+    /// Backfill the lookup indices for existing commitments (admin only).
+    ///
+    /// Inserts each commitment into `AllCommitmentIds`, its owner's list (active
+    /// commitments only, matching `settle`, `early_exit` and `update_value`) and the
+    /// NFT token reverse lookup. Entries that are already present are left alone, so
+    /// the call is safe to repeat. At most `get_max_batch_size` ids are accepted per
+    /// call.
+    pub fn reindex(e: Env, caller: Address, commitment_ids: Vec<String>) {
+        require_admin(&e, &caller);
+        require_batch_size(&e, commitment_ids.len(), "reindex");
+
+        let mut all_ids = e
+            .storage()
+            .instance()
+            .get::<_, Vec<String>>(&DataKey::AllCommitmentIds)
+            .unwrap_or(Vec::new(&e));
+        for commitment_id in commitment_ids.iter() {
+            let commitment = read_commitment(&e, &commitment_id)
+                .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "reindex"));
+
+            if !all_ids.contains(&commitment_id) {
+                all_ids.push_back(commitment_id.clone());
+            }
+
+            if commitment.status == CommitmentStatus::Active {
+                let owner_key = DataKey::OwnerCommitments(commitment.owner.clone());
+                let mut owner_commitments = e
+                    .storage()
+                    .instance()
+                    .get::<_, Vec<String>>(&owner_key)
+                    .unwrap_or(Vec::new(&e));
+                if !owner_commitments.contains(&commitment_id) {
+                    if owner_commitments.len() >= MAX_COMMITMENTS_PER_OWNER {
+                        fail(&e, CommitmentError::TooManyCommitments, "reindex");
+                    }
+                    owner_commitments.push_back(commitment_id.clone());
+                    e.storage().instance().set(&owner_key, &owner_commitments);
+                }
+            }
+
+            if let Some(nft_contract) = commitment_nft_contract(&e, &commitment_id) {
+                let nft_key = DataKey::NftTokenCommitment(nft_contract, commitment.nft_token_id);
+                if !e.storage().instance().has(&nft_key) {
+                    e.storage().instance().set(&nft_key, &commitment_id);
+                }
+            }
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::AllCommitmentIds, &all_ids);
+
+        e.events().publish(
+            (Symbol::new(&e, "Reindexed"),),
+            (commitment_ids.len(), e.ledger().timestamp()),
+        );
+    }
+
+    /// Rewrite a commitment stored in the legacy layout into the current one (admin only).
+    ///
+    /// The string status is mapped to `CommitmentStatus` and the missing fields get
+    /// defaults: empty memo, zero settlement totals and PnL, no beneficiary,
+    /// `last_update_ts = created_at`, and the drawdown implied by `current_value` as
+    /// the high-water mark. The commitment's `MigrationVersion` marker is then set to
+    /// `COMMITMENT_SCHEMA_VERSION`.
+    ///
+    /// ### Errors
+    /// - `CommitmentError::AlreadyMigrated` — the marker is set or the entry already
+    ///   decodes as the current layout, so a second run cannot clobber live fields.
+    /// - `CommitmentError::UnsupportedLayout` — the entry matches neither layout or
+    ///   carries an unknown status string.
+    pub fn migrate_commitment(e: Env, caller: Address, commitment_id: String) {
+        require_admin(&e, &caller);
+        let version_key = DataKey::MigrationVersion(commitment_id.clone());
+        // Decoding into the wrong struct traps in the host, so the layout is told
+        // apart by its field names before picking a type.
+        let fields = e
+            .storage()
+            .instance()
+            .get::<_, Map<Symbol, Val>>(&DataKey::Commitment(commitment_id.clone()))
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "migrate"));
+        let migrated = e
+            .storage()
+            .instance()
+            .get::<_, u32>(&version_key)
+            .unwrap_or(0);
+        if migrated >= COMMITMENT_SCHEMA_VERSION || fields.contains_key(symbol_short!("memo")) {
+            fail(&e, CommitmentError::AlreadyMigrated, "migrate");
+        }
+        if fields.len() != LEGACY_COMMITMENT_FIELDS {
+            fail(&e, CommitmentError::UnsupportedLayout, "migrate");
+        }
+
+        let legacy = LegacyCommitment::try_from_val(&e, &fields.to_val())
+            .unwrap_or_else(|_| fail(&e, CommitmentError::UnsupportedLayout, "migrate"));
+        let status = if legacy.status == String::from_str(&e, "active") {
+            CommitmentStatus::Active
+        } else if legacy.status == String::from_str(&e, "settled") {
+            CommitmentStatus::Settled
+        } else if legacy.status == String::from_str(&e, "violated") {
+            CommitmentStatus::Violated
+        } else if legacy.status == String::from_str(&e, "early_exit") {
+            CommitmentStatus::EarlyExit
+        } else {
+            fail(&e, CommitmentError::UnsupportedLayout, "migrate")
+        };
+        let max_drawdown_percent =
+            SafeMath::checked_drawdown_percent(legacy.amount, legacy.current_value)
+                .unwrap_or_else(|| fail(&e, CommitmentError::ArithmeticOverflow, "migrate"));
+
+        let commitment = Commitment {
+            commitment_id: legacy.commitment_id,
+            owner: legacy.owner,
+            nft_token_id: legacy.nft_token_id,
+            rules: legacy.rules,
+            amount: legacy.amount,
+            asset_address: legacy.asset_address,
+            created_at: legacy.created_at,
+            expires_at: legacy.expires_at,
+            current_value: legacy.current_value,
+            status,
+            memo: String::from_str(&e, ""),
+            settled_amount: 0,
+            settled_at: 0,
+            max_drawdown_percent: max_drawdown_percent as u32,
+            last_update_ts: legacy.created_at,
+            realized_pnl: 0,
+            beneficiary: None,
+        };
+        set_commitment(&e, &commitment);
+        e.storage()
+            .instance()
+            .set(&version_key, &COMMITMENT_SCHEMA_VERSION);
+
+        e.events().publish(
+            (Symbol::new(&e, "CommitmentMigrated"), commitment_id),
+            (COMMITMENT_SCHEMA_VERSION, e.ledger().timestamp()),
+        );
+    }
+
+    pub fn pause(e: Env, caller: Address) {
+        caller.require_auth();
+        if !Self::is_operator(e.clone(), caller.clone()) {
+            fail(&e, CommitmentError::Unauthorized, "pause");
+        }
+        Pausable::pause(&e);
+    }
+
+    /// Unpauses the contract, re-enabling normal operations.
     ///
     /// Restricted to the Operator or Admin role.
     pub fn unpause(e: Env, caller: Address) {
@@ -768,6 +1644,47 @@ impl CommitmentCoreContract {
         Pausable::is_paused(&e)
     }
 
+    /// Freeze or unfreeze a single asset without pausing the whole protocol.
+    ///
+    /// While paused, `create_commitment`, `allocate` and `update_value` reject
+    /// commitments in `asset`. Settlement and early exit stay open so holders can leave.
+    /// Admin only; operators can pause the whole contract but not single assets.
+    pub fn set_asset_paused(e: Env, caller: Address, asset: Address, paused: bool) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .set(&DataKey::AssetPaused(asset.clone()), &paused);
+        e.events().publish(
+            (Symbol::new(&e, "AssetPauseSet"), asset),
+            (paused, e.ledger().timestamp()),
+        );
+    }
+
+    /// Returns true if `asset` is individually paused.
+    pub fn is_asset_paused(e: Env, asset: Address) -> bool {
+        is_asset_paused(&e, &asset)
+    }
+
+    /// Turn informational events on or off (admin only).
+    ///
+    /// Informational events are `ValUpd` and `memo_updated`. Violation, settlement,
+    /// exit, creation and admin events are always emitted.
+    pub fn set_events_enabled(e: Env, caller: Address, enabled: bool) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .set(&DataKey::EventsEnabled, &enabled);
+        e.events().publish(
+            (Symbol::new(&e, "EventsToggled"),),
+            (caller, enabled, e.ledger().timestamp()),
+        );
+    }
+
+    /// Whether informational events are emitted.
+    pub fn is_events_enabled(e: Env) -> bool {
+        events_enabled(&e)
+    }
+
     /// Adds an address to the authorized allocators list.
     ///
     /// Restricted to the Admin role.
@@ -775,10 +1692,15 @@ impl CommitmentCoreContract {
     /// from commitments to target pools.
     pub fn add_allocator(e: Env, caller: Address, allocator: Address) {
         require_admin(&e, &caller);
-        e.storage().instance().set(
-            &DataKey::AuthorizedAllocator(allocator.clone()),
-            &true,
-        );
+        let key = DataKey::AuthorizedAllocator(allocator.clone());
+        if !e.storage().instance().has(&key) {
+            let mut allocators = Self::get_allocators(e.clone());
+            allocators.push_back(allocator.clone());
+            e.storage()
+                .instance()
+                .set(&DataKey::AuthorizedAllocators, &allocators);
+        }
+        e.storage().instance().set(&key, &true);
         e.events().publish(
             (Symbol::new(&e, "AuthorizedAllocatorAdded"),),
             (allocator, e.ledger().timestamp()),
@@ -791,12 +1713,28 @@ impl CommitmentCoreContract {
     pub fn remove_allocator(e: Env, caller: Address, allocator: Address) {
         require_admin(&e, &caller);
         e.storage().instance().remove(&DataKey::AuthorizedAllocator(allocator.clone()));
+        let mut allocators = Self::get_allocators(e.clone());
+        if let Some(index) = allocators.first_index_of(&allocator) {
+            allocators.remove(index);
+            e.storage()
+                .instance()
+                .set(&DataKey::AuthorizedAllocators, &allocators);
+        }
         e.events().publish(
             (Symbol::new(&e, "AuthorizedAllocatorRemoved"),),
             (allocator, e.ledger().timestamp()),
         );
     }
 
+    /// Addresses added via `add_allocator` and not since removed, in the order they were
+    /// added. The admin and the allocation contract are allocators too but are not listed.
+    pub fn get_allocators(e: Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::AuthorizedAllocators)
+            .unwrap_or(Vec::new(&e))
+    }
+
     /// Alias for `add_allocator` to maintain backward compatibility with previous versions.
     pub fn add_authorized_contract(e: Env, caller: Address, contract_address: Address) {
         Self::add_allocator(e, caller, contract_address);
@@ -901,17 +1839,29 @@ impl CommitmentCoreContract {
     /// ### Security Notes
     /// - Requires `caller.require_auth()`.
     /// - Enforces `is_updater` check.
+    /// - Rejects commitments at or past `expires_at` with `Matured`; they can only be settled.
+    /// - Rejected while the contract is paused, so a misbehaving oracle can be halted.
+    /// - Fails with `ArithmeticOverflow` if the drawdown percentage overflows `i128`.
     pub fn update_value(e: Env, caller: Address, commitment_id: String, new_value: i128) {
+        require_not_paused(&e, "upd");
         require_authorized_updater(&e, &caller);
         let fn_symbol = symbol_short!("upd_val");
         RateLimiter::check(&e, &caller, &fn_symbol);
         Validation::require_non_negative(new_value);
+        acquire_commitment_lock(&e, &commitment_id);
 
         let mut commitment = read_commitment(&e, &commitment_id)
             .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "upd"));
-        if commitment.status != String::from_str(&e, "active") {
+        if commitment.status != CommitmentStatus::Active {
             fail(&e, CommitmentError::NotActive, "upd");
         }
+        // Past maturity the value is frozen until settlement.
+        if e.ledger().timestamp() >= commitment.expires_at {
+            fail(&e, CommitmentError::Matured, "upd");
+        }
+        if is_asset_paused(&e, &commitment.asset_address) {
+            fail(&e, CommitmentError::AssetPaused, "upd");
+        }
 
         let old_value = commitment.current_value;
 
@@ -919,33 +1869,46 @@ impl CommitmentCoreContract {
         // update_value behavior: the value is always written to storage so that
         // subsequent reads and settlement calculations reflect the update.
         commitment.current_value = new_value;
+        commitment.last_update_ts = e.ledger().timestamp();
 
-        let loss_percent = if commitment.amount > 0 {
-            SafeMath::loss_percent(commitment.amount, new_value)
-        } else {
-            0
-        };
-        let violated = loss_percent > commitment.rules.max_loss_percent as i128;
+        let loss_percent = SafeMath::checked_drawdown_percent(commitment.amount, new_value)
+            .unwrap_or_else(|| fail(&e, CommitmentError::ArithmeticOverflow, "upd"));
+        // High-water mark: a later recovery must not lower the worst drawdown seen.
+        if loss_percent > commitment.max_drawdown_percent as i128 {
+            commitment.max_drawdown_percent = loss_percent as u32;
+        }
+        let max_loss_percent = enforced_max_loss_percent(&e, &commitment);
+        let violated = loss_percent > max_loss_percent as i128;
 
         if violated {
-            commitment.status = String::from_str(&e, "violated");
+            commitment.status = CommitmentStatus::Violated;
+            remove_from_owner_commitments(&e, &commitment.owner, &commitment_id);
+            Storage::push_bounded(
+                &e,
+                &DataKey::ViolationHistory(commitment_id.clone()),
+                (e.ledger().timestamp(), loss_percent, max_loss_percent),
+                MAX_VIOLATION_HISTORY,
+            );
             e.events().publish(
                 (symbol_short!("Violated"), commitment_id.clone()),
                 (
+                    commitment_id.clone(),
                     loss_percent,
-                    commitment.rules.max_loss_percent,
+                    max_loss_percent,
                     e.ledger().timestamp(),
                 ),
             );
-        } else {
+        } else if events_enabled(&e) {
             e.events().publish(
                 (symbol_short!("ValUpd"), commitment_id.clone()),
-                (new_value, e.ledger().timestamp()),
+                (commitment_id.clone(), new_value, e.ledger().timestamp()),
             );
         }
 
         // Persist to storage — value and (potentially) status are both written here.
         set_commitment(&e, &commitment);
+        record_snapshot(&e, &commitment_id, new_value);
+        extend_commitment_ttl(&e, &commitment_id);
 
         // Update TVL by the delta so the aggregate stays consistent with the persisted value.
         let tvl = e.storage().instance().get::<_, i128>(&DataKey::TotalValueLocked).unwrap_or(0);
@@ -954,34 +1917,171 @@ impl CommitmentCoreContract {
             .and_then(|value| value.checked_add(new_value))
             .unwrap_or_else(|| fail(&e, CommitmentError::ArithmeticOverflow, "upd"));
         e.storage().instance().set(&DataKey::TotalValueLocked, &updated_tvl);
+
+        // NFT `settle` requires expiry, so unlocking a violated NFT uses `mark_inactive`.
+        if violated
+            && Self::get_nft_violation_policy(e.clone()) == NftViolationPolicy::UnlockOnViolation
+        {
+            if let Some(nft_contract) = commitment_nft_contract(&e, &commitment_id) {
+                if nft_token_exists(&e, &nft_contract, commitment.nft_token_id) {
+                    let mut args = Vec::new(&e);
+                    args.push_back(e.current_contract_address().into_val(&e));
+                    args.push_back(commitment.nft_token_id.into_val(&e));
+                    e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "mark_inactive"), args);
+                }
+            }
+        }
+        release_commitment_lock(&e, &commitment_id);
+    }
+
+    /// Violations detected by `update_value`, oldest first, as
+    /// `(timestamp, loss_percent, max_loss_percent)`. Keeps the last `MAX_VIOLATION_HISTORY`.
+    ///
+    /// This is the core's own record of when its value checks marked the commitment
+    /// `Violated`. Drawdown breaches reported by verifiers are kept by the attestation
+    /// engine's `get_violation_history` and do not appear here.
+    pub fn get_violation_history(e: Env, commitment_id: String) -> Vec<(u64, i128, u32)> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::ViolationHistory(commitment_id))
+            .unwrap_or_else(|| Vec::new(&e))
+    }
+
+    /// The last `limit` value snapshots recorded by `update_value`, `top_up` and
+    /// `partial_withdraw`, oldest first, as `(timestamp, value)`. A top-up or withdrawal
+    /// therefore shows up as one step in the series.
+    ///
+    /// Only the last `MAX_VALUE_HISTORY` snapshots are kept; older ones are evicted
+    /// as new ones arrive, so off-chain consumers computing time-weighted returns
+    /// should archive the history if they need the full series.
+    pub fn get_value_history(e: Env, commitment_id: String, limit: u32) -> Vec<(u64, i128)> {
+        let history: Vec<(u64, i128)> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::ValueHistory(commitment_id))
+            .unwrap_or_else(|| Vec::new(&e));
+        let len = history.len();
+        if limit >= len {
+            return history;
+        }
+        history.slice(len - limit..len)
+    }
+
+    /// Replace the memo attached to a commitment. Owner only; an empty memo clears it.
+    pub fn set_memo(e: Env, caller: Address, commitment_id: String, memo: String) {
+        caller.require_auth();
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "set_memo"));
+        if commitment.owner != caller {
+            fail(&e, CommitmentError::Unauthorized, "set_memo");
+        }
+        if memo.len() > MAX_MEMO_LENGTH {
+            fail(&e, CommitmentError::MemoTooLong, "set_memo");
+        }
+
+        commitment.memo = memo.clone();
+        set_commitment(&e, &commitment);
+
+        if events_enabled(&e) {
+            e.events().publish(
+                (Symbol::new(&e, "memo_updated"), commitment_id, caller),
+                (memo, e.ledger().timestamp()),
+            );
+        }
+    }
+
+    /// Cross-check the commitment's asset against the one its NFT recorded.
+    ///
+    /// Returns `false` and emits `AssetMismatch` when they diverge. An NFT
+    /// contract that cannot report its asset is treated as consistent.
+    pub fn audit_nft_asset(e: Env, commitment_id: String) -> bool {
+        let commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "audit"));
+        let nft_contract = commitment_nft_contract(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "audit"));
+        match nft_asset_address(&e, &nft_contract, commitment.nft_token_id) {
+            Some(nft_asset) if nft_asset != commitment.asset_address => {
+                e.events().publish(
+                    (Symbol::new(&e, "AssetMismatch"), commitment_id),
+                    (commitment.asset_address, nft_asset, e.ledger().timestamp()),
+                );
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Route settlement proceeds to `beneficiary` instead of the owner (owner only).
+    ///
+    /// The owner keeps control of the commitment (early exit, memo, settlement
+    /// priority); only the settlement payout changes. Pass `None` to pay the owner again.
+    pub fn set_beneficiary(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        beneficiary: Option<Address>,
+    ) {
+        caller.require_auth();
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "set_benef"));
+        if commitment.owner != caller {
+            fail(&e, CommitmentError::Unauthorized, "set_benef");
+        }
+        if commitment.status != CommitmentStatus::Active {
+            fail(&e, CommitmentError::NotActive, "set_benef");
+        }
+
+        commitment.beneficiary = beneficiary.clone();
+        set_commitment(&e, &commitment);
+
+        e.events().publish(
+            (Symbol::new(&e, "BeneficiarySet"), commitment_id, caller),
+            (beneficiary, e.ledger().timestamp()),
+        );
     }
 
+    /// Check whether an active commitment breaks its loss or duration rules.
+    ///
+    /// A stale value (see `set_max_value_age`) is not a violation; it is reported
+    /// separately through a `ValueStale` event so keepers know to refresh it.
     pub fn check_violations(e: Env, commitment_id: String) -> bool {
         let commitment = read_commitment(&e, &commitment_id)
             .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "chk"));
-        if commitment.status != String::from_str(&e, "active") {
+        if commitment.status != CommitmentStatus::Active {
             return false;
         }
+        if value_is_stale(&e, &commitment) {
+            e.events().publish(
+                (Symbol::new(&e, "ValueStale"), commitment_id.clone()),
+                (commitment.last_update_ts, e.ledger().timestamp()),
+            );
+        }
 
         let current_time = e.ledger().timestamp();
-        let loss_percent = if commitment.amount > 0 {
-            SafeMath::loss_percent(commitment.amount, commitment.current_value)
-        } else {
-            0
-        };
-        let violated = (loss_percent > commitment.rules.max_loss_percent as i128)
-            || (current_time >= commitment.expires_at);
+        let loss_percent =
+            SafeMath::checked_drawdown_percent(commitment.amount, commitment.current_value)
+                .unwrap_or_else(|| fail(&e, CommitmentError::ArithmeticOverflow, "chk"));
+        let max_loss_percent = enforced_max_loss_percent(&e, &commitment);
+        let violated =
+            (loss_percent > max_loss_percent as i128) || (current_time >= commitment.expires_at);
 
         if violated {
             e.events().publish(
-                (symbol_short!("Violated"), commitment_id),
-                (symbol_short!("RuleViol"), e.ledger().timestamp()),
+                (symbol_short!("Violated"), commitment_id.clone()),
+                (
+                    commitment_id,
+                    symbol_short!("RuleViol"),
+                    e.ledger().timestamp(),
+                ),
             );
         }
         violated
     }
 
-    pub fn get_violation_details(e: Env, commitment_id: String) -> (bool, bool, bool, i128, u64) {
+    /// Get a named breakdown of which rules a commitment currently violates.
+    ///
+    /// `has_violations` agrees with `check_violations` for active commitments.
+    pub fn get_violation_details(e: Env, commitment_id: String) -> ViolationDetails {
         let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
             fail(
                 &e,
@@ -991,28 +2091,64 @@ impl CommitmentCoreContract {
         });
 
         let now = e.ledger().timestamp();
-        let loss_percent = if commitment.amount > 0 {
-            SafeMath::loss_percent(commitment.amount, commitment.current_value)
+        let loss_percent =
+            SafeMath::checked_drawdown_percent(commitment.amount, commitment.current_value)
+                .unwrap_or_else(|| {
+                    fail(
+                        &e,
+                        CommitmentError::ArithmeticOverflow,
+                        "get_violation_details",
+                    )
+                });
+        let loss_violated = loss_percent > clamped_max_loss_percent(&e, &commitment) as i128;
+        let duration_violated = now >= commitment.expires_at;
+        let fee_violated = false;
+        let reason_code = if loss_violated {
+            VIOLATION_REASON_LOSS
+        } else if duration_violated {
+            VIOLATION_REASON_DURATION
+        } else if fee_violated {
+            VIOLATION_REASON_FEE
         } else {
-            0
+            VIOLATION_REASON_NONE
         };
-        let loss_violated = loss_percent > commitment.rules.max_loss_percent as i128;
-        let duration_violated = now >= commitment.expires_at;
-        let has_violations = loss_violated || duration_violated;
-        let time_remaining = commitment.expires_at.saturating_sub(now);
 
-        (
-            has_violations,
+        ViolationDetails {
+            has_violations: loss_violated || duration_violated || fee_violated,
             loss_violated,
             duration_violated,
+            fee_violated,
             loss_percent,
-            time_remaining,
+            time_remaining: commitment.expires_at.saturating_sub(now),
+            reason_code,
+            value_stale: value_is_stale(&e, &commitment),
+        }
+    }
+
+    /// Deprecated: use `get_violation_details`. Returns
+    /// `(has_violations, loss_violated, duration_violated, loss_percent, time_remaining)`.
+    pub fn get_violation_details_tuple(
+        e: Env,
+        commitment_id: String,
+    ) -> (bool, bool, bool, i128, u64) {
+        let details = Self::get_violation_details(e, commitment_id);
+        (
+            details.has_violations,
+            details.loss_violated,
+            details.duration_violated,
+            details.loss_percent,
+            details.time_remaining,
         )
     }
 
     /// Settle an expired commitment, release assets to the owner, and mark the NFT settled.
     ///
     /// Settles an expired commitment, transfers assets back to the owner, and notifies the NFT contract.
+    /// The protocol fee (`current_value * protocol_fee_bps / 10000`, rounded down) is
+    /// added to the accrued fees first and reported in a `SettlementFee` event. A remaining
+    /// payout below the settlement dust threshold is credited to collected fees instead; the
+    /// `Settled` event data is `(commitment_id, payout, dust_swept, realized_pnl, timestamp)`, where
+    /// `realized_pnl = payout - amount` is also stored on the commitment.
     ///
     /// # Arguments
     /// * `commitment_id` - Unique identifier of the commitment to settle.
@@ -1025,38 +2161,68 @@ impl CommitmentCoreContract {
     /// * `NotInitialized` - If the contract state is missing dependencies.
     ///
     /// # Security
+    /// * Permissionless once `expires_at + settlement_priority_window` has passed; inside the
+    /// window the owner's authorization is required, so keepers cannot crank ahead of them.
     /// * Guarded by a reentrancy flag.
     /// * Follows the check-effects-interactions pattern: status updated before assets transferred.
     /// * Cross-contract dependency: invokes `commitment_nft::settle` after the core state and
     /// token transfer path have been prepared.
     pub fn settle(e: Env, commitment_id: String) {
         require_no_reentrancy(&e);
+        require_not_paused(&e, "settle");
         set_reentrancy_guard(&e, true);
-        Pausable::require_not_paused(&e);
+        acquire_commitment_lock(&e, &commitment_id);
 
-        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
-            set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::CommitmentNotFound, "settle")
-        });
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail_guarded(&e, CommitmentError::CommitmentNotFound, "settle"));
         let current_time = e.ledger().timestamp();
 
         if current_time < commitment.expires_at {
-            set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::NotExpired, "settle");
+            fail_guarded(&e, CommitmentError::NotExpired, "settle");
         }
-        let settled_status = String::from_str(&e, "settled");
-        if commitment.status == settled_status {
-            set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::AlreadySettled, "settle");
+        if commitment.status == CommitmentStatus::Settled {
+            fail_guarded(&e, CommitmentError::AlreadySettled, "settle");
         }
-        if commitment.status != String::from_str(&e, "active") {
-            set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::NotActive, "settle");
+        if commitment.status != CommitmentStatus::Active {
+            fail_guarded(&e, CommitmentError::NotActive, "settle");
         }
+        let priority_window = Self::get_settlement_priority_window(e.clone());
+        if current_time < commitment.expires_at.saturating_add(priority_window) {
+            commitment.owner.require_auth();
+        }
+        let nft_contract = commitment_nft_contract(&e, &commitment_id)
+            .unwrap_or_else(|| fail_guarded(&e, CommitmentError::NotInitialized, "settle"));
 
         let settlement_amount = commitment.current_value;
         let owner = commitment.owner.clone();
-        commitment.status = settled_status;
+        let recipient = commitment
+            .beneficiary
+            .clone()
+            .unwrap_or_else(|| owner.clone());
+
+        // The fee rounds down and bps is capped at 10000, so it never exceeds the value.
+        let settlement_fee = if settlement_amount > 0 {
+            SafeMath::apply_bps(settlement_amount, Self::get_protocol_fee_bps(e.clone()))
+        } else {
+            0
+        };
+        let net_amount = settlement_amount - settlement_fee;
+
+        // Payouts below the dust threshold cost more to move than they are worth;
+        // they are kept as protocol revenue instead.
+        let dust_threshold = Self::get_settlement_dust_threshold(e.clone());
+        let dust_swept = if net_amount > 0 && net_amount < dust_threshold {
+            net_amount
+        } else {
+            0
+        };
+        let payout = net_amount - dust_swept;
+
+        let realized_pnl = payout - commitment.amount;
+        commitment.status = CommitmentStatus::Settled;
+        commitment.settled_amount = payout;
+        commitment.settled_at = current_time;
+        commitment.realized_pnl = realized_pnl;
         set_commitment(&e, &commitment);
         remove_from_owner_commitments(&e, &owner, &commitment_id);
 
@@ -1065,38 +2231,76 @@ impl CommitmentCoreContract {
             .instance()
             .get::<_, i128>(&DataKey::TotalValueLocked)
             .unwrap_or(0);
-        let new_tvl = if tvl > settlement_amount {
-            SafeMath::sub(tvl, settlement_amount)
-        } else {
-            0
-        };
+        let new_tvl = tvl.saturating_sub(settlement_amount).max(0);
         e.storage().instance().set(&DataKey::TotalValueLocked, &new_tvl);
 
-        transfer_assets(
-            &e,
-            &e.current_contract_address(),
-            &owner,
-            &commitment.asset_address,
-            settlement_amount,
-        );
+        if settlement_fee > 0 {
+            let mut accrued = Self::get_accrued_fees(e.clone());
+            let updated_fees = accrued
+                .get(commitment.asset_address.clone())
+                .unwrap_or(0)
+                .checked_add(settlement_fee)
+                .unwrap_or_else(|| fail_guarded(&e, CommitmentError::ArithmeticOverflow, "settle"));
+            accrued.set(commitment.asset_address.clone(), updated_fees);
+            e.storage().instance().set(&DataKey::AccruedFees, &accrued);
+            add_protocol_revenue(&e, &DataKey::TotalSettlementFees, settlement_fee, "settle");
+        }
+        if dust_swept > 0 {
+            let fee_key = DataKey::CollectedFees(commitment.asset_address.clone());
+            let current_fees: i128 = e.storage().instance().get(&fee_key).unwrap_or(0);
+            let updated_fees = current_fees
+                .checked_add(dust_swept)
+                .unwrap_or_else(|| fail_guarded(&e, CommitmentError::ArithmeticOverflow, "settle"));
+            e.storage().instance().set(&fee_key, &updated_fees);
+            add_protocol_revenue(&e, &DataKey::TotalSettlementFees, dust_swept, "settle");
+        }
+        if dust_swept == 0 {
+            transfer_assets(
+                &e,
+                &e.current_contract_address(),
+                &recipient,
+                &commitment.asset_address,
+                payout,
+            );
+        }
 
-        let nft_contract = e
-            .storage()
-            .instance()
-            .get::<_, Address>(&DataKey::NftContract)
-            .unwrap_or_else(|| {
-                set_reentrancy_guard(&e, false);
-                fail(&e, CommitmentError::NotInitialized, "settle")
-            });
-        let mut args = Vec::new(&e);
-        args.push_back(e.current_contract_address().into_val(&e));
-        args.push_back(commitment.nft_token_id.into_val(&e));
-        e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "settle"), args);
+        // A burned NFT must not lock the payout: treat its settlement as done.
+        if nft_token_exists(&e, &nft_contract, commitment.nft_token_id) {
+            let mut args = Vec::new(&e);
+            args.push_back(e.current_contract_address().into_val(&e));
+            args.push_back(commitment.nft_token_id.into_val(&e));
+            e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "settle"), args);
+        } else {
+            e.events().publish(
+                (
+                    Symbol::new(&e, "nft_missing_on_settle"),
+                    commitment_id.clone(),
+                ),
+                (commitment.nft_token_id, e.ledger().timestamp()),
+            );
+        }
 
+        release_commitment_lock(&e, &commitment_id);
         set_reentrancy_guard(&e, false);
+        if settlement_fee > 0 {
+            e.events().publish(
+                (Symbol::new(&e, "SettlementFee"), commitment_id.clone()),
+                (
+                    commitment_id.clone(),
+                    settlement_fee,
+                    e.ledger().timestamp(),
+                ),
+            );
+        }
         e.events().publish(
-            (symbol_short!("Settled"), commitment_id, owner),
-            (settlement_amount, e.ledger().timestamp()),
+            (symbol_short!("Settled"), commitment_id.clone(), owner),
+            (
+                commitment_id,
+                payout,
+                dust_swept,
+                realized_pnl,
+                e.ledger().timestamp(),
+            ),
         );
     }
 
@@ -1114,6 +2318,12 @@ impl CommitmentCoreContract {
     /// The penalty is credited to `CollectedFees(asset_address)` as protocol revenue.
     /// `returned = current_value - penalty` is transferred back to the owner only
     /// when `returned > 0`; a 100% penalty results in no transfer.
+    /// Within the cooling-off period after `created_at` (see `set_cooling_off_period`)
+    /// the exit cancels the commitment: the penalty is zero and the committed
+    /// principal (`amount`) is returned whatever `current_value` has been marked to.
+    /// `allocate` is rejected during that period, so the contract still holds the
+    /// whole principal. TVL drops by the funds released: `amount` for a cancellation,
+    /// `current_value` otherwise.
     ///
     /// # Overflow safety
     /// `SafeMath::mul(current_value, early_exit_penalty as i128)` panics with
@@ -1125,20 +2335,21 @@ impl CommitmentCoreContract {
     /// # Trust boundaries
     /// - Only the commitment owner (verified via `require_auth` + owner equality check)
     ///   may call this function. Admin and third-party addresses are rejected.
-    /// - The commitment must be in `"active"` status; settled, violated, or already-exited
+    /// - The commitment must be `CommitmentStatus::Active`; settled, violated, or already-exited
     ///   commitments are rejected with `CommitmentError::NotActive`.
     ///
     /// # Reentrancy
     /// Protected by the `ReentrancyGuard` storage flag. The guard is cleared before
     /// the outbound `commitment_nft::mark_inactive` call to allow the NFT contract to
     /// read commitment state if needed, but the core state mutation is complete before
-    /// any cross-contract call.
+    /// any cross-contract call. The per-commitment lock is held for the whole call.
     ///
     /// # Errors
     /// - `CommitmentError::CommitmentNotFound` — commitment_id does not exist.
     /// - `CommitmentError::Unauthorized` — caller is not the commitment owner.
-    /// - `CommitmentError::NotActive` — commitment is not in `"active"` status.
+    /// - `CommitmentError::NotActive` — commitment is not `CommitmentStatus::Active`.
     /// - `CommitmentError::ReentrancyDetected` — reentrant call detected.
+    /// - `CommitmentError::CommitmentBusy` — another call is mutating this commitment.
     /// - `CommitmentError::NotInitialized` — NFT contract address not set.
     ///
     /// # Cross-contract dependency
@@ -1146,41 +2357,53 @@ impl CommitmentCoreContract {
     /// and returning the post-penalty amount to the owner.
     pub fn early_exit(e: Env, commitment_id: String, caller: Address) {
         require_no_reentrancy(&e);
+        require_not_paused(&e, "early_exit");
         set_reentrancy_guard(&e, true);
-        Pausable::require_not_paused(&e);
+        acquire_commitment_lock(&e, &commitment_id);
 
-        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
-            set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::CommitmentNotFound, "exit")
-        });
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail_guarded(&e, CommitmentError::CommitmentNotFound, "exit"));
         caller.require_auth();
         if commitment.owner != caller {
-            set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::Unauthorized, "exit");
+            fail_guarded(&e, CommitmentError::Unauthorized, "exit");
         }
-        if commitment.status != String::from_str(&e, "active") {
-            set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::NotActive, "exit");
+        if commitment.status != CommitmentStatus::Active {
+            fail_guarded(&e, CommitmentError::NotActive, "exit");
         }
+        let nft_contract = commitment_nft_contract(&e, &commitment_id)
+            .unwrap_or_else(|| fail_guarded(&e, CommitmentError::NotInitialized, "early_exit"));
 
-        let penalty = SafeMath::penalty_amount(
-            commitment.current_value,
-            commitment.rules.early_exit_penalty,
-        );
-        let returned = SafeMath::sub(commitment.current_value, penalty);
-        let original_val = commitment.current_value;
+        let (penalty, returned, released) = if in_cooling_off(&e, &commitment) {
+            (0, commitment.amount, commitment.amount)
+        } else {
+            let penalty = SafeMath::penalty_amount(
+                commitment.current_value,
+                commitment.rules.early_exit_penalty,
+            );
+            (
+                penalty,
+                SafeMath::sub(commitment.current_value, penalty),
+                commitment.current_value,
+            )
+        };
 
         // Add penalty to collected fees (protocol revenue)
         if penalty > 0 {
             let fee_key = DataKey::CollectedFees(commitment.asset_address.clone());
             let current_fees: i128 = e.storage().instance().get(&fee_key).unwrap_or(0);
-            e.storage()
-                .instance()
-                .set(&fee_key, &(current_fees + penalty));
+            let updated_fees = current_fees.checked_add(penalty).unwrap_or_else(|| {
+                fail_guarded(&e, CommitmentError::ArithmeticOverflow, "early_exit")
+            });
+            e.storage().instance().set(&fee_key, &updated_fees);
+            add_protocol_revenue(&e, &DataKey::TotalPenaltiesCollected, penalty, "early_exit");
         }
 
-        commitment.status = String::from_str(&e, "early_exit");
+        commitment.status = CommitmentStatus::EarlyExit;
+        remove_from_owner_commitments(&e, &commitment.owner, &commitment_id);
         commitment.current_value = 0;
+        commitment.settled_amount = returned;
+        commitment.settled_at = e.ledger().timestamp();
+        commitment.realized_pnl = returned - commitment.amount;
         set_commitment(&e, &commitment);
 
         let tvl = e
@@ -1188,9 +2411,10 @@ impl CommitmentCoreContract {
             .instance()
             .get::<_, i128>(&DataKey::TotalValueLocked)
             .unwrap_or(0);
-        e.storage()
-            .instance()
-            .set(&DataKey::TotalValueLocked, &(tvl - original_val));
+        e.storage().instance().set(
+            &DataKey::TotalValueLocked,
+            &tvl.saturating_sub(released).max(0),
+        );
 
         if returned > 0 {
             transfer_assets(
@@ -1202,24 +2426,374 @@ impl CommitmentCoreContract {
             );
         }
 
-        let nft_contract = e
+        let mut args = Vec::new(&e);
+        args.push_back(e.current_contract_address().into_val(&e));
+        args.push_back(commitment.nft_token_id.into_val(&e));
+        e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "mark_inactive"), args);
+
+        release_commitment_lock(&e, &commitment_id);
+        set_reentrancy_guard(&e, false);
+        e.events().publish(
+            (symbol_short!("EarlyExt"), commitment_id, caller),
+            (penalty, returned, e.ledger().timestamp()),
+        );
+    }
+
+    /// Add `additional_amount` to an active commitment.
+    ///
+    /// Both `amount` and `current_value` grow by `additional_amount`, and no creation
+    /// fee is charged.
+    ///
+    /// # Loss reference
+    /// `amount` remains the loss reference, so after a top-up `update_value` and
+    /// `check_violations` measure loss against the enlarged principal. The absolute loss so far
+    /// (`amount - current_value`) is unchanged, so a top-up neither erases past
+    /// losses nor counts the new funds as a drawdown. The loss *percentage* is
+    /// diluted by the larger principal, as if the funds had been committed up
+    /// front. `max_drawdown_percent` keeps its historical value.
+    ///
+    /// The new `current_value` is recorded as a value-history snapshot, so the next
+    /// `update_value` is measured from the topped-up value rather than the old one, and the
+    /// new `amount` is mirrored onto the NFT's `initial_amount`.
+    ///
+    /// # Errors
+    /// - `CommitmentError::InvalidAmount` — `additional_amount` is not positive.
+    /// - `CommitmentError::CommitmentNotFound` — commitment_id does not exist.
+    /// - `CommitmentError::Unauthorized` — caller is not the commitment owner.
+    /// - `CommitmentError::NotActive` — commitment is not `CommitmentStatus::Active`.
+    /// - `CommitmentError::Matured` — commitment is at or past `expires_at`.
+    /// - `CommitmentError::AssetPaused` — the commitment's asset is paused.
+    /// - `CommitmentError::InsufficientBalance` — owner cannot cover the top-up.
+    pub fn top_up(e: Env, caller: Address, commitment_id: String, additional_amount: i128) {
+        require_no_reentrancy(&e);
+        require_not_paused(&e, "top_up");
+        EmergencyControl::require_not_emergency(&e);
+        set_reentrancy_guard(&e, true);
+        acquire_commitment_lock(&e, &commitment_id);
+
+        if additional_amount <= 0 {
+            fail_guarded(&e, CommitmentError::InvalidAmount, "top_up");
+        }
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail_guarded(&e, CommitmentError::CommitmentNotFound, "top_up"));
+        caller.require_auth();
+        if commitment.owner != caller {
+            fail_guarded(&e, CommitmentError::Unauthorized, "top_up");
+        }
+        if commitment.status != CommitmentStatus::Active {
+            fail_guarded(&e, CommitmentError::NotActive, "top_up");
+        }
+        if e.ledger().timestamp() >= commitment.expires_at {
+            fail_guarded(&e, CommitmentError::Matured, "top_up");
+        }
+        if is_asset_paused(&e, &commitment.asset_address) {
+            fail_guarded(&e, CommitmentError::AssetPaused, "top_up");
+        }
+        check_sufficient_balance(&e, &caller, &commitment.asset_address, additional_amount);
+
+        commitment.amount = commitment
+            .amount
+            .checked_add(additional_amount)
+            .unwrap_or_else(|| fail_guarded(&e, CommitmentError::ArithmeticOverflow, "top_up"));
+        commitment.current_value = commitment
+            .current_value
+            .checked_add(additional_amount)
+            .unwrap_or_else(|| fail_guarded(&e, CommitmentError::ArithmeticOverflow, "top_up"));
+        set_commitment(&e, &commitment);
+        record_snapshot(&e, &commitment_id, commitment.current_value);
+        sync_nft_initial_amount(&e, &commitment, "top_up");
+
+        let tvl = e
             .storage()
             .instance()
-            .get::<_, Address>(&DataKey::NftContract)
+            .get::<_, i128>(&DataKey::TotalValueLocked)
+            .unwrap_or(0);
+        let updated_tvl = tvl
+            .checked_add(additional_amount)
+            .unwrap_or_else(|| fail_guarded(&e, CommitmentError::ArithmeticOverflow, "top_up"));
+        e.storage()
+            .instance()
+            .set(&DataKey::TotalValueLocked, &updated_tvl);
+
+        transfer_assets(
+            &e,
+            &caller,
+            &e.current_contract_address(),
+            &commitment.asset_address,
+            additional_amount,
+        );
+
+        release_commitment_lock(&e, &commitment_id);
+        set_reentrancy_guard(&e, false);
+        e.events().publish(
+            (symbol_short!("TopUp"), commitment_id, caller),
+            (additional_amount, commitment.amount, e.ledger().timestamp()),
+        );
+    }
+
+    /// Withdraw part of an active commitment before maturity.
+    ///
+    /// `amount` is taken out of `current_value`, and `amount` (the principal) shrinks in
+    /// the same proportion so the loss percentage is unchanged. The early-exit penalty
+    /// applies to the withdrawn slice only (`proportional_penalty(amount, early_exit_penalty)`,
+    /// rounded up and raised to `get_min_partial_penalty` so many small withdrawals cannot
+    /// dodge it, capped at `amount`, and zero during the cooling-off period or when the
+    /// rules carry no penalty) and is credited to `CollectedFees(asset_address)`;
+    /// `amount - penalty` is transferred to the owner. The commitment stays active; the new
+    /// `current_value` is recorded as a value-history snapshot and the reduced principal is
+    /// mirrored onto the NFT's `initial_amount`. Use `early_exit` to withdraw everything.
+    ///
+    /// # Errors
+    /// - `CommitmentError::InvalidAmount` — `amount` is not positive.
+    /// - `CommitmentError::CommitmentNotFound` — commitment_id does not exist.
+    /// - `CommitmentError::Unauthorized` — caller is not the commitment owner.
+    /// - `CommitmentError::NotActive` — commitment is not `CommitmentStatus::Active`.
+    /// - `CommitmentError::Matured` — commitment is at or past `expires_at`.
+    /// - `CommitmentError::BelowMinimumBalance` — `current_value - amount` would fall below
+    ///   `get_min_remaining_balance`.
+    /// - `CommitmentError::ArithmeticOverflow` — scaling the principal down overflows `i128`.
+    pub fn partial_withdraw(e: Env, caller: Address, commitment_id: String, amount: i128) {
+        require_no_reentrancy(&e);
+        require_not_paused(&e, "partial_withdraw");
+        set_reentrancy_guard(&e, true);
+        acquire_commitment_lock(&e, &commitment_id);
+
+        if amount <= 0 {
+            fail_guarded(&e, CommitmentError::InvalidAmount, "partial_withdraw");
+        }
+        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail_guarded(&e, CommitmentError::CommitmentNotFound, "partial_withdraw")
+        });
+        caller.require_auth();
+        if commitment.owner != caller {
+            fail_guarded(&e, CommitmentError::Unauthorized, "partial_withdraw");
+        }
+        if commitment.status != CommitmentStatus::Active {
+            fail_guarded(&e, CommitmentError::NotActive, "partial_withdraw");
+        }
+        if e.ledger().timestamp() >= commitment.expires_at {
+            fail_guarded(&e, CommitmentError::Matured, "partial_withdraw");
+        }
+        let remaining = commitment.current_value - amount;
+        if remaining < Self::get_min_remaining_balance(e.clone()) {
+            fail_guarded(&e, CommitmentError::BelowMinimumBalance, "partial_withdraw");
+        }
+
+        let cooling_off_ends = commitment
+            .created_at
+            .saturating_add(Self::get_cooling_off_period(e.clone()));
+        let penalty = if e.ledger().timestamp() < cooling_off_ends {
+            0
+        } else {
+            SafeMath::proportional_penalty(
+                amount,
+                commitment.rules.early_exit_penalty,
+                Self::get_min_partial_penalty(e.clone()),
+            )
+        };
+        let net = amount - penalty;
+
+        if penalty > 0 {
+            let fee_key = DataKey::CollectedFees(commitment.asset_address.clone());
+            let current_fees: i128 = e.storage().instance().get(&fee_key).unwrap_or(0);
+            let updated_fees = current_fees.checked_add(penalty).unwrap_or_else(|| {
+                fail_guarded(&e, CommitmentError::ArithmeticOverflow, "partial_withdraw")
+            });
+            e.storage().instance().set(&fee_key, &updated_fees);
+            add_protocol_revenue(
+                &e,
+                &DataKey::TotalPenaltiesCollected,
+                penalty,
+                "partial_withdraw",
+            );
+        }
+
+        let principal_withdrawn = commitment
+            .amount
+            .checked_mul(amount)
+            .and_then(|scaled| scaled.checked_div(commitment.current_value))
             .unwrap_or_else(|| {
-                set_reentrancy_guard(&e, false);
-                fail(&e, CommitmentError::NotInitialized, "early_exit")
+                fail_guarded(&e, CommitmentError::ArithmeticOverflow, "partial_withdraw")
+            });
+        commitment.amount = commitment
+            .amount
+            .checked_sub(principal_withdrawn)
+            .unwrap_or_else(|| {
+                fail_guarded(&e, CommitmentError::ArithmeticOverflow, "partial_withdraw")
+            });
+        commitment.current_value = remaining;
+        set_commitment(&e, &commitment);
+        record_snapshot(&e, &commitment_id, commitment.current_value);
+        sync_nft_initial_amount(&e, &commitment, "partial_withdraw");
+
+        let tvl = e
+            .storage()
+            .instance()
+            .get::<_, i128>(&DataKey::TotalValueLocked)
+            .unwrap_or(0);
+        e.storage().instance().set(
+            &DataKey::TotalValueLocked,
+            &tvl.saturating_sub(amount).max(0),
+        );
+
+        if net > 0 {
+            transfer_assets(
+                &e,
+                &e.current_contract_address(),
+                &commitment.owner,
+                &commitment.asset_address,
+                net,
+            );
+        }
+
+        release_commitment_lock(&e, &commitment_id);
+        set_reentrancy_guard(&e, false);
+        e.events().publish(
+            (Symbol::new(&e, "PartialWithdraw"), commitment_id, caller),
+            (amount, penalty, net, e.ledger().timestamp()),
+        );
+    }
+
+    /// Lock an active commitment for `extra_days` more days.
+    ///
+    /// Pushes `expires_at` out by `extra_days * 86400` seconds, adds `extra_days` to
+    /// `rules.duration_days`, and mirrors both values onto the linked NFT via
+    /// `extend_expiration`.
+    ///
+    /// # Errors
+    /// - `CommitmentError::InvalidDuration` — `extra_days` is zero.
+    /// - `CommitmentError::CommitmentNotFound` — commitment_id does not exist.
+    /// - `CommitmentError::Unauthorized` — caller is not the commitment owner.
+    /// - `CommitmentError::NotActive` — commitment is not `CommitmentStatus::Active`.
+    /// - `CommitmentError::Matured` — commitment is already at or past `expires_at`.
+    /// - `CommitmentError::ExpirationOverflow` — the new duration or expiry overflows.
+    /// - `CommitmentError::NotInitialized` — no NFT contract is configured.
+    pub fn extend_duration(e: Env, caller: Address, commitment_id: String, extra_days: u32) {
+        require_no_reentrancy(&e);
+        require_not_paused(&e, "extend_duration");
+        EmergencyControl::require_not_emergency(&e);
+        set_reentrancy_guard(&e, true);
+        acquire_commitment_lock(&e, &commitment_id);
+
+        if extra_days == 0 {
+            fail_guarded(&e, CommitmentError::InvalidDuration, "extend_duration");
+        }
+        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail_guarded(&e, CommitmentError::CommitmentNotFound, "extend_duration")
+        });
+        caller.require_auth();
+        if commitment.owner != caller {
+            fail_guarded(&e, CommitmentError::Unauthorized, "extend_duration");
+        }
+        if commitment.status != CommitmentStatus::Active {
+            fail_guarded(&e, CommitmentError::NotActive, "extend_duration");
+        }
+        if e.ledger().timestamp() >= commitment.expires_at {
+            fail_guarded(&e, CommitmentError::Matured, "extend_duration");
+        }
+        let nft_contract = commitment_nft_contract(&e, &commitment_id).unwrap_or_else(|| {
+            fail_guarded(&e, CommitmentError::NotInitialized, "extend_duration")
+        });
+
+        let old_expires_at = commitment.expires_at;
+        commitment.rules.duration_days = commitment
+            .rules
+            .duration_days
+            .checked_add(extra_days)
+            .unwrap_or_else(|| {
+                fail_guarded(&e, CommitmentError::ExpirationOverflow, "extend_duration")
+            });
+        commitment.expires_at = (extra_days as u64)
+            .checked_mul(86400)
+            .and_then(|secs| commitment.expires_at.checked_add(secs))
+            .unwrap_or_else(|| {
+                fail_guarded(&e, CommitmentError::ExpirationOverflow, "extend_duration")
             });
+        set_commitment(&e, &commitment);
 
         let mut args = Vec::new(&e);
         args.push_back(e.current_contract_address().into_val(&e));
         args.push_back(commitment.nft_token_id.into_val(&e));
-        e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "mark_inactive"), args);
+        args.push_back(commitment.rules.duration_days.into_val(&e));
+        args.push_back(commitment.expires_at.into_val(&e));
+        e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "extend_expiration"), args);
 
+        release_commitment_lock(&e, &commitment_id);
         set_reentrancy_guard(&e, false);
         e.events().publish(
-            (symbol_short!("EarlyExt"), commitment_id, caller),
-            (penalty, returned, e.ledger().timestamp()),
+            (symbol_short!("Extended"), commitment_id, caller),
+            (
+                old_expires_at,
+                commitment.expires_at,
+                e.ledger().timestamp(),
+            ),
+        );
+    }
+
+    /// Renegotiate the early-exit penalty of an active commitment (admin only).
+    ///
+    /// `new_penalty` goes through the same checks as `create_commitment`, including
+    /// the per-type minimum, and is mirrored onto the linked NFT via
+    /// `set_early_exit_penalty`. `early_exit` and `partial_withdraw` charge the new
+    /// rate from then on.
+    ///
+    /// # Errors
+    /// - `CommitmentError::Unauthorized` — caller is not the admin.
+    /// - `CommitmentError::CommitmentNotFound` — commitment_id does not exist.
+    /// - `CommitmentError::NotActive` — commitment is not `CommitmentStatus::Active`.
+    /// - `CommitmentError::InvalidEarlyExitPenalty` — `new_penalty` is above 100 or
+    ///   below the type's minimum (safe 15, balanced 10, aggressive 5).
+    /// - `CommitmentError::NotInitialized` — no NFT contract is configured.
+    pub fn set_early_exit_penalty(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        new_penalty: u32,
+    ) {
+        require_admin(&e, &caller);
+        require_no_reentrancy(&e);
+        set_reentrancy_guard(&e, true);
+        acquire_commitment_lock(&e, &commitment_id);
+
+        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail_guarded(
+                &e,
+                CommitmentError::CommitmentNotFound,
+                "set_early_exit_penalty",
+            )
+        });
+        if commitment.status != CommitmentStatus::Active {
+            fail_guarded(&e, CommitmentError::NotActive, "set_early_exit_penalty");
+        }
+        let old_penalty = commitment.rules.early_exit_penalty;
+        commitment.rules.early_exit_penalty = new_penalty;
+        if let Err(err) = Self::validate_rules_check(e.clone(), commitment.rules.clone()) {
+            fail_guarded(&e, err, "set_early_exit_penalty");
+        }
+        let nft_contract = commitment_nft_contract(&e, &commitment_id).unwrap_or_else(|| {
+            fail_guarded(
+                &e,
+                CommitmentError::NotInitialized,
+                "set_early_exit_penalty",
+            )
+        });
+        set_commitment(&e, &commitment);
+
+        let mut args = Vec::new(&e);
+        args.push_back(e.current_contract_address().into_val(&e));
+        args.push_back(commitment.nft_token_id.into_val(&e));
+        args.push_back(new_penalty.into_val(&e));
+        e.invoke_contract::<()>(
+            &nft_contract,
+            &Symbol::new(&e, "set_early_exit_penalty"),
+            args,
+        );
+
+        release_commitment_lock(&e, &commitment_id);
+        set_reentrancy_guard(&e, false);
+        e.events().publish(
+            (Symbol::new(&e, "EarlyExitPenaltySet"), commitment_id),
+            (old_penalty, new_penalty, e.ledger().timestamp()),
         );
     }
 
@@ -1253,6 +2827,293 @@ impl CommitmentCoreContract {
         e.storage().instance().remove(&DataKey::AuthorizedOperator(operator));
     }
 
+    /// Add `owner` to the creation allowlist (admin only).
+    pub fn add_allowed_owner(e: Env, caller: Address, owner: Address) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .set(&DataKey::AllowedOwner(owner.clone()), &true);
+        e.events().publish(
+            (Symbol::new(&e, "OwnerAllowed"), owner),
+            e.ledger().timestamp(),
+        );
+    }
+
+    /// Remove `owner` from the creation allowlist (admin only).
+    ///
+    /// Existing commitments are unaffected; only new creations are gated.
+    pub fn remove_allowed_owner(e: Env, caller: Address, owner: Address) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .remove(&DataKey::AllowedOwner(owner.clone()));
+        e.events().publish(
+            (Symbol::new(&e, "OwnerDisallowed"), owner),
+            e.ledger().timestamp(),
+        );
+    }
+
+    /// Whether `owner` is on the creation allowlist.
+    pub fn is_allowed_owner(e: Env, owner: Address) -> bool {
+        is_allowed_owner(&e, &owner)
+    }
+
+    /// Turn the owner allowlist on or off (admin only).
+    ///
+    /// While disabled (the default) anyone may create commitments.
+    pub fn set_owner_allowlist_enabled(e: Env, caller: Address, enabled: bool) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .set(&DataKey::OwnerAllowlistEnabled, &enabled);
+        e.events().publish(
+            (Symbol::new(&e, "AllowlistToggled"),),
+            (enabled, e.ledger().timestamp()),
+        );
+    }
+
+    /// Whether `create_commitment` is restricted to allowlisted owners.
+    pub fn is_owner_allowlist_enabled(e: Env) -> bool {
+        is_owner_allowlist_enabled(&e)
+    }
+
+    /// Choose what `update_value` does to the NFT of a commitment it marks violated
+    /// (admin only). Defaults to `LockUntilForceSettle`.
+    pub fn set_nft_violation_policy(e: Env, caller: Address, policy: NftViolationPolicy) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .set(&DataKey::NftViolationPolicy, &policy);
+        e.events().publish(
+            (Symbol::new(&e, "NftViolationPolicySet"),),
+            (policy, e.ledger().timestamp()),
+        );
+    }
+
+    /// Current NFT handling for violated commitments.
+    pub fn get_nft_violation_policy(e: Env) -> NftViolationPolicy {
+        e.storage()
+            .instance()
+            .get(&DataKey::NftViolationPolicy)
+            .unwrap_or(NftViolationPolicy::LockUntilForceSettle)
+    }
+
+    /// Set how long after creation `early_exit` charges no penalty (admin only).
+    ///
+    /// `0` (the default) disables the cooling-off period.
+    pub fn set_cooling_off_period(e: Env, caller: Address, cooling_off_secs: u64) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .set(&DataKey::CoolingOffSecs, &cooling_off_secs);
+        e.events().publish(
+            (Symbol::new(&e, "CoolingOffSet"),),
+            (cooling_off_secs, e.ledger().timestamp()),
+        );
+    }
+
+    /// Penalty-free early exit period after creation, in seconds.
+    pub fn get_cooling_off_period(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::CoolingOffSecs)
+            .unwrap_or(0)
+    }
+
+    /// Set the smallest `current_value` a `partial_withdraw` may leave behind (admin only).
+    ///
+    /// Must be at least 1, so a partial withdrawal can never empty a commitment.
+    pub fn set_min_remaining_balance(e: Env, caller: Address, min_balance: i128) {
+        require_admin(&e, &caller);
+        if min_balance < 1 {
+            fail(
+                &e,
+                CommitmentError::InvalidAmount,
+                "set_min_remaining_balance",
+            );
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::MinRemainingBalance, &min_balance);
+        e.events().publish(
+            (Symbol::new(&e, "MinRemainingSet"),),
+            (min_balance, e.ledger().timestamp()),
+        );
+    }
+
+    /// Smallest `current_value` a `partial_withdraw` may leave behind.
+    pub fn get_min_remaining_balance(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MinRemainingBalance)
+            .unwrap_or(1)
+    }
+
+    /// Set the smallest penalty a penalised `partial_withdraw` is charged (admin only).
+    ///
+    /// Applies only when the commitment's `early_exit_penalty` is non-zero and the
+    /// cooling-off period has passed; the penalty never exceeds the withdrawn amount.
+    pub fn set_min_partial_penalty(e: Env, caller: Address, min_penalty: i128) {
+        require_admin(&e, &caller);
+        if min_penalty < 0 {
+            fail(
+                &e,
+                CommitmentError::InvalidAmount,
+                "set_min_partial_penalty",
+            );
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::MinPartialPenalty, &min_penalty);
+        e.events().publish(
+            (Symbol::new(&e, "MinPenaltySet"),),
+            (min_penalty, e.ledger().timestamp()),
+        );
+    }
+
+    /// Smallest penalty a penalised `partial_withdraw` is charged; 0 by default.
+    pub fn get_min_partial_penalty(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MinPartialPenalty)
+            .unwrap_or(0)
+    }
+
+    /// Set how long after expiry only the owner may `settle` (admin only).
+    ///
+    /// Once the window has passed anyone may settle. `0` (the default) makes settlement
+    /// permissionless immediately at expiry.
+    pub fn set_settlement_priority_window(e: Env, caller: Address, window_secs: u64) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .set(&DataKey::SettlementPriorityWindow, &window_secs);
+        e.events().publish(
+            (Symbol::new(&e, "SettleWindowSet"),),
+            (window_secs, e.ledger().timestamp()),
+        );
+    }
+
+    /// Owner-only settlement window after expiry, in seconds.
+    pub fn get_settlement_priority_window(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SettlementPriorityWindow)
+            .unwrap_or(0)
+    }
+
+    /// Set the settlement dust threshold (admin only).
+    ///
+    /// A settlement payout strictly below `threshold` is credited to the asset's
+    /// collected fees instead of being transferred to the owner. `0` (the default)
+    /// disables sweeping.
+    pub fn set_settlement_dust_threshold(e: Env, caller: Address, threshold: i128) {
+        require_admin(&e, &caller);
+        Validation::require_non_negative(threshold);
+        e.storage()
+            .instance()
+            .set(&DataKey::SettlementDustThreshold, &threshold);
+        e.events().publish(
+            (Symbol::new(&e, "DustThresholdSet"),),
+            (threshold, e.ledger().timestamp()),
+        );
+    }
+
+    /// Set how many items a single batch call may process (admin only).
+    ///
+    /// Lets operators tune batch entrypoints against network limits without a
+    /// redeploy; calls above the limit fail with `BatchTooLarge`.
+    pub fn set_max_batch_size(e: Env, caller: Address, max_batch_size: u32) {
+        require_admin(&e, &caller);
+        if max_batch_size == 0 {
+            fail(&e, CommitmentError::InvalidBatchSize, "set_max_batch_size");
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::MaxBatchSize, &max_batch_size);
+        e.events().publish(
+            (Symbol::new(&e, "MaxBatchSizeSet"),),
+            (max_batch_size, e.ledger().timestamp()),
+        );
+    }
+
+    /// Items a single batch call may process.
+    pub fn get_max_batch_size(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxBatchSize)
+            .unwrap_or(DEFAULT_MAX_BATCH_SIZE)
+    }
+
+    /// Settlement payouts below this amount are swept to collected fees.
+    pub fn get_settlement_dust_threshold(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SettlementDustThreshold)
+            .unwrap_or(0)
+    }
+
+    /// Set how long a commitment's value may go without an update before it is
+    /// considered stale (admin only). `0` (the default) disables the check.
+    pub fn set_max_value_age(e: Env, caller: Address, max_value_age_secs: u64) {
+        require_admin(&e, &caller);
+        e.storage()
+            .instance()
+            .set(&DataKey::MaxValueAgeSecs, &max_value_age_secs);
+        e.events().publish(
+            (Symbol::new(&e, "MaxValueAgeSet"),),
+            (max_value_age_secs, e.ledger().timestamp()),
+        );
+    }
+
+    /// Seconds after the last value update before a commitment is stale; `0` when disabled.
+    pub fn get_max_value_age(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxValueAgeSecs)
+            .unwrap_or(0)
+    }
+
+    /// Whether an active commitment's value is older than the configured max age.
+    pub fn is_value_stale(e: Env, commitment_id: String) -> bool {
+        let commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "is_value_stale"));
+        value_is_stale(&e, &commitment)
+    }
+
+    /// Relative cost of settling a commitment, for keepers to prioritize cheap settlements.
+    ///
+    /// A heuristic, not a gas figure: one unit each for the commitment write, the NFT
+    /// cross-call and the payout transfer (skipped for dust), plus one per allocation
+    /// made from the commitment and per entry in the owner's commitment list that
+    /// `settle` scans. Returns 0 for commitments that are no longer active.
+    pub fn settlement_complexity(e: Env, commitment_id: String) -> u32 {
+        let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail(
+                &e,
+                CommitmentError::CommitmentNotFound,
+                "settlement_complexity",
+            )
+        });
+        if commitment.status != CommitmentStatus::Active {
+            return 0;
+        }
+
+        let pays_out = commitment.current_value > 0
+            && commitment.current_value >= Self::get_settlement_dust_threshold(e.clone());
+        let allocations = Self::get_allocations(e.clone(), commitment_id).len();
+        let owner_entries = e
+            .storage()
+            .instance()
+            .get::<_, Vec<String>>(&DataKey::OwnerCommitments(commitment.owner))
+            .map(|ids| ids.len())
+            .unwrap_or(0);
+
+        2u32.saturating_add(pays_out as u32)
+            .saturating_add(allocations)
+            .saturating_add(owner_entries)
+    }
+
     /// Allocates assets from a commitment to a target investment pool.
     ///
     /// This operation is restricted to the admin or an authorized allocator contract.
@@ -1268,6 +3129,8 @@ impl CommitmentCoreContract {
     /// ### Security Notes
     /// - Requires `caller.require_auth()`.
     /// - Enforces `is_allocator` check.
+    /// - Fails with `TooManyAllocations` once `MAX_ALLOCATIONS_PER_COMMITMENT` are recorded.
+    /// - Fails with `InCoolingOff` while the commitment can still be cancelled penalty-free.
     pub fn allocate(
         e: Env,
         caller: Address,
@@ -1277,7 +3140,7 @@ impl CommitmentCoreContract {
     ) {
         require_no_reentrancy(&e);
         set_reentrancy_guard(&e, true);
-        Pausable::require_not_paused(&e);
+        require_not_paused(&e, "allocate");
 
         caller.require_auth();
         if !Self::is_allocator(e.clone(), caller.clone()) {
@@ -1293,22 +3156,42 @@ impl CommitmentCoreContract {
             fail(&e, CommitmentError::InvalidAmount, "allocate");
         }
 
+        acquire_commitment_lock(&e, &commitment_id);
         let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
             set_reentrancy_guard(&e, false);
             fail(&e, CommitmentError::CommitmentNotFound, "allocate")
         });
 
-        let active_status = String::from_str(&e, "active");
-        if commitment.status != active_status {
+        if commitment.status != CommitmentStatus::Active {
             set_reentrancy_guard(&e, false);
             fail(&e, CommitmentError::NotActive, "allocate");
         }
+        if is_asset_paused(&e, &commitment.asset_address) {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::AssetPaused, "allocate");
+        }
+        // A cooling-off exit refunds the whole principal, so none of it may leave yet.
+        if in_cooling_off(&e, &commitment) {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::InCoolingOff, "allocate");
+        }
 
         if commitment.current_value < amount {
             set_reentrancy_guard(&e, false);
             fail(&e, CommitmentError::InsufficientBalance, "allocate");
         }
 
+        let record_key = DataKey::Allocation(commitment_id.clone());
+        let mut records = e
+            .storage()
+            .persistent()
+            .get::<_, Vec<(Address, i128)>>(&record_key)
+            .unwrap_or_else(|| Vec::new(&e));
+        if records.len() >= MAX_ALLOCATIONS_PER_COMMITMENT {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::TooManyAllocations, "allocate");
+        }
+
         let mut updated_commitment = commitment;
         updated_commitment.current_value = SafeMath::sub(updated_commitment.current_value, amount);
         set_commitment(&e, &updated_commitment);
@@ -1330,6 +3213,10 @@ impl CommitmentCoreContract {
             .instance()
             .set(&DataKey::TotalValueLocked, &updated_tvl);
 
+        records.push_back((target_pool.clone(), amount));
+        e.storage().persistent().set(&record_key, &records);
+
+        release_commitment_lock(&e, &commitment_id);
         set_reentrancy_guard(&e, false);
         e.events().publish(
             (symbol_short!("Alloc"), commitment_id, target_pool),
@@ -1337,6 +3224,16 @@ impl CommitmentCoreContract {
         );
     }
 
+    /// Allocations made from a commitment as `(target_pool, amount)`, oldest first,
+    /// so indexers can rebuild its position state. Holds at most
+    /// `MAX_ALLOCATIONS_PER_COMMITMENT` entries.
+    pub fn get_allocations(e: Env, commitment_id: String) -> Vec<(Address, i128)> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Allocation(commitment_id))
+            .unwrap_or_else(|| Vec::new(&e))
+    }
+
     /// Removes an address from the authorized updaters list.
     ///
     /// Restricted to the Admin role.
@@ -1432,6 +3329,82 @@ impl CommitmentCoreContract {
         );
     }
 
+    /// Set the protocol fee rate in basis points (0-10000, admin only).
+    ///
+    /// The fee is taken from `current_value` when a commitment settles and added to the
+    /// accrued fees (see `get_accrued_fees`); `early_exit` is unaffected. `0` (the
+    /// default) disables it.
+    ///
+    /// # Errors
+    /// - `CommitmentError::Unauthorized` if caller is not the admin
+    /// - `CommitmentError::InvalidFeeBps` if bps > 10000
+    pub fn set_protocol_fee_bps(e: Env, caller: Address, bps: u32) {
+        require_admin(&e, &caller);
+        if bps > fees::BPS_MAX {
+            fail(&e, CommitmentError::InvalidFeeBps, "set_protocol_fee_bps");
+        }
+        e.storage().instance().set(&DataKey::ProtocolFeeBps, &bps);
+        e.events().publish(
+            (Symbol::new(&e, "ProtocolFeeSet"),),
+            (bps, e.ledger().timestamp()),
+        );
+    }
+
+    /// Protocol fees taken at `settle` and not yet withdrawn, per asset.
+    pub fn get_accrued_fees(e: Env) -> Map<Address, i128> {
+        e.storage()
+            .instance()
+            .get(&DataKey::AccruedFees)
+            .unwrap_or(Map::new(&e))
+    }
+
+    /// Transfer every accrued protocol fee to `to` and reset the accrual (admin only).
+    ///
+    /// Creation fees, penalties and settlement dust are not included; they stay in
+    /// `CollectedFees` and are withdrawn with `withdraw_fees`.
+    ///
+    /// # Errors
+    /// - `CommitmentError::Unauthorized` if caller is not the admin
+    /// - `CommitmentError::ZeroAddress` if `to` is the zero address
+    /// - `CommitmentError::InsufficientFees` if nothing has accrued
+    pub fn withdraw_accrued_fees(e: Env, caller: Address, to: Address) {
+        require_admin(&e, &caller);
+        if is_zero_address(&e, &to) {
+            fail(&e, CommitmentError::ZeroAddress, "withdraw_accrued_fees");
+        }
+        require_no_reentrancy(&e);
+        set_reentrancy_guard(&e, true);
+
+        let accrued = Self::get_accrued_fees(e.clone());
+        if accrued.is_empty() {
+            fail_guarded(
+                &e,
+                CommitmentError::InsufficientFees,
+                "withdraw_accrued_fees",
+            );
+        }
+        e.storage().instance().remove(&DataKey::AccruedFees);
+
+        for (asset_address, amount) in accrued.iter() {
+            transfer_assets(
+                &e,
+                &e.current_contract_address(),
+                &to,
+                &asset_address,
+                amount,
+            );
+            e.events().publish(
+                (
+                    Symbol::new(&e, "AccruedFeesWithdrawn"),
+                    asset_address,
+                    to.clone(),
+                ),
+                (amount, e.ledger().timestamp()),
+            );
+        }
+        set_reentrancy_guard(&e, false);
+    }
+
     /// Set the fee recipient (protocol treasury) for fee withdrawals.
     ///
     /// # Arguments
@@ -1534,11 +3507,34 @@ impl CommitmentCoreContract {
             .unwrap_or(0)
     }
 
+    /// Get the current protocol fee rate in basis points.
+    pub fn get_protocol_fee_bps(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::ProtocolFeeBps)
+            .unwrap_or(0)
+    }
+
     /// Get the configured fee recipient address.
     pub fn get_fee_recipient(e: Env) -> Option<Address> {
         e.storage().instance().get(&DataKey::FeeRecipient)
     }
 
+    /// Lifetime protocol revenue as `(penalties, fees, settlement_fees)`.
+    ///
+    /// Penalties come from `early_exit`, fees from creation fees and settlement fees
+    /// from the protocol fee and dust swept at `settle`. Totals sum base units across
+    /// every asset and are not reduced by withdrawals; use `get_collected_fees` and
+    /// `get_accrued_fees` for balances.
+    pub fn get_protocol_revenue(e: Env) -> (i128, i128, i128) {
+        let storage = e.storage().instance();
+        (
+            storage.get(&DataKey::TotalPenaltiesCollected).unwrap_or(0),
+            storage.get(&DataKey::TotalFeesCollected).unwrap_or(0),
+            storage.get(&DataKey::TotalSettlementFees).unwrap_or(0),
+        )
+    }
+
     /// Get the collected fees for a specific asset.
     pub fn get_collected_fees(e: Env, asset_address: Address) -> i128 {
         e.storage()
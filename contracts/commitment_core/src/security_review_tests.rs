@@ -75,7 +75,14 @@ fn make_commitment(
         created_at,
         expires_at,
         current_value,
-        status: String::from_str(e, "active"),
+        status: CommitmentStatus::Active,
+        memo: String::from_str(e, ""),
+        settled_amount: 0,
+        settled_at: 0,
+        max_drawdown_percent: 0,
+        last_update_ts: 0,
+        realized_pnl: 0,
+        beneficiary: None,
     }
 }
 
@@ -236,7 +243,7 @@ fn sec_update_value_on_settled_commitment_fails() {
     e.as_contract(&contract_id, || {
         CommitmentCoreContract::initialize(e.clone(), admin.clone(), nft.clone());
         let mut c = make_commitment(&e, "c1", &owner, 1000, 1000, 10, 30, 1000);
-        c.status = String::from_str(&e, "settled");
+        c.status = CommitmentStatus::Settled;
         set_commitment(&e, &c);
         e.storage().instance().set(&DataKey::TotalValueLocked, &1000i128);
     });
@@ -298,7 +305,7 @@ fn sec_settle_by_third_party_sends_tokens_to_owner() {
     let c = e.as_contract(&core, || {
         CommitmentCoreContract::get_commitment(e.clone(), commitment_id.clone())
     });
-    assert_eq!(c.status, String::from_str(&e, "settled"));
+    assert_eq!(c.status, CommitmentStatus::Settled);
 }
 
 /// settle before expiry must always be rejected, regardless of caller.
@@ -344,7 +351,7 @@ fn sec_settle_idempotency_double_settle_rejected() {
     e.as_contract(&contract_id, || {
         CommitmentCoreContract::initialize(e.clone(), admin.clone(), nft.clone());
         let mut c = make_commitment(&e, commitment_id, &owner, 1000, 1000, 10, 30, 1000);
-        c.status = String::from_str(&e, "settled");
+        c.status = CommitmentStatus::Settled;
         set_commitment(&e, &c);
     });
 
@@ -466,7 +473,7 @@ fn sec_loss_percent_exact_boundary_no_violation() {
     let updated = client.get_commitment(&String::from_str(&e, "c1"));
     assert_eq!(
         updated.status,
-        String::from_str(&e, "active"),
+        CommitmentStatus::Active,
         "10% loss at boundary must not trigger violation"
     );
 }
@@ -495,7 +502,7 @@ fn sec_loss_percent_one_over_boundary_triggers_violation() {
     let updated = client.get_commitment(&String::from_str(&e, "c1"));
     assert_eq!(
         updated.status,
-        String::from_str(&e, "violated"),
+        CommitmentStatus::Violated,
         "loss > max_loss_percent must trigger violation"
     );
 }
@@ -522,7 +529,7 @@ fn sec_update_value_zero_amount_no_division_by_zero() {
     client.update_value(&admin, &String::from_str(&e, "c1"), &0);
     // With zero amount the loss_percent path returns 0 → no violation
     let updated = client.get_commitment(&String::from_str(&e, "c1"));
-    assert_eq!(updated.status, String::from_str(&e, "active"));
+    assert_eq!(updated.status, CommitmentStatus::Active);
 }
 
 /// Negative new_value must be rejected.
@@ -42,13 +42,13 @@ use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Sy
 
 use crate::error::Error;
 pub use crate::types::{
-    Commitment, CommitmentCreatedEvent, CommitmentRules, CommitmentSettledEvent,
+    Commitment, CommitmentCreatedEvent, CommitmentRules, CommitmentSettledEvent, CommitmentStatus,
 };
 
 /// =======================
 /// Interface Metadata
 /// =======================
-pub const INTERFACE_VERSION: u32 = 2;
+pub const INTERFACE_VERSION: u32 = 3;
 
 /// =======================
 /// Events
@@ -307,7 +307,7 @@ mod tests {
 
     #[test]
     fn interface_version_tracks_current_abi_generation() {
-        assert_eq!(INTERFACE_VERSION, 2);
+        assert_eq!(INTERFACE_VERSION, 3);
     }
 
     #[test]
@@ -354,6 +354,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn commitment_status_source_matches_live_contracts() {
+        let expected = normalize(&extract_block(
+            INTERFACE_TYPES,
+            "pub enum CommitmentStatus {",
+        ));
+        for source in [CORE_SOURCE, ATTESTATION_SOURCE] {
+            assert_eq!(
+                normalize(&extract_block(source, "pub enum CommitmentStatus {")),
+                expected
+            );
+        }
+    }
+
     #[test]
     fn created_event_source_matches_commitment_core() {
         assert_eq!(
@@ -26,6 +26,19 @@ pub struct CommitmentRules {
     pub grace_period_days: u32,
 }
 
+/// Lifecycle state of a commitment.
+///
+/// Only `Active` commitments accept value updates, allocations, settlement or
+/// early exit; the other variants are terminal.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CommitmentStatus {
+    Active,
+    Settled,
+    Violated,
+    EarlyExit,
+}
+
 /// Canonical commitment record returned by live on-chain contracts.
 ///
 /// # Security
@@ -53,8 +66,22 @@ pub struct Commitment {
     pub expires_at: u64,
     /// Latest tracked value for the position.
     pub current_value: i128,
-    /// Lifecycle status such as `active`, `settled`, `violated`, or `early_exit`.
-    pub status: String,
+    /// Current lifecycle state.
+    pub status: CommitmentStatus,
+    /// Optional owner-supplied note (empty when unset), capped at `MAX_MEMO_LENGTH` bytes.
+    pub memo: String,
+    /// Amount paid out to the owner on settlement or early exit; 0 while open.
+    pub settled_amount: i128,
+    /// Ledger timestamp of settlement or early exit; 0 while open.
+    pub settled_at: u64,
+    /// Worst loss percent seen by `update_value`; a later recovery does not lower it.
+    pub max_drawdown_percent: u32,
+    /// Ledger timestamp of the most recent value update (creation time until the first update).
+    pub last_update_ts: u64,
+    /// Final payout minus `amount`; set by `settle` and `early_exit`, 0 while open.
+    pub realized_pnl: i128,
+    /// Recipient of settlement proceeds; `None` means the owner.
+    pub beneficiary: Option<Address>,
 }
 
 /// Event payload emitted by the live core contract when a commitment is created.
@@ -23,18 +23,20 @@
 //!
 //! | Caller role | What they can do |
 //! |-------------|-----------------|
-//! | Admin | Initialize, pause/unpause, set core contract, manage minter whitelist, upgrade, migrate, emergency mode |
+//! | Admin | Initialize, pause/unpause, pause/resume transfers, set core contract, manage minter whitelist, upgrade, migrate, emergency mode |
 //! | Core contract (`set_core_contract`) | Call `mint` as an authorized minter |
 //! | Whitelisted minter (`add_authorized_contract`) | Call `mint` |
-//! | NFT owner | `transfer` (inactive NFTs only) |
-//! | Anyone | All view functions (`get_metadata`, `owner_of`, etc.) |
+//! | NFT owner | `transfer` (inactive NFTs only), `get_private_metadata`, `set_token_uri` |
+//! | Anyone | All other view functions (`get_metadata`, `owner_of`, etc.) |
 //!
 //! ## Reentrancy
 //!
-//! All state-mutating functions use a flag-based reentrancy guard
-//! (`DataKey::ReentrancyGuard`). The guard is set on entry and cleared before
-//! every return path (including errors), so nested calls from the same
-//! contract invocation are rejected with `ReentrancyDetected`.
+//! Token-moving and lifecycle functions (`mint`, `transfer`, `transfer_from`,
+//! `transfer_all`, `mark_inactive`, `settle`, `burn`) use a flag-based
+//! reentrancy guard (`DataKey::ReentrancyGuard`). `check_and_set_guard` takes it
+//! on entry and `clear_guard` releases it before every returned result
+//! (including errors), so nested calls made while it is held are rejected with
+//! `ReentrancyDetected`.
 
 #![no_std]
 //! Commitment NFT contract.
@@ -42,7 +44,18 @@
 //! This contract mirrors the lifecycle of commitments managed by
 //! `commitment_core`. Minting, settlement, and early-exit deactivation mutate
 //! NFT state and therefore must only be driven by trusted protocol contracts.
-use shared_utils::{EmergencyControl, Pausable, SafeMath};
+//!
+//! ## Lifecycle events
+//!
+//! Data is a tuple whose first element is the `token_id` and whose last element
+//! is the ledger timestamp. `Transfer` keeps both parties in its topics.
+//!
+//! | Event      | Topics                    | Data                                  |
+//! |------------|---------------------------|---------------------------------------|
+//! | `Mint`     | `(Mint, token_id, owner)` | `(token_id, commitment_id, timestamp)` |
+//! | `Transfer` | `(Transfer, from, to)`    | `(token_id, timestamp)`               |
+//! | `Settle`   | `(Settle, token_id)`      | `(token_id, timestamp)`               |
+use shared_utils::{EmergencyControl, Pausable, SafeMath, Storage};
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
     String, Symbol, Vec,
@@ -55,6 +68,23 @@ pub const CURRENT_VERSION: u32 = 2;
 #[allow(dead_code)]
 const MAX_COMMITMENT_ID_LENGTH: u32 = 256;
 
+/// Maximum NFTs a single address may hold. `OwnerTokens` is one Vec scanned on every
+/// transfer, so mint and transfer refuse to grow it past this size.
+pub const MAX_TOKENS_PER_OWNER: u32 = 200;
+
+/// Maximum NFTs moved by a single `transfer_all` call, to stay within instruction limits.
+pub const MAX_TRANSFER_ALL_BATCH: u32 = 50;
+
+/// Maximum token ids returned by one `get_tokens_by_owner_paginated` call.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Default maximum NFTs minted by a single `batch_mint` call, to stay within ledger
+/// limits, until the admin tunes it with `set_max_batch_size`.
+pub const MAX_BATCH_MINT: u32 = 25;
+
+/// Maximum length in bytes of a token's metadata URI.
+pub const MAX_TOKEN_URI_LENGTH: u32 = 512;
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -108,6 +138,20 @@ pub enum ContractError {
     InvalidCommitmentId = 21,
     /// Given address is a zero/invalid address
     InvalidAddress = 22,
+    /// Recipient already holds `MAX_TOKENS_PER_OWNER` NFTs
+    TooManyTokens = 23,
+    /// Transfers are frozen by `pause_transfers`
+    TransfersPaused = 24,
+    /// Invalid early-exit penalty (must be 0-100)
+    InvalidPenalty = 25,
+    /// No metadata URI has been set for the token
+    TokenUriNotSet = 26,
+    /// Metadata URI is empty or longer than `MAX_TOKEN_URI_LENGTH`
+    InvalidTokenUri = 27,
+    /// Batch is empty, or the maximum batch size was set to 0
+    InvalidBatchSize = 28,
+    /// Batch holds more items than `get_max_batch_size`
+    BatchTooLarge = 29,
 }
 
 // ============================================================================
@@ -164,6 +208,37 @@ pub struct CommitmentNFT {
     pub early_exit_penalty: u32,
 }
 
+/// Non-sensitive view of a commitment NFT returned by `get_metadata`.
+///
+/// Omits the amount, asset and risk parameters; those are only returned by
+/// `get_private_metadata` to the owner or admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicCommitmentMetadata {
+    pub owner: Address,
+    pub token_id: u32,
+    pub commitment_id: String,
+    pub duration_days: u32,
+    pub commitment_type: String,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub is_active: bool,
+}
+
+/// Parameters for one NFT in a `batch_mint` call; fields match `mint`. As with
+/// `mint`, the commitment id is generated from the token id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintParams {
+    pub owner: Address,
+    pub duration_days: u32,
+    pub max_loss_percent: u32,
+    pub commitment_type: String,
+    pub initial_amount: i128,
+    pub asset_address: Address,
+    pub early_exit_penalty: u32,
+}
+
 /// Parameters for batch NFT transfer operations
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -194,6 +269,8 @@ pub enum DataKey {
     CoreContract,
     /// Authorized minter addresses (from upstream)
     AuthorizedMinter(Address),
+    /// Every `AuthorizedMinter`, in the order it was added (for enumeration)
+    AuthorizedMinters,
     /// Active status (token_id -> bool)
     ActiveStatus(u32),
     /// Reentrancy guard flag
@@ -202,6 +279,18 @@ pub enum DataKey {
     Version,
     /// Mapping from commitment_id to token_id for reverse lookup (commitment_id -> token_id)
     CommitmentIdIndex(String),
+    /// Number of burned tokens; `TokenCounter` keeps issuing fresh ids
+    BurnedCount,
+    /// Address approved to transfer a single token (token_id -> Address)
+    Approved(u32),
+    /// Operator approval for all of an owner's tokens ((owner, operator) -> bool)
+    OperatorApproval(Address, Address),
+    /// Transfer freeze flag set by `pause_transfers`
+    TransfersPaused,
+    /// Off-chain metadata URI (token_id -> String)
+    TokenURI(u32),
+    /// Items accepted per `batch_mint` call (u32, default `MAX_BATCH_MINT`)
+    MaxBatchSize,
 }
 
 #[cfg(all(test, feature = "legacy-test-suite"))]
@@ -314,6 +403,43 @@ impl CommitmentNFTContract {
         Pausable::is_paused(&e)
     }
 
+    /// Freeze all transfers (admin only).
+    ///
+    /// Unlike `pause`, minting and settlement keep working so the core
+    /// contract can still operate; only `transfer`, `transfer_from` and
+    /// `transfer_all` fail with [`ContractError::TransfersPaused`].
+    pub fn pause_transfers(e: Env) -> Result<(), ContractError> {
+        Self::set_transfers_paused(&e, true)
+    }
+
+    /// Lift a freeze set by `pause_transfers` (admin only).
+    pub fn resume_transfers(e: Env) -> Result<(), ContractError> {
+        Self::set_transfers_paused(&e, false)
+    }
+
+    /// Check if transfers are frozen
+    pub fn are_transfers_paused(e: Env) -> bool {
+        transfers_paused(&e)
+    }
+
+    fn set_transfers_paused(e: &Env, paused: bool) -> Result<(), ContractError> {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .set(&DataKey::TransfersPaused, &paused);
+        e.events().publish(
+            (Symbol::new(e, "TransfersPaused"),),
+            (paused, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
     /// Validate commitment type
     fn is_valid_commitment_type(e: &Env, commitment_type: &String) -> bool {
         let safe = String::from_str(e, "safe");
@@ -521,9 +647,15 @@ impl CommitmentNFTContract {
             return Err(ContractError::InvalidAddress);
         }
 
-        e.storage()
-            .instance()
-            .set(&DataKey::AuthorizedMinter(contract_address.clone()), &true);
+        let key = DataKey::AuthorizedMinter(contract_address.clone());
+        if !e.storage().instance().has(&key) {
+            let mut minters = Self::get_authorized_contracts(e.clone());
+            minters.push_back(contract_address.clone());
+            e.storage()
+                .instance()
+                .set(&DataKey::AuthorizedMinters, &minters);
+        }
+        e.storage().instance().set(&key, &true);
         e.events().publish(
             (Symbol::new(&e, "AuthorizedContractAdded"),),
             (contract_address, e.ledger().timestamp()),
@@ -541,6 +673,13 @@ impl CommitmentNFTContract {
         e.storage()
             .instance()
             .remove(&DataKey::AuthorizedMinter(contract_address.clone()));
+        let mut minters = Self::get_authorized_contracts(e.clone());
+        if let Some(index) = minters.first_index_of(&contract_address) {
+            minters.remove(index);
+            e.storage()
+                .instance()
+                .set(&DataKey::AuthorizedMinters, &minters);
+        }
         e.events().publish(
             (Symbol::new(&e, "AuthorizedContractRemoved"),),
             (contract_address, e.ledger().timestamp()),
@@ -548,6 +687,15 @@ impl CommitmentNFTContract {
         Ok(())
     }
 
+    /// List the whitelisted minters, in the order they were added. The admin and core
+    /// contract may also mint but are not listed.
+    pub fn get_authorized_contracts(e: Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::AuthorizedMinters)
+            .unwrap_or(Vec::new(&e))
+    }
+
     /// Return true if the given address may call mint() (admin, core contract, or in whitelist).
     pub fn is_authorized(e: Env, contract_address: Address) -> bool {
         if let Some(admin) = e.storage().instance().get::<_, Address>(&DataKey::Admin) {
@@ -587,7 +735,7 @@ impl CommitmentNFTContract {
         Ok(())
     }
 
-    /// Upgrade contract WASM (admin-only).
+    /// Upgrade contract WASM (admin-only). Emits `upgraded` with the new hash.
     pub fn upgrade(
         e: Env,
         caller: Address,
@@ -595,7 +743,12 @@ impl CommitmentNFTContract {
     ) -> Result<(), ContractError> {
         require_admin(&e, &caller)?;
         require_valid_wasm_hash(&e, &new_wasm_hash)?;
-        e.deployer().update_current_contract_wasm(new_wasm_hash);
+        e.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        e.events().publish(
+            (symbol_short!("upgraded"), caller),
+            (new_wasm_hash, e.ledger().timestamp()),
+        );
         Ok(())
     }
 
@@ -649,44 +802,16 @@ impl CommitmentNFTContract {
         asset_address: Address,
         early_exit_penalty: u32,
     ) -> Result<u32, ContractError> {
-        // Reentrancy protection
-        let guard: bool = e
-            .storage()
-            .instance()
-            .get(&DataKey::ReentrancyGuard)
-            .unwrap_or(false);
-
-        if guard {
-            return Err(ContractError::ReentrancyDetected);
-        }
-        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+        check_and_set_guard(&e)?;
         EmergencyControl::require_not_emergency(&e);
 
         // Check if contract is paused
         Pausable::require_not_paused(&e);
 
-        if !e.storage().instance().has(&DataKey::Admin) {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::NotInitialized);
-        }
-
         // --- Authorization: enforce on-chain signature from caller ---
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        let core_contract: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
-        let is_authorized_minter = e
-            .storage()
-            .instance()
-            .get(&DataKey::AuthorizedMinter(caller.clone()))
-            .unwrap_or(false);
-        let allowed =
-            (caller == admin) || (core_contract.as_ref() == Some(&caller)) || is_authorized_minter;
-        if !allowed {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::NotAuthorized);
+        if let Err(err) = require_minter(&e, &caller) {
+            clear_guard(&e);
+            return Err(err);
         }
         // Require a valid on-chain authorization from the caller.
         // This must come AFTER the allowlist check so that only whitelisted
@@ -694,45 +819,148 @@ impl CommitmentNFTContract {
         // consumption by arbitrary addresses.
         caller.require_auth();
 
+        let result = Self::mint_token(
+            &e,
+            owner,
+            duration_days,
+            max_loss_percent,
+            commitment_type,
+            initial_amount,
+            asset_address,
+            early_exit_penalty,
+        );
+        clear_guard(&e);
+        result
+    }
+
+    /// Mint one NFT per entry of `params` in a single call. Caller must be admin
+    /// or an authorized minter.
+    ///
+    /// Every entry goes through the same checks as `mint`; if any fails, the whole
+    /// call fails and no token from the batch is minted. Returns the new token ids
+    /// in input order.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidBatchSize`] if `params` is empty.
+    /// - [`ContractError::BatchTooLarge`] if `params` is longer than `get_max_batch_size`.
+    /// - Any error `mint` returns for an individual entry.
+    pub fn batch_mint(
+        e: Env,
+        caller: Address,
+        params: Vec<MintParams>,
+    ) -> Result<Vec<u32>, ContractError> {
+        check_and_set_guard(&e)?;
+        EmergencyControl::require_not_emergency(&e);
+        Pausable::require_not_paused(&e);
+
+        if let Err(err) = require_minter(&e, &caller) {
+            clear_guard(&e);
+            return Err(err);
+        }
+        caller.require_auth();
+
+        if params.is_empty() {
+            clear_guard(&e);
+            return Err(ContractError::InvalidBatchSize);
+        }
+        if params.len() > Self::get_max_batch_size(e.clone()) {
+            clear_guard(&e);
+            return Err(ContractError::BatchTooLarge);
+        }
+
+        let mut token_ids = Vec::new(&e);
+        for p in params.iter() {
+            // Returning the error reverts the tokens minted earlier in the loop.
+            match Self::mint_token(
+                &e,
+                p.owner,
+                p.duration_days,
+                p.max_loss_percent,
+                p.commitment_type,
+                p.initial_amount,
+                p.asset_address,
+                p.early_exit_penalty,
+            ) {
+                Ok(token_id) => token_ids.push_back(token_id),
+                Err(err) => {
+                    clear_guard(&e);
+                    return Err(err);
+                }
+            }
+        }
+
+        clear_guard(&e);
+        Ok(token_ids)
+    }
+
+    /// Set how many NFTs a single `batch_mint` call may carry (admin only), so operators
+    /// can tune against network limits without a redeploy.
+    pub fn set_max_batch_size(
+        e: Env,
+        caller: Address,
+        max_batch_size: u32,
+    ) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        if max_batch_size == 0 {
+            return Err(ContractError::InvalidBatchSize);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::MaxBatchSize, &max_batch_size);
+        e.events().publish(
+            (Symbol::new(&e, "MaxBatchSizeSet"),),
+            (max_batch_size, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// NFTs a single `batch_mint` call may carry.
+    pub fn get_max_batch_size(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxBatchSize)
+            .unwrap_or(MAX_BATCH_MINT)
+    }
+
+    /// Validate and store one NFT; shared by `mint` and `batch_mint`, which handle
+    /// auth and the reentrancy guard.
+    #[allow(clippy::too_many_arguments)]
+    fn mint_token(
+        e: &Env,
+        owner: Address,
+        duration_days: u32,
+        max_loss_percent: u32,
+        commitment_type: String,
+        initial_amount: i128,
+        asset_address: Address,
+        early_exit_penalty: u32,
+    ) -> Result<u32, ContractError> {
         // CHECKS: Reject zero address owner
-        if is_zero_address(&e, &owner) {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
+        if is_zero_address(e, &owner) {
             return Err(ContractError::TransferToZeroAddress);
         }
 
         // CHECKS: Reject zero address for asset
-        if is_zero_address(&e, &asset_address) {
-            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        if is_zero_address(e, &asset_address) {
             return Err(ContractError::InvalidAddress);
         }
 
         // Validate inputs
         if duration_days == 0 {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
             return Err(ContractError::InvalidDuration);
         }
         if max_loss_percent > 100 {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
             return Err(ContractError::InvalidMaxLoss);
         }
-        if !Self::is_valid_commitment_type(&e, &commitment_type) {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
+        if !Self::is_valid_commitment_type(e, &commitment_type) {
             return Err(ContractError::InvalidCommitmentType);
         }
         if initial_amount < 0 {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
             return Err(ContractError::InvalidAmount);
         }
+        if Self::balance_of(e.clone(), owner.clone()) >= MAX_TOKENS_PER_OWNER {
+            return Err(ContractError::TooManyTokens);
+        }
 
         // Calculate timestamps with overflow check (duration_days * 86400 + created_at must fit in u64)
         let created_at = e.ledger().timestamp();
@@ -740,18 +968,12 @@ impl CommitmentNFTContract {
         let duration_seconds = match (duration_days as u64).checked_mul(seconds_per_day) {
             Some(s) => s,
             None => {
-                e.storage()
-                    .instance()
-                    .set(&DataKey::ReentrancyGuard, &false);
                 return Err(ContractError::ExpirationOverflow);
             }
         };
         let expires_at = match created_at.checked_add(duration_seconds) {
             Some(t) => t,
             None => {
-                e.storage()
-                    .instance()
-                    .set(&DataKey::ReentrancyGuard, &false);
                 return Err(ContractError::ExpirationOverflow);
             }
         };
@@ -770,7 +992,7 @@ impl CommitmentNFTContract {
             .set(&DataKey::TokenCounter, &next_token_id);
 
         // Generate unique commitment_id based on token_id
-        let generated_commitment_id = Self::format_commitment_id(&e, token_id);
+        let generated_commitment_id = Self::format_commitment_id(e, token_id);
 
         // Register commitment_id in the index for reverse lookup
         e.storage().persistent().set(
@@ -819,7 +1041,7 @@ impl CommitmentNFTContract {
             .storage()
             .persistent()
             .get(&DataKey::OwnerTokens(owner.clone()))
-            .unwrap_or(Vec::new(&e));
+            .unwrap_or(Vec::new(e));
         owner_tokens.push_back(token_id);
         e.storage()
             .persistent()
@@ -830,19 +1052,15 @@ impl CommitmentNFTContract {
             .storage()
             .persistent()
             .get(&DataKey::TokenIds)
-            .unwrap_or(Vec::new(&e));
+            .unwrap_or(Vec::new(e));
         token_ids.push_back(token_id);
         e.storage().persistent().set(&DataKey::TokenIds, &token_ids);
-
-        // Clear reentrancy guard
-        e.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &false);
+        extend_nft_ttl(e, token_id, &owner);
 
         // Emit mint event
         e.events().publish(
             (symbol_short!("Mint"), token_id, owner.clone()),
-            (generated_commitment_id, e.ledger().timestamp()),
+            (token_id, generated_commitment_id, e.ledger().timestamp()),
         );
 
         Ok(token_id)
@@ -852,14 +1070,63 @@ impl CommitmentNFTContract {
     // NFT Query Functions
     // ========================================================================
 
-    /// Get full `CommitmentNFT` metadata by `token_id`.
+    /// Get the non-sensitive metadata of an NFT by `token_id`.
     ///
-    /// Returns the stored `CommitmentNFT` or `TokenNotFound` if missing.
-    pub fn get_metadata(e: Env, token_id: u32) -> Result<CommitmentNFT, ContractError> {
-        e.storage()
+    /// Returns duration, type, timing and status only. Use `get_private_metadata`
+    /// for the amount, asset and risk parameters.
+    pub fn get_metadata(e: Env, token_id: u32) -> Result<PublicCommitmentMetadata, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
             .persistent()
             .get(&DataKey::NFT(token_id))
-            .ok_or(ContractError::TokenNotFound)
+            .ok_or(ContractError::TokenNotFound)?;
+        extend_nft_ttl(&e, token_id, &nft.owner);
+
+        Ok(PublicCommitmentMetadata {
+            owner: nft.owner,
+            token_id: nft.token_id,
+            commitment_id: nft.metadata.commitment_id,
+            duration_days: nft.metadata.duration_days,
+            commitment_type: nft.metadata.commitment_type,
+            created_at: nft.metadata.created_at,
+            expires_at: nft.metadata.expires_at,
+            is_active: nft.is_active,
+        })
+    }
+
+    /// Get full `CommitmentNFT` metadata by `token_id`, including sensitive fields.
+    ///
+    /// # Errors
+    /// - [`ContractError::TokenNotFound`] if the NFT does not exist.
+    /// - [`ContractError::NotAuthorized`] if `caller` is not the owner, the admin or
+    ///   the core contract.
+    ///
+    /// # Security
+    /// - Requires `caller.require_auth()` so the read is signed by an allowed reader.
+    pub fn get_private_metadata(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+    ) -> Result<CommitmentNFT, ContractError> {
+        caller.require_auth();
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        require_private_reader(&e, &caller, &nft)?;
+
+        Ok(nft)
+    }
+
+    /// Get the asset recorded for `token_id`, under the same access rules as
+    /// `get_private_metadata`. Lets `commitment_core` cross-check its own record.
+    pub fn get_asset_address(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+    ) -> Result<Address, ContractError> {
+        Self::get_private_metadata(e, caller, token_id).map(|nft| nft.metadata.asset_address)
     }
 
     /// Lookup a `CommitmentNFT` by its auto-generated `commitment_id`.
@@ -884,6 +1151,70 @@ impl CommitmentNFTContract {
             .ok_or(ContractError::TokenNotFound)
     }
 
+    /// Set the off-chain metadata URI of `token_id`, replacing any previous one.
+    ///
+    /// Follows the ERC-721 metadata convention: the URI points at a JSON document
+    /// describing the token for marketplaces and wallets.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenUri`] if `uri` is empty or longer than
+    ///   `MAX_TOKEN_URI_LENGTH` bytes.
+    /// - [`ContractError::TokenNotFound`] if the token does not exist.
+    /// - [`ContractError::NotAuthorized`] if `caller` is neither the owner nor the admin.
+    ///
+    /// # Security
+    /// - Requires `caller.require_auth()`.
+    pub fn set_token_uri(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+        uri: String,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if uri.is_empty() || uri.len() > MAX_TOKEN_URI_LENGTH {
+            return Err(ContractError::InvalidTokenUri);
+        }
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if caller != nft.owner {
+            let admin: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .ok_or(ContractError::NotInitialized)?;
+            if caller != admin {
+                return Err(ContractError::NotAuthorized);
+            }
+        }
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::TokenURI(token_id), &uri);
+        e.events().publish(
+            (symbol_short!("TokenUri"), token_id, caller),
+            (token_id, uri, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the off-chain metadata URI of `token_id`.
+    ///
+    /// # Errors
+    /// - [`ContractError::TokenNotFound`] if the token does not exist.
+    /// - [`ContractError::TokenUriNotSet`] if no URI has been set.
+    pub fn token_uri(e: Env, token_id: u32) -> Result<String, ContractError> {
+        if !e.storage().persistent().has(&DataKey::NFT(token_id)) {
+            return Err(ContractError::TokenNotFound);
+        }
+        e.storage()
+            .persistent()
+            .get(&DataKey::TokenURI(token_id))
+            .ok_or(ContractError::TokenUriNotSet)
+    }
+
     /// Get owner of NFT
     pub fn owner_of(e: Env, token_id: u32) -> Result<Address, ContractError> {
         let nft: CommitmentNFT = e
@@ -891,6 +1222,7 @@ impl CommitmentNFTContract {
             .persistent()
             .get(&DataKey::NFT(token_id))
             .ok_or(ContractError::TokenNotFound)?;
+        extend_nft_ttl(&e, token_id, &nft.owner);
 
         Ok(nft.owner)
     }
@@ -920,134 +1252,214 @@ impl CommitmentNFTContract {
         to: Address,
         token_id: u32,
     ) -> Result<(), ContractError> {
-        // Reentrancy protection
-        let guard: bool = e
-            .storage()
-            .instance()
-            .get(&DataKey::ReentrancyGuard)
-            .unwrap_or(false);
+        transfer_token(&e, &from, &from, &to, token_id)
+    }
+
+    /// Approve `spender` to transfer a single NFT on the owner's behalf.
+    ///
+    /// Only one approved address exists per token; approving again replaces it.
+    /// The approval is cleared whenever the token changes hands or is burned.
+    ///
+    /// # Errors
+    /// - [`ContractError::TokenNotFound`] if the token does not exist.
+    /// - [`ContractError::NotOwner`] if `owner` does not own the token.
+    ///
+    /// # Security
+    /// - Requires `owner.require_auth()`.
+    pub fn approve(
+        e: Env,
+        owner: Address,
+        spender: Address,
+        token_id: u32,
+    ) -> Result<(), ContractError> {
+        owner.require_auth();
 
-        if guard {
-            return Err(ContractError::ReentrancyDetected);
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if nft.owner != owner {
+            return Err(ContractError::NotOwner);
         }
-        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
-        EmergencyControl::require_not_emergency(&e);
 
-        // Check if contract is paused
-        Pausable::require_not_paused(&e);
+        e.storage()
+            .persistent()
+            .set(&DataKey::Approved(token_id), &spender);
 
-        // CHECKS: Require authorization from the sender
-        from.require_auth();
+        e.events().publish(
+            (symbol_short!("Approval"), owner, spender),
+            (token_id, e.ledger().timestamp()),
+        );
 
-        // Validate 'to' address is not the same as 'from' (prevent self-transfer)
-        if to == from {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::TransferToZeroAddress);
+        Ok(())
+    }
+
+    /// Get the address approved to transfer `token_id`, if any.
+    pub fn get_approved(e: Env, token_id: u32) -> Result<Option<Address>, ContractError> {
+        if !e.storage().persistent().has(&DataKey::NFT(token_id)) {
+            return Err(ContractError::TokenNotFound);
         }
 
-        // CHECKS: Reject transfer to zero address
-        if is_zero_address(&e, &to) {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::TransferToZeroAddress);
+        Ok(e.storage().persistent().get(&DataKey::Approved(token_id)))
+    }
+
+    /// Grant or revoke `operator` permission to transfer every NFT held by `owner`.
+    ///
+    /// # Security
+    /// - Requires `owner.require_auth()`.
+    pub fn set_approval_for_all(e: Env, owner: Address, operator: Address, approved: bool) {
+        owner.require_auth();
+
+        let key = DataKey::OperatorApproval(owner.clone(), operator.clone());
+        if approved {
+            e.storage().persistent().set(&key, &true);
+        } else {
+            e.storage().persistent().remove(&key);
         }
 
-        // Get the NFT
-        let mut nft: CommitmentNFT = e
-            .storage()
+        e.events().publish(
+            (symbol_short!("ApprAll"), owner, operator),
+            (approved, e.ledger().timestamp()),
+        );
+    }
+
+    /// Check whether `operator` may transfer every NFT held by `owner`.
+    pub fn is_approved_for_all(e: Env, owner: Address, operator: Address) -> bool {
+        e.storage()
             .persistent()
-            .get(&DataKey::NFT(token_id))
-            .ok_or_else(|| {
-                e.storage()
-                    .instance()
-                    .set(&DataKey::ReentrancyGuard, &false);
-                ContractError::TokenNotFound
-            })?;
+            .get(&DataKey::OperatorApproval(owner, operator))
+            .unwrap_or(false)
+    }
 
-        // Verify ownership
-        if nft.owner != from {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::NotOwner);
+    /// Transfer an NFT on behalf of its owner.
+    ///
+    /// `spender` may be the owner itself, the address approved for `token_id`
+    /// or an operator approved for all of `from`'s tokens. The same lock rules
+    /// as `transfer` apply, and the per-token approval is cleared on success.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotAuthorized`] if `spender` is not the owner or approved.
+    /// - Otherwise the same errors as `transfer`.
+    ///
+    /// # Security
+    /// - Requires `spender.require_auth()`.
+    pub fn transfer_from(
+        e: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u32,
+    ) -> Result<(), ContractError> {
+        transfer_token(&e, &spender, &from, &to, token_id)
+    }
+
+    /// Transfer every unlocked NFT owned by `from` to `to` in one call.
+    ///
+    /// Active (locked) NFTs are skipped and their ids returned. At most
+    /// `MAX_TRANSFER_ALL_BATCH` NFTs are moved per call, and never more than `to`
+    /// can hold under `MAX_TOKENS_PER_OWNER`; any unlocked NFTs left over stay with
+    /// `from` for a follow-up call.
+    ///
+    /// # Errors
+    /// - [`ContractError::TransferToZeroAddress`] if `to` is zero or equal to `from`.
+    /// - [`ContractError::TooManyTokens`] if `to` already holds `MAX_TOKENS_PER_OWNER` NFTs.
+    /// - [`ContractError::ReentrancyDetected`] if the guard is already set.
+    ///
+    /// # Security
+    /// - Requires `from.require_auth()`; applies the same lock rules as `transfer`.
+    pub fn transfer_all(e: Env, from: Address, to: Address) -> Result<Vec<u32>, ContractError> {
+        EmergencyControl::require_not_emergency(&e);
+        Pausable::require_not_paused(&e);
+        from.require_auth();
+
+        if transfers_paused(&e) {
+            return Err(ContractError::TransfersPaused);
+        }
+        if to == from || is_zero_address(&e, &to) {
+            return Err(ContractError::TransferToZeroAddress);
         }
 
-        // Active (locked) commitment NFTs cannot be transferred (#145)
-        if nft.is_active {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::NFTLocked);
+        let to_balance = Self::balance_of(e.clone(), to.clone());
+        if to_balance >= MAX_TOKENS_PER_OWNER {
+            return Err(ContractError::TooManyTokens);
         }
+        let limit = MAX_TRANSFER_ALL_BATCH.min(MAX_TOKENS_PER_OWNER - to_balance);
 
-        // EFFECTS: Update state
-        // Update owner
-        nft.owner = to.clone();
-        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        check_and_set_guard(&e)?;
+
+        let from_tokens: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::OwnerTokens(from.clone()))
+            .unwrap_or(Vec::new(&e));
+        let mut kept = Vec::new(&e);
+        let mut moved = Vec::new(&e);
+        let mut skipped = Vec::new(&e);
+
+        for token_id in from_tokens.iter() {
+            let nft: Option<CommitmentNFT> = e.storage().persistent().get(&DataKey::NFT(token_id));
+            match nft {
+                Some(mut nft) if !nft.is_active && moved.len() < limit => {
+                    nft.owner = to.clone();
+                    e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+                    e.storage()
+                        .persistent()
+                        .remove(&DataKey::Approved(token_id));
+                    moved.push_back(token_id);
+                }
+                Some(nft) => {
+                    if nft.is_active {
+                        skipped.push_back(token_id);
+                    }
+                    kept.push_back(token_id);
+                }
+                None => kept.push_back(token_id),
+            }
+        }
 
-        // OPTIMIZATION: Batch read balances before updating
-        let (from_balance, to_balance) = {
-            let from_bal = e
+        if !moved.is_empty() {
+            let from_balance: u32 = e
                 .storage()
                 .persistent()
                 .get(&DataKey::OwnerBalance(from.clone()))
-                .unwrap_or(0u32);
-            let to_bal = e
+                .unwrap_or(0);
+            e.storage().persistent().set(
+                &DataKey::OwnerBalance(from.clone()),
+                &from_balance.saturating_sub(moved.len()),
+            );
+            e.storage().persistent().set(
+                &DataKey::OwnerBalance(to.clone()),
+                &(to_balance + moved.len()),
+            );
+
+            let mut to_tokens: Vec<u32> = e
                 .storage()
                 .persistent()
-                .get(&DataKey::OwnerBalance(to.clone()))
-                .unwrap_or(0u32);
-            (from_bal, to_bal)
-        };
-
-        // Update balance counts
-        if from_balance > 0 {
+                .get(&DataKey::OwnerTokens(to.clone()))
+                .unwrap_or(Vec::new(&e));
+            to_tokens.append(&moved);
             e.storage()
                 .persistent()
-                .set(&DataKey::OwnerBalance(from.clone()), &(from_balance - 1));
-        }
-        e.storage()
-            .persistent()
-            .set(&DataKey::OwnerBalance(to.clone()), &(to_balance + 1));
-
-        // Update owner tokens lists
-        let mut from_tokens: Vec<u32> = e
-            .storage()
-            .persistent()
-            .get(&DataKey::OwnerTokens(from.clone()))
-            .unwrap_or(Vec::new(&e));
-        if let Some(index) = from_tokens.iter().position(|id| id == token_id) {
-            from_tokens.remove(index as u32);
+                .set(&DataKey::OwnerTokens(to.clone()), &to_tokens);
+            e.storage()
+                .persistent()
+                .set(&DataKey::OwnerTokens(from.clone()), &kept);
+            for token_id in moved.iter() {
+                extend_nft_ttl(&e, token_id, &to);
+            }
         }
-        e.storage()
-            .persistent()
-            .set(&DataKey::OwnerTokens(from.clone()), &from_tokens);
 
-        let mut to_tokens: Vec<u32> = e
-            .storage()
-            .persistent()
-            .get(&DataKey::OwnerTokens(to.clone()))
-            .unwrap_or(Vec::new(&e));
-        to_tokens.push_back(token_id);
-        e.storage()
-            .persistent()
-            .set(&DataKey::OwnerTokens(to.clone()), &to_tokens);
-
-        // Clear reentrancy guard
-        e.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &false);
+        clear_guard(&e);
 
-        // Emit transfer event
-        e.events().publish(
-            (symbol_short!("Transfer"), from, to),
-            (token_id, e.ledger().timestamp()),
-        );
+        for token_id in moved.iter() {
+            e.events().publish(
+                (symbol_short!("Transfer"), from.clone(), to.clone()),
+                (token_id, e.ledger().timestamp()),
+            );
+        }
 
-        Ok(())
+        Ok(skipped)
     }
 
     /// Check if NFT is active
@@ -1061,12 +1473,36 @@ impl CommitmentNFTContract {
         Ok(nft.is_active)
     }
 
-    /// Get total supply of NFTs minted
+    /// Get total supply of NFTs (minted minus burned)
     pub fn total_supply(e: Env) -> u32 {
-        e.storage()
+        let minted: u32 = e
+            .storage()
             .instance()
             .get(&DataKey::TokenCounter)
-            .unwrap_or(0)
+            .unwrap_or(0);
+        let burned: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::BurnedCount)
+            .unwrap_or(0);
+        minted.saturating_sub(burned)
+    }
+
+    /// Get the token id at `index` across all live tokens.
+    ///
+    /// Indices run from `0` to `total_supply() - 1` in mint order. Burning a
+    /// token shifts every later token down by one, so indices stay contiguous
+    /// but are not stable across burns.
+    ///
+    /// # Errors
+    /// - [`ContractError::TokenNotFound`] if `index >= total_supply()`.
+    pub fn token_by_index(e: Env, index: u32) -> Result<u32, ContractError> {
+        let token_ids: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::TokenIds)
+            .unwrap_or(Vec::new(&e));
+        token_ids.get(index).ok_or(ContractError::TokenNotFound)
     }
 
     /// Get NFT count for a specific owner
@@ -1126,6 +1562,31 @@ impl CommitmentNFTContract {
         owned_nfts
     }
 
+    /// Get a page of the token ids held by `owner`.
+    ///
+    /// Returns up to `limit` ids (capped at [`MAX_PAGE_SIZE`]) starting at
+    /// `offset`, in the order the owner received them. Empty when `offset` is
+    /// past the end; use `balance_of` to size the walk.
+    pub fn get_tokens_by_owner_paginated(
+        e: Env,
+        owner: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u32> {
+        let token_ids: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::OwnerTokens(owner))
+            .unwrap_or(Vec::new(&e));
+
+        let len = token_ids.len();
+        if offset >= len {
+            return Vec::new(&e);
+        }
+        let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len);
+        token_ids.slice(offset..end)
+    }
+
     // ========================================================================
     // Settlement (Issue #5 - Main Implementation)
     // ========================================================================
@@ -1148,21 +1609,11 @@ impl CommitmentNFTContract {
     /// - Restricted to the configured `commitment_core` contract because this mutates lifecycle state.
     /// - Uses checks-effects-interactions and does not perform outbound calls.
     pub fn mark_inactive(e: Env, caller: Address, token_id: u32) -> Result<(), ContractError> {
-        // Reentrancy protection
-        let guard: bool = e
-            .storage()
-            .instance()
-            .get(&DataKey::ReentrancyGuard)
-            .unwrap_or(false);
-
-        if guard {
-            return Err(ContractError::ReentrancyDetected);
-        }
-        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+        check_and_set_guard(&e)?;
         EmergencyControl::require_not_emergency(&e);
 
         if let Err(err) = require_core_contract_caller(&e, &caller) {
-            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            clear_guard(&e);
             return Err(err);
         }
 
@@ -1175,17 +1626,13 @@ impl CommitmentNFTContract {
             .persistent()
             .get(&DataKey::NFT(token_id))
             .ok_or_else(|| {
-                e.storage()
-                    .instance()
-                    .set(&DataKey::ReentrancyGuard, &false);
+                clear_guard(&e);
                 ContractError::TokenNotFound
             })?;
 
         // Check if already inactive
         if !nft.is_active {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
+            clear_guard(&e);
             return Err(ContractError::AlreadySettled);
         }
 
@@ -1193,11 +1640,10 @@ impl CommitmentNFTContract {
         // Mark as inactive
         nft.is_active = false;
         e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        extend_nft_ttl(&e, token_id, &nft.owner);
 
         // Clear reentrancy guard
-        e.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &false);
+        clear_guard(&e);
 
         // Emit event
         e.events().publish(
@@ -1208,6 +1654,155 @@ impl CommitmentNFTContract {
         Ok(())
     }
 
+    /// Sync an active NFT's early-exit penalty after `commitment_core::set_early_exit_penalty`.
+    ///
+    /// Updates both `early_exit_penalty` and `metadata.early_exit_penalty`. Core owns
+    /// the penalty and enforces the per-type minimums before calling this.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotInitialized`] if no core contract has been configured.
+    /// - [`ContractError::NotAuthorized`] if `caller` is not the configured core contract.
+    /// - [`ContractError::InvalidPenalty`] if `new_penalty > 100`.
+    /// - [`ContractError::TokenNotFound`] if the NFT does not exist.
+    /// - [`ContractError::AlreadySettled`] if the NFT is no longer active.
+    ///
+    /// # Security
+    /// - Requires `caller.require_auth()`; restricted to the core contract.
+    pub fn set_early_exit_penalty(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+        new_penalty: u32,
+    ) -> Result<(), ContractError> {
+        require_core_contract_caller(&e, &caller)?;
+        if new_penalty > 100 {
+            return Err(ContractError::InvalidPenalty);
+        }
+
+        let mut nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if !nft.is_active {
+            return Err(ContractError::AlreadySettled);
+        }
+
+        let old_penalty = nft.early_exit_penalty;
+        nft.early_exit_penalty = new_penalty;
+        nft.metadata.early_exit_penalty = new_penalty;
+        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        extend_nft_ttl(&e, token_id, &nft.owner);
+
+        e.events().publish(
+            (Symbol::new(&e, "EarlyExitPenaltySet"), token_id),
+            (old_penalty, new_penalty, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the early-exit penalty (percent) of an NFT.
+    pub fn get_early_exit_penalty(e: Env, token_id: u32) -> Result<u32, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        Ok(nft.early_exit_penalty)
+    }
+
+    /// Sync an active NFT's lock period after `commitment_core::extend_duration`.
+    ///
+    /// Core passes the new absolute values so both contracts agree on the
+    /// maturity timestamp.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotInitialized`] if no core contract has been configured.
+    /// - [`ContractError::NotAuthorized`] if `caller` is not the configured core contract.
+    /// - [`ContractError::TokenNotFound`] if the NFT does not exist.
+    /// - [`ContractError::AlreadySettled`] if the NFT is no longer active.
+    /// - [`ContractError::InvalidDuration`] if `expires_at` would shorten the lock.
+    ///
+    /// # Security
+    /// - Requires `caller.require_auth()`; restricted to the core contract.
+    pub fn extend_expiration(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+        duration_days: u32,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        require_core_contract_caller(&e, &caller)?;
+
+        let mut nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if !nft.is_active {
+            return Err(ContractError::AlreadySettled);
+        }
+        if expires_at <= nft.metadata.expires_at {
+            return Err(ContractError::InvalidDuration);
+        }
+
+        let old_expires_at = nft.metadata.expires_at;
+        nft.metadata.duration_days = duration_days;
+        nft.metadata.expires_at = expires_at;
+        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        extend_nft_ttl(&e, token_id, &nft.owner);
+
+        e.events().publish(
+            (Symbol::new(&e, "ExpirationExtended"), token_id),
+            (old_expires_at, expires_at, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Sync an active NFT's `initial_amount` after core changes the commitment's
+    /// principal (`commitment_core::partial_withdraw` or `top_up`).
+    ///
+    /// # Errors
+    /// - [`ContractError::NotInitialized`] if no core contract has been configured.
+    /// - [`ContractError::NotAuthorized`] if `caller` is not the configured core contract.
+    /// - [`ContractError::InvalidAmount`] if `initial_amount` is negative.
+    /// - [`ContractError::TokenNotFound`] if the NFT does not exist.
+    /// - [`ContractError::AlreadySettled`] if the NFT is no longer active.
+    ///
+    /// # Security
+    /// - Requires `caller.require_auth()`; restricted to the core contract.
+    pub fn update_initial_amount(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+        initial_amount: i128,
+    ) -> Result<(), ContractError> {
+        require_core_contract_caller(&e, &caller)?;
+        if initial_amount < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if !nft.is_active {
+            return Err(ContractError::AlreadySettled);
+        }
+
+        let old_amount = nft.metadata.initial_amount;
+        nft.metadata.initial_amount = initial_amount;
+        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        extend_nft_ttl(&e, token_id, &nft.owner);
+
+        e.events().publish(
+            (Symbol::new(&e, "InitialAmountUpdated"), token_id),
+            (old_amount, initial_amount, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
     /// Mark NFT as settled after maturity.
     ///
     /// # Params
@@ -1224,24 +1819,15 @@ impl CommitmentNFTContract {
     ///
     /// # Security
     /// - Requires `caller.require_auth()` so only the authorized core contract can settle.
+    ///   The NFT owner's signature is neither required nor sufficient.
     /// - Restricted to the configured `commitment_core` contract to keep core and NFT state aligned.
     /// - Uses checks-effects-interactions and does not perform outbound calls.
     pub fn settle(e: Env, caller: Address, token_id: u32) -> Result<(), ContractError> {
-        // Reentrancy protection
-        let guard: bool = e
-            .storage()
-            .instance()
-            .get(&DataKey::ReentrancyGuard)
-            .unwrap_or(false);
-
-        if guard {
-            return Err(ContractError::ReentrancyDetected);
-        }
-        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+        check_and_set_guard(&e)?;
         EmergencyControl::require_not_emergency(&e);
 
         if let Err(err) = require_core_contract_caller(&e, &caller) {
-            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            clear_guard(&e);
             return Err(err);
         }
 
@@ -1254,26 +1840,20 @@ impl CommitmentNFTContract {
             .persistent()
             .get(&DataKey::NFT(token_id))
             .ok_or_else(|| {
-                e.storage()
-                    .instance()
-                    .set(&DataKey::ReentrancyGuard, &false);
+                clear_guard(&e);
                 ContractError::TokenNotFound
             })?;
 
         // Check if already settled
         if !nft.is_active {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
+            clear_guard(&e);
             return Err(ContractError::AlreadySettled);
         }
 
         // Verify expiration
         let current_time = e.ledger().timestamp();
         if current_time < nft.metadata.expires_at {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
+            clear_guard(&e);
             return Err(ContractError::NotExpired);
         }
 
@@ -1281,15 +1861,87 @@ impl CommitmentNFTContract {
         // Mark as inactive (settled)
         nft.is_active = false;
         e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        extend_nft_ttl(&e, token_id, &nft.owner);
 
         // Clear reentrancy guard
+        clear_guard(&e);
+
+        // Emit settle event
+        e.events().publish(
+            (symbol_short!("Settle"), token_id),
+            (token_id, e.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Destroy a settled (inactive) NFT.
+    ///
+    /// # Errors
+    /// - [`ContractError::TokenNotFound`] if the NFT does not exist.
+    /// - [`ContractError::NotOwner`] if `caller` does not own the NFT.
+    /// - [`ContractError::NFTLocked`] if the NFT is still active.
+    /// - [`ContractError::ReentrancyDetected`] if the guard is already set.
+    ///
+    /// # Security
+    /// - Requires `caller.require_auth()`; only the owner can burn.
+    /// - Token ids are never reused: `TokenCounter` is left untouched and the
+    ///   burn is tracked in `BurnedCount` so `total_supply` drops by one.
+    pub fn burn(e: Env, caller: Address, token_id: u32) -> Result<(), ContractError> {
+        check_and_set_guard(&e)?;
+        caller.require_auth();
+        Pausable::require_not_paused(&e);
+
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or_else(|| {
+                clear_guard(&e);
+                ContractError::TokenNotFound
+            })?;
+        if nft.owner != caller {
+            clear_guard(&e);
+            return Err(ContractError::NotOwner);
+        }
+        if nft.is_active {
+            clear_guard(&e);
+            return Err(ContractError::NFTLocked);
+        }
+
+        e.storage().persistent().remove(&DataKey::NFT(token_id));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::CommitmentIdIndex(nft.metadata.commitment_id));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::TokenURI(token_id));
+        remove_token_from_owner(&e, &caller, token_id);
+
+        let mut token_ids: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::TokenIds)
+            .unwrap_or(Vec::new(&e));
+        if let Some(index) = token_ids.iter().position(|id| id == token_id) {
+            token_ids.remove(index as u32);
+        }
+        e.storage().persistent().set(&DataKey::TokenIds, &token_ids);
+
+        let burned: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::BurnedCount)
+            .unwrap_or(0);
         e.storage()
             .instance()
-            .set(&DataKey::ReentrancyGuard, &false);
+            .set(&DataKey::BurnedCount, &(burned + 1));
+        clear_guard(&e);
 
-        // Emit settle event
-        e.events()
-            .publish((symbol_short!("Settle"), token_id), e.ledger().timestamp());
+        e.events().publish(
+            (symbol_short!("Burn"), token_id, caller),
+            e.ledger().timestamp(),
+        );
 
         Ok(())
     }
@@ -1336,6 +1988,22 @@ fn read_version(e: &Env) -> u32 {
         .unwrap_or(0)
 }
 
+/// Keep a token and its owner's index from being archived while the token is in use.
+fn extend_nft_ttl(e: &Env, token_id: u32, owner: &Address) {
+    Storage::extend_instance_ttl(e);
+    Storage::extend_persistent_ttl(e, &DataKey::NFT(token_id));
+    for key in [
+        DataKey::TokenURI(token_id),
+        DataKey::OwnerTokens(owner.clone()),
+        DataKey::OwnerBalance(owner.clone()),
+        DataKey::TokenIds,
+    ] {
+        if e.storage().persistent().has(&key) {
+            Storage::extend_persistent_ttl(e, &key);
+        }
+    }
+}
+
 fn require_admin(e: &Env, caller: &Address) -> Result<(), ContractError> {
     caller.require_auth();
     let admin: Address = e
@@ -1362,6 +2030,220 @@ fn require_core_contract_caller(e: &Env, caller: &Address) -> Result<(), Contrac
     Ok(())
 }
 
+/// Private metadata is readable by the token owner, the admin and the core contract.
+/// Auth is checked by the caller.
+fn require_private_reader(
+    e: &Env,
+    caller: &Address,
+    nft: &CommitmentNFT,
+) -> Result<(), ContractError> {
+    if *caller == nft.owner {
+        return Ok(());
+    }
+    let admin: Address = e
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)?;
+    let core_contract: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+    if *caller == admin || core_contract.as_ref() == Some(caller) {
+        return Ok(());
+    }
+    Err(ContractError::NotAuthorized)
+}
+
+/// Take the reentrancy guard, rejecting a nested call while it is held.
+fn check_and_set_guard(e: &Env) -> Result<(), ContractError> {
+    let guard: bool = e
+        .storage()
+        .instance()
+        .get(&DataKey::ReentrancyGuard)
+        .unwrap_or(false);
+    if guard {
+        return Err(ContractError::ReentrancyDetected);
+    }
+    e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+    Ok(())
+}
+
+/// Release the reentrancy guard; must run before every non-trapping return.
+fn clear_guard(e: &Env) {
+    e.storage()
+        .instance()
+        .set(&DataKey::ReentrancyGuard, &false);
+}
+
+/// Check that `caller` is the admin, the core contract or an authorized minter.
+fn require_minter(e: &Env, caller: &Address) -> Result<(), ContractError> {
+    let admin: Address = e
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)?;
+    let core_contract: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+    let is_authorized_minter = e
+        .storage()
+        .instance()
+        .get(&DataKey::AuthorizedMinter(caller.clone()))
+        .unwrap_or(false);
+    if *caller == admin || core_contract.as_ref() == Some(caller) || is_authorized_minter {
+        Ok(())
+    } else {
+        Err(ContractError::NotAuthorized)
+    }
+}
+
+/// Whether `pause_transfers` is in effect.
+fn transfers_paused(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::TransfersPaused)
+        .unwrap_or(false)
+}
+
+/// Shared body of `transfer` and `transfer_from`; `spender` must be `from` or approved.
+fn transfer_token(
+    e: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    token_id: u32,
+) -> Result<(), ContractError> {
+    check_and_set_guard(e)?;
+
+    // A trap reverts the guard along with everything else; returned errors below
+    // clear it explicitly.
+    EmergencyControl::require_not_emergency(e);
+    Pausable::require_not_paused(e);
+    spender.require_auth();
+
+    if transfers_paused(e) {
+        clear_guard(e);
+        return Err(ContractError::TransfersPaused);
+    }
+
+    // Validate 'to' address is not the same as 'from' (prevent self-transfer)
+    if to == from {
+        clear_guard(e);
+        return Err(ContractError::TransferToZeroAddress);
+    }
+
+    // CHECKS: Reject transfer to zero address
+    if is_zero_address(e, to) {
+        clear_guard(e);
+        return Err(ContractError::TransferToZeroAddress);
+    }
+
+    // Get the NFT
+    let mut nft: CommitmentNFT = e
+        .storage()
+        .persistent()
+        .get(&DataKey::NFT(token_id))
+        .ok_or_else(|| {
+            clear_guard(e);
+            ContractError::TokenNotFound
+        })?;
+
+    // Verify ownership
+    if nft.owner != *from {
+        clear_guard(e);
+        return Err(ContractError::NotOwner);
+    }
+
+    // Non-owners need a per-token approval or an operator approval
+    if spender != from && !is_approved_spender(e, spender, from, token_id) {
+        clear_guard(e);
+        return Err(ContractError::NotAuthorized);
+    }
+
+    // Active (locked) commitment NFTs cannot be transferred (#145)
+    if nft.is_active {
+        clear_guard(e);
+        return Err(ContractError::NFTLocked);
+    }
+
+    if CommitmentNFTContract::balance_of(e.clone(), to.clone()) >= MAX_TOKENS_PER_OWNER {
+        clear_guard(e);
+        return Err(ContractError::TooManyTokens);
+    }
+
+    // EFFECTS: Update state
+    // Update owner
+    nft.owner = to.clone();
+    e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+
+    // Update balance counts and owner tokens lists
+    remove_token_from_owner(e, from, token_id);
+    let to_balance: u32 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::OwnerBalance(to.clone()))
+        .unwrap_or(0);
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnerBalance(to.clone()), &(to_balance + 1));
+
+    let mut to_tokens: Vec<u32> = e
+        .storage()
+        .persistent()
+        .get(&DataKey::OwnerTokens(to.clone()))
+        .unwrap_or(Vec::new(e));
+    to_tokens.push_back(token_id);
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnerTokens(to.clone()), &to_tokens);
+    extend_nft_ttl(e, token_id, to);
+
+    // Clear reentrancy guard
+    clear_guard(e);
+
+    // Emit transfer event
+    e.events().publish(
+        (symbol_short!("Transfer"), from.clone(), to.clone()),
+        (token_id, e.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+/// Whether `spender` holds the approval for `token_id` or is an operator for `owner`.
+fn is_approved_spender(e: &Env, spender: &Address, owner: &Address, token_id: u32) -> bool {
+    let approved: Option<Address> = e.storage().persistent().get(&DataKey::Approved(token_id));
+    approved.as_ref() == Some(spender)
+        || CommitmentNFTContract::is_approved_for_all(e.clone(), owner.clone(), spender.clone())
+}
+
+/// Drop `token_id` from `owner`'s token list, decrement their balance and clear
+/// any per-token approval.
+fn remove_token_from_owner(e: &Env, owner: &Address, token_id: u32) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Approved(token_id));
+
+    let balance: u32 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::OwnerBalance(owner.clone()))
+        .unwrap_or(0);
+    if balance > 0 {
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerBalance(owner.clone()), &(balance - 1));
+    }
+
+    let mut tokens: Vec<u32> = e
+        .storage()
+        .persistent()
+        .get(&DataKey::OwnerTokens(owner.clone()))
+        .unwrap_or(Vec::new(e));
+    if let Some(index) = tokens.iter().position(|id| id == token_id) {
+        tokens.remove(index as u32);
+    }
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnerTokens(owner.clone()), &tokens);
+}
+
 fn require_valid_wasm_hash(e: &Env, wasm_hash: &BytesN<32>) -> Result<(), ContractError> {
     let zero = BytesN::from_array(e, &[0; 32]);
     if *wasm_hash == zero {
@@ -2,6 +2,7 @@
 
 use super::*;
 use soroban_sdk::{
+    contract, contractimpl,
     testutils::{Address as _, Ledger},
     Address, Env, String,
 };
@@ -58,3 +59,926 @@ fn test_mint_and_settle_as_core_updates_supply_and_activity() {
     assert!(!client.is_active(&token_id));
     assert_eq!(client.total_supply(), 1);
 }
+
+fn mint_for(
+    e: &Env,
+    client: &CommitmentNFTContractClient<'_>,
+    admin: &Address,
+    owner: &Address,
+) -> u32 {
+    client.mint(
+        admin,
+        owner,
+        &String::from_str(e, "commitment_transfer"),
+        &1,
+        &10,
+        &String::from_str(e, "safe"),
+        &1_000,
+        &Address::generate(e),
+        &5,
+    )
+}
+
+fn mint_params(e: &Env, owner: &Address, max_loss_percent: u32) -> MintParams {
+    MintParams {
+        owner: owner.clone(),
+        duration_days: 1,
+        max_loss_percent,
+        commitment_type: String::from_str(e, "safe"),
+        initial_amount: 1_000,
+        asset_address: Address::generate(e),
+        early_exit_penalty: 5,
+    }
+}
+
+#[test]
+fn test_batch_mint_mints_all_or_nothing() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    let ids = client.batch_mint(
+        &admin,
+        &soroban_sdk::vec![
+            &e,
+            mint_params(&e, &alice, 10),
+            mint_params(&e, &bob, 10),
+            mint_params(&e, &alice, 20),
+        ],
+    );
+    assert_eq!(ids.len(), 3);
+    assert_eq!(client.owner_of(&ids.get(1).unwrap()), bob);
+    assert_eq!(client.balance_of(&alice), 2);
+    assert_eq!(client.total_supply(), 3);
+
+    // One bad entry reverts the entries before it.
+    assert_eq!(
+        client.try_batch_mint(
+            &admin,
+            &soroban_sdk::vec![&e, mint_params(&e, &bob, 10), mint_params(&e, &bob, 101)],
+        ),
+        Err(Ok(ContractError::InvalidMaxLoss))
+    );
+    assert_eq!(client.total_supply(), 3);
+    assert_eq!(client.balance_of(&bob), 1);
+
+    assert_eq!(
+        client.try_batch_mint(&admin, &Vec::new(&e)),
+        Err(Ok(ContractError::InvalidBatchSize))
+    );
+    assert_eq!(
+        client.try_batch_mint(
+            &Address::generate(&e),
+            &soroban_sdk::vec![&e, mint_params(&e, &bob, 10)],
+        ),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+}
+
+#[test]
+fn test_batch_mint_honours_configured_max_batch_size() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let alice = Address::generate(&e);
+    assert_eq!(client.get_max_batch_size(), MAX_BATCH_MINT);
+
+    assert_eq!(
+        client.try_set_max_batch_size(&alice, &2),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_set_max_batch_size(&admin, &0),
+        Err(Ok(ContractError::InvalidBatchSize))
+    );
+    client.set_max_batch_size(&admin, &2);
+    assert_eq!(client.get_max_batch_size(), 2);
+
+    let two = soroban_sdk::vec![&e, mint_params(&e, &alice, 10), mint_params(&e, &alice, 10)];
+    assert_eq!(client.batch_mint(&admin, &two).len(), 2);
+    let mut three = two.clone();
+    three.push_back(mint_params(&e, &alice, 10));
+    assert_eq!(
+        client.try_batch_mint(&admin, &three),
+        Err(Ok(ContractError::BatchTooLarge))
+    );
+    assert_eq!(client.total_supply(), 2);
+
+    // Raising the limit above the default is allowed too.
+    client.set_max_batch_size(&admin, &(MAX_BATCH_MINT + 1));
+    assert_eq!(client.batch_mint(&admin, &three).len(), 3);
+}
+
+#[test]
+fn test_set_early_exit_penalty_updates_active_nft() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    assert_eq!(client.get_early_exit_penalty(&token_id), 5);
+
+    client.set_early_exit_penalty(&core_contract, &token_id, &20);
+    client.set_early_exit_penalty(&core_contract, &token_id, &15);
+    assert_eq!(client.get_early_exit_penalty(&token_id), 15);
+    assert_eq!(
+        client
+            .get_private_metadata(&owner, &token_id)
+            .metadata
+            .early_exit_penalty,
+        15
+    );
+
+    // Core owns the penalty; the NFT admin can no longer change it directly.
+    assert_eq!(
+        client.try_set_early_exit_penalty(&admin, &token_id, &0),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_set_early_exit_penalty(&owner, &token_id, &0),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_set_early_exit_penalty(&core_contract, &token_id, &101),
+        Err(Ok(ContractError::InvalidPenalty))
+    );
+
+    settle_token(&e, &client, &core_contract, token_id);
+    assert_eq!(
+        client.try_set_early_exit_penalty(&core_contract, &token_id, &0),
+        Err(Ok(ContractError::AlreadySettled))
+    );
+}
+
+#[test]
+fn test_extend_expiration_is_core_only_and_forward_only() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    let metadata = client.get_private_metadata(&owner, &token_id).metadata;
+    let new_expires_at = metadata.expires_at + 86400;
+
+    assert_eq!(
+        client.try_extend_expiration(&admin, &token_id, &2, &new_expires_at),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_extend_expiration(&core_contract, &token_id, &2, &metadata.expires_at),
+        Err(Ok(ContractError::InvalidDuration))
+    );
+
+    client.extend_expiration(&core_contract, &token_id, &2, &new_expires_at);
+    let metadata = client.get_private_metadata(&owner, &token_id).metadata;
+    assert_eq!(metadata.expires_at, new_expires_at);
+    assert_eq!(metadata.duration_days, 2);
+}
+
+#[test]
+fn test_authorized_contract_list_tracks_adds_and_removes() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+    let third = Address::generate(&e);
+
+    client.add_authorized_contract(&admin, &first);
+    client.add_authorized_contract(&admin, &second);
+    client.add_authorized_contract(&admin, &third);
+    client.add_authorized_contract(&admin, &first);
+    client.remove_authorized_contract(&admin, &second);
+
+    let listed = client.get_authorized_contracts();
+    assert_eq!(listed, soroban_sdk::vec![&e, first.clone(), third.clone()]);
+    for address in listed.iter() {
+        assert!(client.is_authorized(&address));
+    }
+    assert!(!client.is_authorized(&second));
+}
+
+#[test]
+fn test_token_uri_is_set_by_owner_or_admin() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    let uri = String::from_str(&e, "ipfs://bafy/commitment.json");
+
+    assert_eq!(
+        client.try_token_uri(&token_id),
+        Err(Ok(ContractError::TokenUriNotSet))
+    );
+    assert_eq!(
+        client.try_token_uri(&(token_id + 1)),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_set_token_uri(&Address::generate(&e), &token_id, &uri),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_set_token_uri(&owner, &token_id, &String::from_str(&e, "")),
+        Err(Ok(ContractError::InvalidTokenUri))
+    );
+
+    client.set_token_uri(&owner, &token_id, &uri);
+    assert_eq!(client.token_uri(&token_id), uri);
+
+    let replacement = String::from_str(&e, "https://commitlabs.example/nft/1.json");
+    client.set_token_uri(&admin, &token_id, &replacement);
+    assert_eq!(client.token_uri(&token_id), replacement);
+}
+
+#[test]
+fn test_mint_extends_token_ttl() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+
+    e.as_contract(&client.address, || {
+        let expected = shared_utils::storage::TTL_EXTEND_TO_LEDGERS.min(e.storage().max_ttl());
+        assert_eq!(
+            e.storage().persistent().get_ttl(&DataKey::NFT(token_id)),
+            expected
+        );
+        assert_eq!(
+            e.storage()
+                .persistent()
+                .get_ttl(&DataKey::OwnerTokens(owner.clone())),
+            expected
+        );
+    });
+}
+
+#[test]
+fn test_upgrade_is_admin_only_and_emits_event() {
+    use soroban_sdk::{testutils::Events as _, Bytes, BytesN, IntoVal};
+
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let wasm_hash = e.deployer().upload_contract_wasm(Bytes::new(&e));
+
+    let attacker = Address::generate(&e);
+    assert_eq!(
+        client.try_upgrade(&attacker, &wasm_hash),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_upgrade(&admin, &BytesN::from_array(&e, &[0; 32])),
+        Err(Ok(ContractError::InvalidWasmHash))
+    );
+
+    client.upgrade(&admin, &wasm_hash);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("upgraded"), admin.clone()).into_val(&e)
+    );
+    let (hash, _): (BytesN<32>, u64) = event.2.into_val(&e);
+    assert_eq!(hash, wasm_hash);
+}
+
+#[test]
+fn test_transfer_error_paths_do_not_strand_guard() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let contract_id = client.address.clone();
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    client.set_core_contract(&core_contract);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+
+    // Call the contract directly so storage writes from failing calls are kept,
+    // which is what a stale guard would need to wedge the token.
+    let transfer = |from: &Address, to: &Address, id: u32| {
+        e.as_contract(&contract_id, || {
+            CommitmentNFTContract::transfer(e.clone(), from.clone(), to.clone(), id)
+        })
+    };
+
+    for _ in 0..2 {
+        assert_eq!(
+            transfer(&owner, &recipient, token_id),
+            Err(ContractError::NFTLocked)
+        );
+        assert_eq!(
+            transfer(&owner, &owner, token_id),
+            Err(ContractError::TransferToZeroAddress)
+        );
+        assert_eq!(
+            transfer(&owner, &recipient, 999),
+            Err(ContractError::TokenNotFound)
+        );
+        assert_eq!(
+            transfer(&stranger, &recipient, token_id),
+            Err(ContractError::NotOwner)
+        );
+    }
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = 86_400;
+    });
+    client.settle(&core_contract, &token_id);
+
+    assert_eq!(transfer(&owner, &recipient, token_id), Ok(()));
+    assert_eq!(client.owner_of(&token_id), recipient);
+    assert_eq!(
+        transfer(&owner, &stranger, token_id),
+        Err(ContractError::NotOwner)
+    );
+    assert_eq!(transfer(&recipient, &owner, token_id), Ok(()));
+    assert_eq!(client.owner_of(&token_id), owner);
+}
+
+fn seed_balance(e: &Env, contract_id: &Address, owner: &Address, balance: u32) {
+    e.as_contract(contract_id, || {
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerBalance(owner.clone()), &balance);
+    });
+}
+
+#[test]
+fn test_mint_rejects_expiry_overflow() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    // u32::MAX days fits in u64 seconds, but not on top of a near-max timestamp.
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = u64::MAX - 86_400;
+    });
+    let result = client.try_mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "commitment_overflow"),
+        &u32::MAX,
+        &10,
+        &String::from_str(&e, "safe"),
+        &1_000,
+        &Address::generate(&e),
+        &5,
+    );
+    assert_eq!(result, Err(Ok(ContractError::ExpirationOverflow)));
+    assert_eq!(client.total_supply(), 0);
+}
+
+#[test]
+fn test_mint_rejects_owner_at_token_cap() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    seed_balance(&e, &client.address, &owner, MAX_TOKENS_PER_OWNER);
+
+    let result = client.try_mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "commitment_cap"),
+        &1,
+        &10,
+        &String::from_str(&e, "safe"),
+        &1_000,
+        &Address::generate(&e),
+        &5,
+    );
+    assert_eq!(result, Err(Ok(ContractError::TooManyTokens)));
+    assert_eq!(client.total_supply(), 0);
+
+    // One below the cap still mints.
+    seed_balance(&e, &client.address, &owner, MAX_TOKENS_PER_OWNER - 1);
+    mint_for(&e, &client, &admin, &owner);
+    assert_eq!(client.balance_of(&owner), MAX_TOKENS_PER_OWNER);
+}
+
+#[test]
+fn test_transfer_rejects_recipient_at_token_cap() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    client.set_core_contract(&core_contract);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = 86_400;
+    });
+    client.settle(&core_contract, &token_id);
+
+    seed_balance(&e, &client.address, &recipient, MAX_TOKENS_PER_OWNER);
+    assert_eq!(
+        client.try_transfer(&owner, &recipient, &token_id),
+        Err(Ok(ContractError::TooManyTokens))
+    );
+    assert_eq!(client.owner_of(&token_id), owner);
+
+    seed_balance(&e, &client.address, &recipient, 0);
+    client.transfer(&owner, &recipient, &token_id);
+    assert_eq!(client.owner_of(&token_id), recipient);
+}
+
+#[test]
+fn test_get_metadata_omits_sensitive_fields() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+
+    let public = client.get_metadata(&token_id);
+    let full = client.get_private_metadata(&owner, &token_id);
+    assert_eq!(public.owner, owner);
+    assert_eq!(public.token_id, token_id);
+    assert_eq!(public.commitment_id, full.metadata.commitment_id);
+    assert_eq!(public.duration_days, 1);
+    assert_eq!(public.commitment_type, String::from_str(&e, "safe"));
+    assert_eq!(public.expires_at, full.metadata.expires_at);
+    assert!(public.is_active);
+}
+
+#[test]
+fn test_get_private_metadata_allows_owner_and_admin() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+
+    let nft = client.get_private_metadata(&owner, &token_id);
+    assert_eq!(nft.metadata.initial_amount, 1_000);
+    assert_eq!(nft.metadata.max_loss_percent, 10);
+    assert_eq!(e.auths()[0].0, owner);
+
+    assert_eq!(client.get_private_metadata(&admin, &token_id), nft);
+}
+
+#[test]
+fn test_get_private_metadata_rejects_stranger() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+
+    assert_eq!(
+        client.try_get_private_metadata(&stranger, &token_id),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_get_private_metadata(&owner, &999),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_get_asset_address_allows_core_contract() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let core_contract = Address::generate(&e);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+
+    assert_eq!(
+        client.try_get_asset_address(&core_contract, &token_id),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    client.set_core_contract(&core_contract);
+    let asset = client
+        .get_private_metadata(&owner, &token_id)
+        .metadata
+        .asset_address;
+    assert_eq!(client.get_asset_address(&core_contract, &token_id), asset);
+}
+
+#[test]
+fn test_settle_requires_core_contract_auth() {
+    use soroban_sdk::{
+        testutils::{MockAuth, MockAuthInvoke},
+        IntoVal,
+    };
+
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = 86_400;
+    });
+
+    let core_invoke = MockAuthInvoke {
+        contract: &client.address,
+        fn_name: "settle",
+        args: (core_contract.clone(), token_id).into_val(&e),
+        sub_invokes: &[],
+    };
+
+    // The owner signing is not enough: the core contract itself must authorize.
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &core_invoke,
+        }])
+        .try_settle(&core_contract, &token_id);
+    assert!(result.is_err());
+    assert!(client.is_active(&token_id));
+
+    // A non-core caller is rejected even with its own valid auth.
+    let stranger_invoke = MockAuthInvoke {
+        contract: &client.address,
+        fn_name: "settle",
+        args: (stranger.clone(), token_id).into_val(&e),
+        sub_invokes: &[],
+    };
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &stranger_invoke,
+        }])
+        .try_settle(&stranger, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &core_contract,
+            invoke: &core_invoke,
+        }])
+        .settle(&core_contract, &token_id);
+    assert!(!client.is_active(&token_id));
+}
+
+fn settle_token(e: &Env, client: &CommitmentNFTContractClient<'_>, core: &Address, id: u32) {
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = 86_400;
+    });
+    client.settle(core, &id);
+}
+
+#[test]
+fn test_transfer_all_moves_unlocked_and_reports_locked() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let new_wallet = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let first = mint_for(&e, &client, &admin, &owner);
+    let locked = mint_for(&e, &client, &admin, &owner);
+    let third = mint_for(&e, &client, &admin, &owner);
+    settle_token(&e, &client, &core_contract, first);
+    settle_token(&e, &client, &core_contract, third);
+
+    let skipped = client.transfer_all(&owner, &new_wallet);
+    assert_eq!(skipped, soroban_sdk::vec![&e, locked]);
+
+    assert_eq!(client.owner_of(&first), new_wallet);
+    assert_eq!(client.owner_of(&third), new_wallet);
+    assert_eq!(client.owner_of(&locked), owner);
+    assert_eq!(client.balance_of(&owner), 1);
+    assert_eq!(client.balance_of(&new_wallet), 2);
+    assert_eq!(client.get_nfts_by_owner(&owner).len(), 1);
+    assert_eq!(client.get_nfts_by_owner(&new_wallet).len(), 2);
+
+    // Nothing left to move on a second call.
+    assert_eq!(
+        client.transfer_all(&owner, &new_wallet),
+        soroban_sdk::vec![&e, locked]
+    );
+    assert_eq!(client.balance_of(&new_wallet), 2);
+}
+
+#[test]
+fn test_transfer_all_caps_tokens_moved_per_call() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let new_wallet = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let total = MAX_TRANSFER_ALL_BATCH + 2;
+    for _ in 0..total {
+        mint_for(&e, &client, &admin, &owner);
+    }
+    // Token ids are assigned sequentially from zero.
+    for id in 0..total {
+        settle_token(&e, &client, &core_contract, id);
+    }
+
+    assert!(client.transfer_all(&owner, &new_wallet).is_empty());
+    assert_eq!(client.balance_of(&new_wallet), MAX_TRANSFER_ALL_BATCH);
+    assert_eq!(client.balance_of(&owner), 2);
+
+    client.transfer_all(&owner, &new_wallet);
+    assert_eq!(client.balance_of(&new_wallet), total);
+    assert_eq!(client.balance_of(&owner), 0);
+
+    assert_eq!(
+        client.try_transfer_all(&owner, &owner),
+        Err(Ok(ContractError::TransferToZeroAddress))
+    );
+}
+
+#[test]
+fn test_burn_removes_settled_token_and_lowers_supply() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let burned = mint_for(&e, &client, &admin, &owner);
+    let kept = mint_for(&e, &client, &admin, &owner);
+    settle_token(&e, &client, &core_contract, burned);
+
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_burn(&stranger, &burned),
+        Err(Ok(ContractError::NotOwner))
+    );
+
+    client.burn(&owner, &burned);
+    assert!(!client.token_exists(&burned));
+    assert_eq!(client.total_supply(), 1);
+    assert_eq!(client.balance_of(&owner), 1);
+    assert_eq!(client.get_nfts_by_owner(&owner).len(), 1);
+    assert_eq!(client.get_all_metadata().len(), 1);
+
+    // Ids keep increasing after a burn.
+    let next = mint_for(&e, &client, &admin, &owner);
+    assert!(next > kept);
+    assert_eq!(client.total_supply(), 2);
+}
+
+#[test]
+fn test_token_by_index_stays_contiguous_after_burn() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let first = mint_for(&e, &client, &admin, &owner);
+    let second = mint_for(&e, &client, &admin, &owner);
+    let third = mint_for(&e, &client, &admin, &owner);
+    assert_eq!(client.token_by_index(&1), second);
+
+    settle_token(&e, &client, &core_contract, second);
+    client.burn(&owner, &second);
+
+    assert_eq!(client.total_supply(), 2);
+    assert_eq!(client.token_by_index(&0), first);
+    assert_eq!(client.token_by_index(&1), third);
+    assert_eq!(
+        client.try_token_by_index(&2),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_get_tokens_by_owner_paginated_walks_owner_tokens() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    let first = mint_for(&e, &client, &admin, &owner);
+    let second = mint_for(&e, &client, &admin, &owner);
+    let third = mint_for(&e, &client, &admin, &owner);
+    assert_eq!(client.balance_of(&owner), 3);
+
+    assert_eq!(
+        client.get_tokens_by_owner_paginated(&owner, &0, &2),
+        soroban_sdk::vec![&e, first, second]
+    );
+    assert_eq!(
+        client.get_tokens_by_owner_paginated(&owner, &2, &2),
+        soroban_sdk::vec![&e, third]
+    );
+    assert!(client
+        .get_tokens_by_owner_paginated(&owner, &3, &2)
+        .is_empty());
+    assert!(client
+        .get_tokens_by_owner_paginated(&Address::generate(&e), &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_burn_rejects_active_and_missing_tokens() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    let active = mint_for(&e, &client, &admin, &owner);
+    assert_eq!(
+        client.try_burn(&owner, &active),
+        Err(Ok(ContractError::NFTLocked))
+    );
+    assert!(client.token_exists(&active));
+    assert_eq!(
+        client.try_burn(&owner, &99),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_transfer_from_allows_approved_spender_and_clears_approval() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    settle_token(&e, &client, &core_contract, token_id);
+
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_approve(&stranger, &spender, &token_id),
+        Err(Ok(ContractError::NotOwner))
+    );
+    assert_eq!(
+        client.try_transfer_from(&spender, &owner, &recipient, &token_id),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+
+    client.approve(&owner, &spender, &token_id);
+    assert_eq!(client.get_approved(&token_id), Some(spender.clone()));
+
+    client.transfer_from(&spender, &owner, &recipient, &token_id);
+    assert_eq!(client.owner_of(&token_id), recipient);
+    assert_eq!(client.get_approved(&token_id), None);
+    assert_eq!(
+        client.try_transfer_from(&spender, &recipient, &owner, &token_id),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+}
+
+#[test]
+fn test_pause_transfers_freezes_transfers_but_not_mint_or_settle() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    client.pause_transfers();
+    assert!(client.are_transfers_paused());
+
+    // Core keeps operating while transfers are frozen.
+    let second = mint_for(&e, &client, &admin, &owner);
+    settle_token(&e, &client, &core_contract, token_id);
+    settle_token(&e, &client, &core_contract, second);
+
+    assert_eq!(
+        client.try_transfer(&owner, &recipient, &token_id),
+        Err(Ok(ContractError::TransfersPaused))
+    );
+    client.approve(&owner, &recipient, &token_id);
+    assert_eq!(
+        client.try_transfer_from(&recipient, &owner, &recipient, &token_id),
+        Err(Ok(ContractError::TransfersPaused))
+    );
+    assert_eq!(
+        client.try_transfer_all(&owner, &recipient),
+        Err(Ok(ContractError::TransfersPaused))
+    );
+    assert_eq!(client.owner_of(&token_id), owner);
+
+    client.resume_transfers();
+    assert!(!client.are_transfers_paused());
+    client.transfer(&owner, &recipient, &token_id);
+    assert_eq!(client.owner_of(&token_id), recipient);
+}
+
+#[test]
+fn test_transfer_from_allows_operator_until_revoked() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+
+    let first = mint_for(&e, &client, &admin, &owner);
+    let second = mint_for(&e, &client, &admin, &owner);
+    settle_token(&e, &client, &core_contract, first);
+    settle_token(&e, &client, &core_contract, second);
+
+    client.set_approval_for_all(&owner, &operator, &true);
+    assert!(client.is_approved_for_all(&owner, &operator));
+    client.transfer_from(&operator, &owner, &recipient, &first);
+    assert_eq!(client.owner_of(&first), recipient);
+
+    client.set_approval_for_all(&owner, &operator, &false);
+    assert!(!client.is_approved_for_all(&owner, &operator));
+    assert_eq!(
+        client.try_transfer_from(&operator, &owner, &recipient, &second),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(client.owner_of(&second), owner);
+}
+
+#[test]
+fn test_is_expired_and_token_exists_read_the_minted_token() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    assert!(client.token_exists(&token_id));
+    assert!(!client.is_expired(&token_id));
+
+    let expires_at = client.get_metadata(&token_id).expires_at;
+    e.ledger().with_mut(|l| l.timestamp = expires_at);
+    assert!(client.is_expired(&token_id));
+
+    assert!(!client.token_exists(&(token_id + 1)));
+    assert_eq!(
+        client.try_is_expired(&(token_id + 1)),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
+/// Authorized minter that calls back into the NFT contract while it holds control.
+#[contract]
+struct ReentrantMinter;
+
+#[contractimpl]
+impl ReentrantMinter {
+    /// Attempts a nested mint and transfer, returning the error code of each
+    /// (0 on success, `u32::MAX` for a host error).
+    pub fn reenter(e: Env, nft: Address, owner: Address, token_id: u32) -> (u32, u32) {
+        let client = CommitmentNFTContractClient::new(&e, &nft);
+        let minted = client.try_mint(
+            &e.current_contract_address(),
+            &owner,
+            &String::from_str(&e, "reentrant"),
+            &1,
+            &10,
+            &String::from_str(&e, "safe"),
+            &1_000,
+            &Address::generate(&e),
+            &5,
+        );
+        let transferred = client.try_transfer(&owner, &Address::generate(&e), &token_id);
+        let code = |err: Option<Result<ContractError, soroban_sdk::InvokeError>>| match err {
+            None => 0,
+            Some(Ok(err)) => err as u32,
+            Some(Err(_)) => u32::MAX,
+        };
+        (code(minted.err()), code(transferred.err()))
+    }
+}
+
+#[test]
+fn test_nested_call_from_malicious_contract_is_rejected() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    e.mock_all_auths_allowing_non_root_auth();
+    let core_contract = Address::generate(&e);
+    let owner = Address::generate(&e);
+    client.set_core_contract(&core_contract);
+    let token_id = mint_for(&e, &client, &admin, &owner);
+    settle_token(&e, &client, &core_contract, token_id);
+
+    let attacker = e.register_contract(None, ReentrantMinter);
+    client.add_authorized_contract(&admin, &attacker);
+    let attacker_client = ReentrantMinterClient::new(&e, &attacker);
+
+    // Hold the guard as an in-flight NFT call would while the attacker runs.
+    e.as_contract(&client.address, || check_and_set_guard(&e))
+        .unwrap();
+    let reentrancy = ContractError::ReentrancyDetected as u32;
+    assert_eq!(
+        attacker_client.reenter(&client.address, &owner, &token_id),
+        (reentrancy, reentrancy)
+    );
+    assert_eq!(client.owner_of(&token_id), owner);
+    assert_eq!(client.total_supply(), 1);
+
+    e.as_contract(&client.address, || clear_guard(&e));
+    assert_eq!(
+        attacker_client.reenter(&client.address, &owner, &token_id),
+        (0, 0)
+    );
+    assert_ne!(client.owner_of(&token_id), owner);
+    assert_eq!(client.total_supply(), 2);
+}
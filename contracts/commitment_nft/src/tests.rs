@@ -2,7 +2,7 @@
 
 use super::*;
 use soroban_sdk::testutils::Address;
-use commitment_core::{CommitmentRules, CommitmentCoreContract, CommitmentCoreContractClient};
+use commitment_core::{CommitmentRules, CommitmentCoreContract, CommitmentCoreContractClient, CommitmentStatus};
 use shared_utils::TimeUtils;
 use soroban_sdk::{
     contract, contractimpl, symbol_short,
@@ -260,9 +260,10 @@ fn test_mint() {
             owner.into_val(&e)
         ]
     );
-    let data: (String, u64) = last_event.2.into_val(&e);
+    let data: (u32, String, u64) = last_event.2.into_val(&e);
+    assert_eq!(data.0, token_id);
     // Verify the auto-generated commitment_id matches the expected format
-    assert_eq!(data.0, String::from_str(&e, "COMMIT_0"));
+    assert_eq!(data.1, String::from_str(&e, "COMMIT_0"));
 }
 
 #[test]
@@ -907,7 +908,7 @@ fn test_get_all_metadata() {
     let client = CommitmentCoreContractClient::new(&e, &contract_id);
     let updated = client.get_commitment(&String::from_str(&e, "test_id"));
     assert_eq!(updated.current_value, 950);
-    assert_eq!(updated.status, String::from_str(&e, "active"));
+    assert_eq!(updated.status, CommitmentStatus::Active);
 }
 
 #[test]
@@ -1033,7 +1034,7 @@ fn test_get_all_metadata() {
     let client = CommitmentCoreContractClient::new(&e, &contract_id);
     let updated = client.get_commitment(&String::from_str(&e, "test_id"));
     assert_eq!(updated.current_value, 950);
-    assert_eq!(updated.status, String::from_str(&e, "active"));
+    assert_eq!(updated.status, CommitmentStatus::Active);
 }
 
 #[test]
@@ -1704,8 +1705,8 @@ fn test_settle_requires_active_and_expired_state() {
             token_id.into_val(&e)
         ]
     );
-    let data: u64 = last_event.2.into_val(&e);
-    assert_eq!(data, e.ledger().timestamp());
+    let data: (u32, u64) = last_event.2.into_val(&e);
+    assert_eq!(data, (token_id, e.ledger().timestamp()));
 }
 
 #[test]
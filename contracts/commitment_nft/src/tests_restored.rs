@@ -1021,7 +1021,7 @@ fn test_get_all_metadata() {
     let client = CommitmentCoreContractClient::new(&e, &contract_id);
     let updated = client.get_commitment(&String::from_str(&e, "test_id"));
     assert_eq!(updated.current_value, 950);
-    assert_eq!(updated.status, String::from_str(&e, "active"));
+    assert_eq!(updated.status, CommitmentStatus::Active);
 }
 
 #[test]
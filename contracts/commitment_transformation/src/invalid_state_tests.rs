@@ -433,7 +433,7 @@ fn test_create_tranches_with_expired_commitment() {
     client.initialize(&admin, &core);
     client.set_authorized_transformer(&admin, &user, &true);
     
-    // "c_expired" is set to "expired" status in MockCommitmentCore
+    // "c_expired" is reported as already settled by MockCommitmentCore
     let commitment_id = String::from_str(&e, "c_expired");
     let tranche_share_bps: Vec<u32> = vec![&e, 10000u32];
     let risk_levels: Vec<String> = vec![&e, String::from_str(&e, "senior")];
@@ -201,6 +201,15 @@ struct CoreCommitmentRules {
     pub grace_period_days: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum CoreCommitmentStatus {
+    Active,
+    Settled,
+    Violated,
+    EarlyExit,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct CoreCommitment {
@@ -213,7 +222,14 @@ struct CoreCommitment {
     pub created_at: u64,
     pub expires_at: u64,
     pub current_value: i128,
-    pub status: String,
+    pub status: CoreCommitmentStatus,
+    pub memo: String,
+    pub settled_amount: i128,
+    pub settled_at: u64,
+    pub max_drawdown_percent: u32,
+    pub last_update_ts: u64,
+    pub realized_pnl: i128,
+    pub beneficiary: Option<Address>,
 }
 
 #[contracttype]
@@ -306,7 +322,7 @@ fn load_commitment(e: &Env, commitment_id: &String) -> CoreCommitment {
         .try_into_val(e)
         .unwrap_or_else(|_| fail(e, TransformationError::CommitmentNotFound, "load_commitment"));
 
-    if commitment.status != String::from_str(e, "active") {
+    if commitment.status != CoreCommitmentStatus::Active {
         fail(e, TransformationError::InvalidState, "load_commitment");
     }
 
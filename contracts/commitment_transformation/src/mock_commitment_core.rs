@@ -14,6 +14,15 @@ pub struct CommitmentRules {
     pub grace_period_days: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommitmentStatus {
+    Active,
+    Settled,
+    Violated,
+    EarlyExit,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Commitment {
@@ -26,7 +35,14 @@ pub struct Commitment {
     pub created_at: u64,
     pub expires_at: u64,
     pub current_value: i128,
-    pub status: String,
+    pub status: CommitmentStatus,
+    pub memo: String,
+    pub settled_amount: i128,
+    pub settled_at: u64,
+    pub max_drawdown_percent: u32,
+    pub last_update_ts: u64,
+    pub realized_pnl: i128,
+    pub beneficiary: Option<Address>,
 }
 
 #[contracttype]
@@ -58,7 +74,14 @@ fn default_active_commitment(e: &Env, commitment_id: String) -> Commitment {
         created_at,
         expires_at: created_at + 30 * 86_400,
         current_value: 1_000_000,
-        status: String::from_str(e, "active"),
+        status: CommitmentStatus::Active,
+        memo: String::from_str(e, ""),
+        settled_amount: 0,
+        settled_at: 0,
+        max_drawdown_percent: 0,
+        last_update_ts: 0,
+        realized_pnl: 0,
+        beneficiary: None,
     }
 }
 
@@ -88,7 +111,7 @@ impl MockCommitmentCore {
 
         if commitment_id == String::from_str(&e, "c_expired") {
             let mut commitment = default_active_commitment(&e, commitment_id);
-            commitment.status = String::from_str(&e, "expired");
+            commitment.status = CommitmentStatus::Settled;
             return commitment;
         }
 
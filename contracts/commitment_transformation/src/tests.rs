@@ -22,7 +22,7 @@
 
 use super::*;
 use crate::mock_commitment_core::{
-    Commitment, CommitmentRules, MockCommitmentCore, MockCommitmentCoreClient,
+    Commitment, CommitmentRules, CommitmentStatus, MockCommitmentCore, MockCommitmentCoreClient,
 };
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{token::StellarAssetClient, vec, Address, Env, String, Vec};
@@ -70,7 +70,14 @@ fn seed_commitment_owner(e: &Env, core: &Address, commitment_id: &str, owner: &A
         created_at,
         expires_at: created_at + 30 * 86_400,
         current_value: 1_000_000,
-        status: String::from_str(e, "active"),
+        status: CommitmentStatus::Active,
+        memo: String::from_str(e, ""),
+        settled_amount: 0,
+        settled_at: 0,
+        max_drawdown_percent: 0,
+        last_update_ts: 0,
+        realized_pnl: 0,
+        beneficiary: None,
     });
 }
 
@@ -1016,5 +1023,5 @@ fn test_expired_commitment() {
 
     let commitment = core_client.get_commitment(&commitment_id);
 
-    assert_eq!(commitment.status, String::from_str(&env, "expired"));
+    assert_eq!(commitment.status, CommitmentStatus::Settled);
 }
@@ -1,7 +1,31 @@
 //! Access control patterns and utilities
 
-use super::storage::Storage;
-use soroban_sdk::{Address, Env, Symbol};
+use super::events::Events;
+use super::storage::{keys, Storage};
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+/// Storage keys used by [`AccessControl`]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccessControlKey {
+    /// Admin proposed by `propose_admin`, waiting for `accept_admin`
+    PendingAdmin,
+    /// Membership of an account in a named role
+    Role(Symbol, Address),
+    /// Whether a contract is in the authorized set (O(1) lookup)
+    AuthorizedContract(Address),
+    /// Every authorized contract, in the order it was added (for enumeration)
+    AuthorizedContracts,
+}
+
+/// Well-known role names for use with [`AccessControl::grant_role`]
+pub mod roles {
+    use soroban_sdk::{symbol_short, Symbol};
+
+    pub const PAUSER: Symbol = symbol_short!("PAUSER");
+    pub const MINTER: Symbol = symbol_short!("MINTER");
+    pub const VERIFIER: Symbol = symbol_short!("VERIFIER");
+}
 
 /// Access control helper functions
 pub struct AccessControl;
@@ -14,30 +38,36 @@ impl AccessControl {
     /// * `caller` - The caller address
     ///
     /// # Panics
-    /// Panics with "Unauthorized: only admin" if caller is not admin
+    /// Panics with "Unauthorized: only admin" if caller is not admin, which
+    /// includes every caller once the admin has been renounced
     pub fn require_admin(e: &Env, caller: &Address) {
         caller.require_auth();
-        let admin = Storage::get_admin(e);
-        if *caller != admin {
+        if Self::admin(e).as_ref() != Some(caller) {
             panic!("Unauthorized: only admin can perform this action");
         }
     }
 
-    /// Require that the caller is authorized (either admin or in authorized list)
+    /// Read the admin, or `None` once it has been renounced
+    fn admin(e: &Env) -> Option<Address> {
+        Storage::require_initialized(e);
+        e.storage().instance().get(&keys::ADMIN)
+    }
+
+    /// Require that the caller is authorized: the admin, a member of the
+    /// `add_authorized_contract` set, or flagged under `authorized_key`
     ///
     /// # Arguments
     /// * `e` - The environment
     /// * `caller` - The caller address
-    /// * `authorized_key` - The storage key prefix for the authorized list
+    /// * `authorized_key` - The storage key prefix for a contract-specific authorized list
     ///
     /// # Panics
     /// Panics with "Unauthorized" if caller is not admin or authorized
     pub fn require_admin_or_authorized(e: &Env, caller: &Address, authorized_key: &Symbol) {
         caller.require_auth();
 
-        // Check if caller is admin
-        let admin = Storage::get_admin(e);
-        if *caller == admin {
+        // Check if caller is admin or in the shared authorized set
+        if Self::is_admin(e, caller) || Self::is_authorized(e, caller) {
             return;
         }
 
@@ -58,8 +88,7 @@ impl AccessControl {
     /// # Returns
     /// `true` if address is admin, `false` otherwise
     pub fn is_admin(e: &Env, address: &Address) -> bool {
-        let admin = Storage::get_admin(e);
-        *address == admin
+        Self::admin(e).as_ref() == Some(address)
     }
 
     /// Require that the caller is the owner
@@ -100,6 +129,284 @@ impl AccessControl {
 
         panic!("Unauthorized: caller is not the owner or admin");
     }
+
+    /// Propose `new_admin` as the next admin (first step of a handoff)
+    ///
+    /// The admin does not change until `new_admin` calls `accept_admin`, so a
+    /// mistyped address cannot lock the contract. A new proposal replaces any
+    /// pending one.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The current admin
+    /// * `new_admin` - The proposed admin
+    ///
+    /// # Panics
+    /// Panics if caller is not admin
+    pub fn propose_admin(e: &Env, caller: &Address, new_admin: &Address) {
+        Self::require_admin(e, caller);
+        e.storage()
+            .instance()
+            .set(&AccessControlKey::PendingAdmin, new_admin);
+        Events::emit_with_topics(
+            e,
+            (Symbol::new(e, "AdminProposed"), caller.clone()),
+            (new_admin.clone(), e.ledger().timestamp()),
+        );
+    }
+
+    /// Accept a pending admin proposal (second step of a handoff)
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The pending admin
+    ///
+    /// # Panics
+    /// Panics with "Unauthorized: caller is not the pending admin" if nothing is
+    /// pending or caller is not the proposed address
+    pub fn accept_admin(e: &Env, caller: &Address) {
+        caller.require_auth();
+        if Self::get_pending_admin(e).as_ref() != Some(caller) {
+            panic!("Unauthorized: caller is not the pending admin");
+        }
+
+        let previous = Storage::get_admin(e);
+        Storage::set_admin(e, caller);
+        e.storage()
+            .instance()
+            .remove(&AccessControlKey::PendingAdmin);
+        Events::emit_with_topics(
+            e,
+            (Symbol::new(e, "AdminAccepted"), caller.clone()),
+            (previous, e.ledger().timestamp()),
+        );
+    }
+
+    /// Withdraw the pending admin proposal, if any
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The current admin
+    ///
+    /// # Panics
+    /// Panics if caller is not admin
+    pub fn cancel_admin_proposal(e: &Env, caller: &Address) {
+        Self::require_admin(e, caller);
+        if let Some(pending) = Self::get_pending_admin(e) {
+            e.storage()
+                .instance()
+                .remove(&AccessControlKey::PendingAdmin);
+            Events::emit_with_topics(
+                e,
+                (Symbol::new(e, "AdminProposalCancelled"), caller.clone()),
+                (pending, e.ledger().timestamp()),
+            );
+        }
+    }
+
+    /// Permanently give up admin control (admin only)
+    ///
+    /// Removes the admin and any pending proposal. Afterwards `require_admin`
+    /// fails for every caller, so admin-only functions (including
+    /// `propose_admin`, the only way to install a new admin) can never be
+    /// called again. Owner and role checks are unaffected.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The current admin
+    ///
+    /// # Panics
+    /// Panics if caller is not admin
+    pub fn renounce_admin(e: &Env, caller: &Address) {
+        Self::require_admin(e, caller);
+        e.storage().instance().remove(&keys::ADMIN);
+        e.storage()
+            .instance()
+            .remove(&AccessControlKey::PendingAdmin);
+        Events::emit_with_topics(
+            e,
+            (Symbol::new(e, "admin_renounced"), caller.clone()),
+            (e.ledger().timestamp(),),
+        );
+    }
+
+    /// Get the admin proposed by `propose_admin`, if any
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    pub fn get_pending_admin(e: &Env) -> Option<Address> {
+        e.storage().instance().get(&AccessControlKey::PendingAdmin)
+    }
+
+    /// Grant `role` to `account` (admin only). Granting an existing member is a no-op.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The admin
+    /// * `role` - The role name, e.g. [`roles::PAUSER`]
+    /// * `account` - The account receiving the role
+    ///
+    /// # Panics
+    /// Panics if caller is not admin
+    pub fn grant_role(e: &Env, caller: &Address, role: &Symbol, account: &Address) {
+        Self::require_admin(e, caller);
+        if Self::has_role(e, role, account) {
+            return;
+        }
+        e.storage().instance().set(
+            &AccessControlKey::Role(role.clone(), account.clone()),
+            &true,
+        );
+        Events::emit_with_topics(
+            e,
+            (Symbol::new(e, "RoleGranted"), role.clone(), account.clone()),
+            (caller.clone(), e.ledger().timestamp()),
+        );
+    }
+
+    /// Revoke `role` from `account` (admin only). Revoking a non-member is a no-op.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The admin
+    /// * `role` - The role name
+    /// * `account` - The account losing the role
+    ///
+    /// # Panics
+    /// Panics if caller is not admin
+    pub fn revoke_role(e: &Env, caller: &Address, role: &Symbol, account: &Address) {
+        Self::require_admin(e, caller);
+        if !Self::has_role(e, role, account) {
+            return;
+        }
+        e.storage()
+            .instance()
+            .remove(&AccessControlKey::Role(role.clone(), account.clone()));
+        Events::emit_with_topics(
+            e,
+            (Symbol::new(e, "RoleRevoked"), role.clone(), account.clone()),
+            (caller.clone(), e.ledger().timestamp()),
+        );
+    }
+
+    /// Check if `account` holds `role`
+    ///
+    /// Roles are independent of admin: the admin holds a role only if granted it.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `role` - The role name
+    /// * `account` - The account to check
+    pub fn has_role(e: &Env, role: &Symbol, account: &Address) -> bool {
+        e.storage()
+            .instance()
+            .get(&AccessControlKey::Role(role.clone(), account.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Require that the caller holds `role`
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The caller address
+    /// * `role` - The required role
+    ///
+    /// # Panics
+    /// Panics with "Unauthorized: caller is missing the required role" if caller
+    /// does not hold `role`
+    pub fn require_role(e: &Env, caller: &Address, role: &Symbol) {
+        caller.require_auth();
+        if !Self::has_role(e, role, caller) {
+            panic!("Unauthorized: caller is missing the required role");
+        }
+    }
+
+    /// Add `contract` to the authorized set (admin only). Adding a member is a no-op.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The admin
+    /// * `contract` - The contract to authorize
+    ///
+    /// # Panics
+    /// Panics if caller is not admin
+    pub fn add_authorized_contract(e: &Env, caller: &Address, contract: &Address) {
+        Self::require_admin(e, caller);
+        if Self::is_authorized(e, contract) {
+            return;
+        }
+        let mut contracts = Self::get_authorized_contracts(e);
+        contracts.push_back(contract.clone());
+        e.storage()
+            .instance()
+            .set(&AccessControlKey::AuthorizedContracts, &contracts);
+        e.storage().instance().set(
+            &AccessControlKey::AuthorizedContract(contract.clone()),
+            &true,
+        );
+        Events::emit_with_topics(
+            e,
+            (Symbol::new(e, "AuthorizedContractAdded"), contract.clone()),
+            (caller.clone(), e.ledger().timestamp()),
+        );
+    }
+
+    /// Remove `contract` from the authorized set (admin only). Removing a non-member is a no-op.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `caller` - The admin
+    /// * `contract` - The contract to deauthorize
+    ///
+    /// # Panics
+    /// Panics if caller is not admin
+    pub fn remove_authorized_contract(e: &Env, caller: &Address, contract: &Address) {
+        Self::require_admin(e, caller);
+        if !Self::is_authorized(e, contract) {
+            return;
+        }
+        let mut contracts = Self::get_authorized_contracts(e);
+        if let Some(index) = contracts.first_index_of(contract) {
+            contracts.remove(index);
+        }
+        e.storage()
+            .instance()
+            .set(&AccessControlKey::AuthorizedContracts, &contracts);
+        e.storage()
+            .instance()
+            .remove(&AccessControlKey::AuthorizedContract(contract.clone()));
+        Events::emit_with_topics(
+            e,
+            (
+                Symbol::new(e, "AuthorizedContractRemoved"),
+                contract.clone(),
+            ),
+            (caller.clone(), e.ledger().timestamp()),
+        );
+    }
+
+    /// Check if `contract` is in the authorized set
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `contract` - The contract to check
+    pub fn is_authorized(e: &Env, contract: &Address) -> bool {
+        e.storage()
+            .instance()
+            .get(&AccessControlKey::AuthorizedContract(contract.clone()))
+            .unwrap_or(false)
+    }
+
+    /// List every authorized contract, in the order they were added
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    pub fn get_authorized_contracts(e: &Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&AccessControlKey::AuthorizedContracts)
+            .unwrap_or(Vec::new(e))
+    }
 }
 
 #[cfg(all(test, not(target_family = "wasm")))]
@@ -186,4 +493,220 @@ mod tests {
             AccessControl::require_admin_or_authorized(&env, &caller, &authorized_key);
         });
     }
+
+    #[test]
+    fn test_require_admin_or_authorized_accepts_authorized_contract_set() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let member = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let authorized_key: Symbol = soroban_sdk::symbol_short!("AUTHUSR");
+        let contract_id = env.register_contract(None, TestContract);
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::add_authorized_contract(&env, &admin, &member);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::require_admin_or_authorized(&env, &member, &authorized_key);
+        });
+    }
+
+    #[test]
+    fn test_two_step_admin_handoff() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let first = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let second = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let contract_id = env.register_contract(None, TestContract);
+
+        // Each step runs in its own frame, as separate contract calls would.
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::propose_admin(&env, &admin, &first);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::propose_admin(&env, &admin, &second);
+            assert_eq!(AccessControl::get_pending_admin(&env), Some(second.clone()));
+            assert!(AccessControl::is_admin(&env, &admin));
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::accept_admin(&env, &second);
+            assert!(AccessControl::is_admin(&env, &second));
+            assert_eq!(AccessControl::get_pending_admin(&env), None);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::propose_admin(&env, &second, &first);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::cancel_admin_proposal(&env, &second);
+            assert_eq!(AccessControl::get_pending_admin(&env), None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller is not the pending admin")]
+    fn test_accept_admin_rejects_replaced_proposal() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let first = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let second = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let contract_id = env.register_contract(None, TestContract);
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::propose_admin(&env, &admin, &first);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::propose_admin(&env, &admin, &second);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::accept_admin(&env, &first);
+        });
+    }
+
+    #[test]
+    fn test_renounce_admin_leaves_no_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let successor = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let contract_id = env.register_contract(None, TestContract);
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::propose_admin(&env, &admin, &successor);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::renounce_admin(&env, &admin);
+            assert!(!AccessControl::is_admin(&env, &admin));
+            assert_eq!(AccessControl::get_pending_admin(&env), None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: only admin can perform this action")]
+    fn test_require_admin_fails_after_renounce() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let contract_id = env.register_contract(None, TestContract);
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::renounce_admin(&env, &admin);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::require_admin(&env, &admin);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: only admin can perform this action")]
+    fn test_admin_handoff_unavailable_after_renounce() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let successor = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let contract_id = env.register_contract(None, TestContract);
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::renounce_admin(&env, &admin);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::propose_admin(&env, &admin, &successor);
+        });
+    }
+
+    #[test]
+    fn test_grant_and_revoke_role() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let pauser = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let contract_id = env.register_contract(None, TestContract);
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::grant_role(&env, &admin, &roles::PAUSER, &pauser);
+
+            assert!(AccessControl::has_role(&env, &roles::PAUSER, &pauser));
+            assert!(!AccessControl::has_role(&env, &roles::MINTER, &pauser));
+            assert!(!AccessControl::has_role(&env, &roles::PAUSER, &admin));
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::require_role(&env, &pauser, &roles::PAUSER);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::revoke_role(&env, &admin, &roles::PAUSER, &pauser);
+            assert!(!AccessControl::has_role(&env, &roles::PAUSER, &pauser));
+        });
+    }
+
+    #[test]
+    fn test_get_authorized_contracts_tracks_adds_and_removes() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let first = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let second = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let third = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let contract_id = env.register_contract(None, TestContract);
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::add_authorized_contract(&env, &admin, &first);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::add_authorized_contract(&env, &admin, &second);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::add_authorized_contract(&env, &admin, &third);
+        });
+        env.as_contract(&contract_id, || {
+            AccessControl::remove_authorized_contract(&env, &admin, &second);
+
+            let contracts = AccessControl::get_authorized_contracts(&env);
+            assert_eq!(contracts.len(), 2);
+            assert!(contracts.contains(&first));
+            assert!(contracts.contains(&third));
+            assert!(AccessControl::is_authorized(&env, &first));
+            assert!(!AccessControl::is_authorized(&env, &second));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller is missing the required role")]
+    fn test_require_role_rejects_non_member() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let contract_id = env.register_contract(None, TestContract);
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            Storage::set_admin(&env, &admin);
+            AccessControl::require_role(&env, &admin, &roles::VERIFIER);
+        });
+    }
 }
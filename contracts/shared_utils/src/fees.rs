@@ -7,6 +7,8 @@
 //! - Marketplace fees (if applicable)
 //! - Early exit fee (goes to protocol)
 
+use crate::math::SafeMath;
+
 /// Basis points scale: 10000 bps = 100%
 pub const BPS_SCALE: u32 = 10000;
 
@@ -25,17 +27,7 @@ pub const BPS_MAX: u32 = 10000;
 /// # Panics
 /// If `bps > 10000`.
 pub fn fee_from_bps(amount: i128, bps: u32) -> i128 {
-    if bps > BPS_MAX {
-        panic!("Fees: bps must be 0-10000");
-    }
-    if bps == 0 {
-        return 0;
-    }
-    amount
-        .checked_mul(bps as i128)
-        .expect("Fees: overflow")
-        .checked_div(BPS_SCALE as i128)
-        .expect("Fees: div by zero")
+    SafeMath::apply_bps(amount, bps)
 }
 
 /// Net amount after deducting a fee in basis points.
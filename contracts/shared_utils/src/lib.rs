@@ -32,7 +32,7 @@ pub mod fee;
 mod tests;
 
 // Re-export all public items from each utility module
-pub use access_control::AccessControl;
+pub use access_control::{roles, AccessControl, AccessControlKey};
 pub use batch::{
     BatchConfig, BatchDataKey, BatchError, BatchMode, BatchOperationReport, BatchProcessor,
     BatchResultString, BatchResultVoid, DetailedBatchError, RollbackHelper, StateSnapshot,
@@ -42,6 +42,7 @@ pub use error_codes::emit_error_event;
 pub use math::SafeMath;
 pub use pausable::Pausable;
 pub use rate_limiting::RateLimiter;
+pub use storage::Storage;
 pub use time::TimeUtils;
 pub use validation::Validation;
 
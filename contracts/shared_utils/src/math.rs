@@ -1,5 +1,7 @@
 //! Math utilities for safe arithmetic operations and percentage calculations
 
+use crate::fees::{BPS_MAX, BPS_SCALE};
+
 /// Safe math operations to prevent overflow/underflow
 pub struct SafeMath;
 
@@ -60,6 +62,32 @@ impl SafeMath {
         Self::percent(value, percent)
     }
 
+    /// Non-panicking [`SafeMath::percent_of`]: `None` if `percent > 100` or the
+    /// product overflows `i128`.
+    pub fn checked_percent_of(value: i128, percent: u32) -> Option<i128> {
+        if percent > 100 {
+            return None;
+        }
+        value
+            .checked_mul(percent as i128)
+            .map(|scaled| scaled / 100)
+    }
+
+    /// Apply a rate in basis points: `(amount * bps) / 10000`, rounded toward zero
+    ///
+    /// # Arguments
+    /// * `amount` - The base amount
+    /// * `bps` - The rate in basis points (0-10000)
+    ///
+    /// # Panics
+    /// If `bps > 10000` or the product overflows `i128`.
+    pub fn apply_bps(amount: i128, bps: u32) -> i128 {
+        if bps > BPS_MAX {
+            panic!("Math: bps must be 0-10000");
+        }
+        Self::div(Self::mul(amount, bps as i128), BPS_SCALE as i128)
+    }
+
     /// Calculate what percentage `part` is of `whole`: (part * 100) / whole
     ///
     /// # Arguments
@@ -103,6 +131,35 @@ impl SafeMath {
         Self::percent_from(loss, initial)
     }
 
+    /// Drawdown of `current` from `initial` in whole percent, clamped to 0-100
+    ///
+    /// Same as [`SafeMath::loss_percent`], except a non-positive `initial` (nothing
+    /// committed to lose) yields `0` instead of panicking.
+    pub fn drawdown_percent(initial: i128, current: i128) -> i128 {
+        if initial <= 0 {
+            return 0;
+        }
+        Self::loss_percent(initial, current)
+    }
+
+    /// Checked variant of [`SafeMath::drawdown_percent`]
+    ///
+    /// # Returns
+    /// `None` if `(initial - current) * 100` overflows `i128`, so callers can
+    /// surface their own error instead of panicking.
+    pub fn checked_drawdown_percent(initial: i128, current: i128) -> Option<i128> {
+        if initial <= 0 || current >= initial {
+            return Some(0);
+        }
+        if current <= 0 {
+            return Some(100);
+        }
+        initial
+            .checked_sub(current)?
+            .checked_mul(100)
+            .map(|scaled| scaled / initial)
+    }
+
     /// Calculate gain percentage: ((current - initial) * 100) / initial
     ///
     /// # Arguments
@@ -151,6 +208,24 @@ impl SafeMath {
     pub fn penalty_amount(value: i128, penalty_percent: u32) -> i128 {
         Self::percent(value, penalty_percent)
     }
+
+    /// Penalty on a partial withdrawal: `ceil(withdrawn * penalty_percent / 100)`,
+    /// raised to `min_penalty` and capped at `withdrawn`.
+    ///
+    /// Rounding is toward the protocol so splitting an exit into many small
+    /// withdrawals cannot round each penalty down to zero. No penalty is charged
+    /// when `penalty_percent` is 0 or nothing is withdrawn.
+    pub fn proportional_penalty(withdrawn: i128, penalty_percent: u32, min_penalty: i128) -> i128 {
+        if penalty_percent > 100 {
+            panic!("Math: percent must be <= 100");
+        }
+        if withdrawn <= 0 || penalty_percent == 0 {
+            return 0;
+        }
+        let scaled = Self::mul(withdrawn, penalty_percent as i128);
+        let rounded_up = Self::div(Self::add(scaled, 99), 100);
+        rounded_up.max(min_penalty).min(withdrawn)
+    }
 }
 
 #[cfg(all(test, not(target_family = "wasm")))]
@@ -264,6 +339,91 @@ mod tests {
         assert_eq!(SafeMath::apply_penalty(1000, 0), 1000);
     }
 
+    #[test]
+    fn test_proportional_penalty_rounds_up_and_applies_floor() {
+        assert_eq!(SafeMath::proportional_penalty(1000, 10, 0), 100);
+        assert_eq!(SafeMath::proportional_penalty(1001, 10, 0), 101);
+        // 5 * 10% = 0.5 would truncate to zero; it rounds up instead.
+        assert_eq!(SafeMath::proportional_penalty(5, 10, 0), 1);
+        assert_eq!(SafeMath::proportional_penalty(5, 10, 3), 3);
+        // The floor never exceeds the withdrawal itself.
+        assert_eq!(SafeMath::proportional_penalty(2, 10, 3), 2);
+        assert_eq!(SafeMath::proportional_penalty(1000, 0, 3), 0);
+        assert_eq!(SafeMath::proportional_penalty(0, 10, 3), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplication overflow")]
+    fn test_proportional_penalty_overflow() {
+        SafeMath::proportional_penalty(i128::MAX, 10, 0);
+    }
+
+    #[test]
+    fn test_drawdown_percent() {
+        assert_eq!(SafeMath::drawdown_percent(1000, 850), 15);
+        assert_eq!(SafeMath::drawdown_percent(1000, 1200), 0);
+        assert_eq!(SafeMath::drawdown_percent(1000, -5), 100);
+        // Zero or negative principal has nothing to lose instead of dividing by zero.
+        assert_eq!(SafeMath::drawdown_percent(0, 500), 0);
+        assert_eq!(SafeMath::drawdown_percent(-1, 500), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplication overflow")]
+    fn test_drawdown_percent_overflow() {
+        SafeMath::drawdown_percent(i128::MAX, 1);
+    }
+
+    #[test]
+    fn test_checked_drawdown_percent() {
+        assert_eq!(SafeMath::checked_drawdown_percent(1000, 850), Some(15));
+        assert_eq!(SafeMath::checked_drawdown_percent(1000, 1200), Some(0));
+        assert_eq!(SafeMath::checked_drawdown_percent(0, 500), Some(0));
+        assert_eq!(SafeMath::checked_drawdown_percent(i128::MAX, 0), Some(100));
+        // Large principals still work while the scaled loss fits in i128.
+        let initial = i128::MAX / 100;
+        assert_eq!(
+            SafeMath::checked_drawdown_percent(initial, initial / 2),
+            Some(50)
+        );
+        assert_eq!(SafeMath::checked_drawdown_percent(i128::MAX, 1), None);
+        assert_eq!(
+            SafeMath::checked_drawdown_percent(i128::MAX, i128::MAX - 1),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_apply_bps() {
+        assert_eq!(SafeMath::apply_bps(1_000_000, 250), 25_000);
+        assert_eq!(SafeMath::apply_bps(1, 9_999), 0);
+        assert_eq!(SafeMath::apply_bps(1000, 10_000), 1000);
+        assert_eq!(SafeMath::apply_bps(1000, 0), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "bps must be 0-10000")]
+    fn test_apply_bps_rejects_rate_above_100_percent() {
+        SafeMath::apply_bps(1000, 10_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplication overflow")]
+    fn test_apply_bps_overflow() {
+        SafeMath::apply_bps(i128::MAX, 2);
+    }
+
+    #[test]
+    fn test_checked_percent_of() {
+        assert_eq!(SafeMath::checked_percent_of(1000, 10), Some(100));
+        assert_eq!(SafeMath::checked_percent_of(1000, 101), None);
+        assert_eq!(SafeMath::checked_percent_of(i128::MAX, 2), None);
+        assert_eq!(
+            SafeMath::checked_percent_of(i128::MAX, 1),
+            Some(i128::MAX / 100)
+        );
+    }
+
     #[test]
     fn test_penalty_amount() {
         assert_eq!(SafeMath::penalty_amount(1000, 10), 100);
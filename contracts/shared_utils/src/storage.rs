@@ -1,6 +1,6 @@
 //! Storage helper utilities for common storage patterns
 
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
 
 /// Storage key constants
 pub mod keys {
@@ -10,6 +10,12 @@ pub mod keys {
     pub const INITIALIZED: Symbol = symbol_short!("INIT");
 }
 
+/// Entries whose TTL drops below this many ledgers are extended (~30 days at 5s ledgers)
+pub const TTL_THRESHOLD_LEDGERS: u32 = 518_400;
+
+/// Ledgers an extended entry lives for (~180 days), capped at the network maximum
+pub const TTL_EXTEND_TO_LEDGERS: u32 = 3_110_400;
+
 /// Storage helper functions
 pub struct Storage;
 
@@ -86,6 +92,65 @@ impl Storage {
         }
     }
 
+    /// Extend the contract instance (and every instance-storage entry) so it is not archived
+    ///
+    /// No-op while more than [`TTL_THRESHOLD_LEDGERS`] remain.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    pub fn extend_instance_ttl(e: &Env) {
+        let (threshold, extend_to) = Self::ttl_bounds(e);
+        e.storage().instance().extend_ttl(threshold, extend_to);
+    }
+
+    /// Extend a persistent entry so it is not archived
+    ///
+    /// No-op while more than [`TTL_THRESHOLD_LEDGERS`] remain.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `key` - The persistent storage key; must exist
+    pub fn extend_persistent_ttl<K>(e: &Env, key: &K)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let (threshold, extend_to) = Self::ttl_bounds(e);
+        e.storage()
+            .persistent()
+            .extend_ttl(key, threshold, extend_to);
+    }
+
+    /// Append `item` to the persistent `Vec` at `key`, evicting the oldest entry
+    /// once it already holds `max` items
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `key` - The persistent storage key; created if missing
+    /// * `item` - The entry to append
+    /// * `max` - Maximum number of entries kept
+    pub fn push_bounded<K, T>(e: &Env, key: &K, item: T, max: u32)
+    where
+        K: IntoVal<Env, Val>,
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        let mut list: Vec<T> = e
+            .storage()
+            .persistent()
+            .get(key)
+            .unwrap_or_else(|| Vec::new(e));
+        if list.len() >= max {
+            list.pop_front();
+        }
+        list.push_back(item);
+        e.storage().persistent().set(key, &list);
+    }
+
+    /// `(threshold, extend_to)` clamped to the network's maximum entry TTL
+    fn ttl_bounds(e: &Env) -> (u32, u32) {
+        let extend_to = TTL_EXTEND_TO_LEDGERS.min(e.storage().max_ttl());
+        (TTL_THRESHOLD_LEDGERS.min(extend_to), extend_to)
+    }
+
     /// Generic storage getter with default value
     ///
     /// # Arguments
@@ -157,6 +222,47 @@ mod tests {
         pub fn stub() {}
     }
 
+    // ========================================================================
+    // TTL Tests
+    // ========================================================================
+
+    #[test]
+    fn test_extend_ttl_keeps_entries_live() {
+        use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TestContract);
+        let key = symbol_short!("LONGLIVED");
+
+        env.as_contract(&contract_id, || {
+            Storage::set_initialized(&env);
+            env.storage().persistent().set(&key, &1u32);
+            assert!(env.storage().instance().get_ttl() < TTL_THRESHOLD_LEDGERS);
+
+            Storage::extend_instance_ttl(&env);
+            Storage::extend_persistent_ttl(&env, &key);
+
+            let expected = TTL_EXTEND_TO_LEDGERS.min(env.storage().max_ttl());
+            assert_eq!(env.storage().instance().get_ttl(), expected);
+            assert_eq!(env.storage().persistent().get_ttl(&key), expected);
+        });
+    }
+
+    #[test]
+    fn test_push_bounded_evicts_oldest() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TestContract);
+        let key = symbol_short!("HISTORY");
+
+        env.as_contract(&contract_id, || {
+            for i in 0..5u32 {
+                Storage::push_bounded(&env, &key, i, 3);
+            }
+            let list: Vec<u32> = env.storage().persistent().get(&key).unwrap();
+            assert_eq!(list, soroban_sdk::vec![&env, 2, 3, 4]);
+        });
+    }
+
     // ========================================================================
     // Initialization Flag Tests
     // ========================================================================
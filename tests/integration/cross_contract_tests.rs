@@ -12,9 +12,16 @@ use soroban_sdk::{
     Address, Env, String, Symbol, IntoVal, Vec,
 };
 
-use commitment_core::{CommitmentCoreContract, CommitmentRules};
-use commitment_nft::{CommitmentNFTContract, ContractError as NftContractError};
-use attestation_engine::{AttestationEngineContract, AttestationError, AttestationsPage};
+use commitment_core::{
+    CommitmentCoreContract, CommitmentCoreContractClient, CommitmentRules, CommitmentStatus,
+    NftViolationPolicy,
+};
+use commitment_nft::{
+    CommitmentNFTContract, CommitmentNFTContractClient, ContractError as NftContractError,
+};
+use attestation_engine::{
+    AttestationEngineContract, AttestationEngineContractClient, AttestationError, AttestationsPage,
+};
 use allocation_logic::{AllocationStrategiesContract, RiskLevel, Strategy};
 
 /// Verify compliance integration between commitment_core and attestation_engine.
@@ -151,6 +158,252 @@ fn test_commitment_core_calls_nft_on_creation() {
     assert_eq!(nft_owner, *user);
 }
 
+/// Integration test: the NFT token id minted during create resolves back to its commitment
+#[test]
+fn test_get_commitment_by_nft_resolves_minted_token() {
+    let harness = TestHarness::new();
+    let user1 = &harness.accounts.user1;
+    let user2 = &harness.accounts.user2;
+    let amount = 1_000_000_000i128;
+
+    let mut created = Vec::new(&harness.env);
+    for user in [user1, user2] {
+        harness.approve_tokens(user, &harness.contracts.commitment_core, amount);
+        let commitment_id = harness
+            .env
+            .as_contract(&harness.contracts.commitment_core, || {
+                CommitmentCoreContract::create_commitment(
+                    harness.env.clone(),
+                    user.clone(),
+                    amount,
+                    harness.contracts.token.clone(),
+                    harness.default_rules(),
+                )
+            });
+        created.push_back(commitment_id);
+    }
+
+    for commitment_id in created.iter() {
+        let commitment = harness
+            .env
+            .as_contract(&harness.contracts.commitment_core, || {
+                CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
+            });
+        let nft = harness
+            .env
+            .as_contract(&harness.contracts.commitment_nft, || {
+                CommitmentNFTContract::get_metadata(harness.env.clone(), commitment.nft_token_id)
+                    .unwrap()
+            });
+        assert_eq!(nft.commitment_id, commitment_id);
+
+        let resolved = harness
+            .env
+            .as_contract(&harness.contracts.commitment_core, || {
+                CommitmentCoreContract::get_commitment_by_nft(
+                    harness.env.clone(),
+                    harness.contracts.commitment_nft.clone(),
+                    nft.token_id,
+                )
+            });
+        assert_eq!(resolved.commitment_id, commitment_id);
+        assert_eq!(resolved.owner, nft.owner);
+    }
+}
+
+/// Integration test: token ids that collide across two NFT contracts resolve separately
+#[test]
+fn test_get_commitment_by_nft_distinguishes_nft_contracts() {
+    let harness = TestHarness::new();
+    let env = &harness.env;
+    let user = &harness.accounts.user1;
+    let admin = &harness.accounts.admin;
+    let amount = 1_000_000_000i128;
+    let core_client = CommitmentCoreContractClient::new(env, &harness.contracts.commitment_core);
+
+    let second_nft = env.register_contract(None, CommitmentNFTContract);
+    let second_nft_client = CommitmentNFTContractClient::new(env, &second_nft);
+    second_nft_client.initialize(admin);
+    second_nft_client.set_core_contract(&harness.contracts.commitment_core);
+
+    harness.approve_tokens(user, &harness.contracts.commitment_core, amount * 2);
+    let first_id = core_client.create_commitment(
+        user,
+        &amount,
+        &harness.contracts.token,
+        &harness.default_rules(),
+    );
+    core_client.set_nft_contract(admin, &second_nft);
+    let second_id = core_client.create_commitment(
+        user,
+        &amount,
+        &harness.contracts.token,
+        &harness.default_rules(),
+    );
+
+    let token_id = core_client.get_commitment(&first_id).nft_token_id;
+    assert_eq!(
+        core_client.get_commitment(&second_id).nft_token_id,
+        token_id
+    );
+
+    assert_eq!(
+        core_client
+            .get_commitment_by_nft(&harness.contracts.commitment_nft, &token_id)
+            .commitment_id,
+        first_id
+    );
+    assert_eq!(
+        core_client
+            .get_commitment_by_nft(&second_nft, &token_id)
+            .commitment_id,
+        second_id
+    );
+}
+
+/// Integration test: extend_duration keeps the real NFT's expiry in step with core
+#[test]
+fn test_extend_duration_updates_nft_expiry() {
+    let harness = TestHarness::new();
+    let env = &harness.env;
+    let user = &harness.accounts.user1;
+    let amount = 1_000_000_000i128;
+    let core_client = CommitmentCoreContractClient::new(env, &harness.contracts.commitment_core);
+    let nft_client = CommitmentNFTContractClient::new(env, &harness.contracts.commitment_nft);
+
+    harness.approve_tokens(user, &harness.contracts.commitment_core, amount);
+    let commitment_id = core_client.create_commitment(
+        user,
+        &amount,
+        &harness.contracts.token,
+        &harness.default_rules(),
+    );
+    let token_id = core_client.get_commitment(&commitment_id).nft_token_id;
+
+    core_client.extend_duration(user, &commitment_id, &15);
+
+    let commitment = core_client.get_commitment(&commitment_id);
+    let metadata = nft_client.get_metadata(&token_id);
+    assert_eq!(metadata.expires_at, commitment.expires_at);
+    assert_eq!(metadata.duration_days, commitment.rules.duration_days);
+    assert_eq!(
+        commitment.rules.duration_days,
+        harness.default_rules().duration_days + 15
+    );
+}
+
+/// Integration test: principal changes are mirrored onto the real NFT's initial_amount
+#[test]
+fn test_partial_withdraw_and_top_up_sync_nft_initial_amount() {
+    let harness = TestHarness::new();
+    let env = &harness.env;
+    let user = &harness.accounts.user1;
+    let amount = 1_000_000_000i128;
+    let core_client = CommitmentCoreContractClient::new(env, &harness.contracts.commitment_core);
+    let nft_client = CommitmentNFTContractClient::new(env, &harness.contracts.commitment_nft);
+
+    harness.approve_tokens(user, &harness.contracts.commitment_core, amount * 2);
+    let commitment_id = core_client.create_commitment(
+        user,
+        &amount,
+        &harness.contracts.token,
+        &harness.default_rules(),
+    );
+    let token_id = core_client.get_commitment(&commitment_id).nft_token_id;
+    let nft_amount = || {
+        nft_client
+            .get_private_metadata(user, &token_id)
+            .metadata
+            .initial_amount
+    };
+
+    core_client.partial_withdraw(user, &commitment_id, &(amount / 4));
+    assert_eq!(
+        nft_amount(),
+        core_client.get_commitment(&commitment_id).amount
+    );
+
+    core_client.top_up(user, &commitment_id, &(amount / 2));
+    assert_eq!(
+        nft_amount(),
+        core_client.get_commitment(&commitment_id).amount
+    );
+}
+
+/// Integration test: a renegotiated early-exit penalty is mirrored onto the real NFT
+#[test]
+fn test_set_early_exit_penalty_syncs_nft() {
+    let harness = TestHarness::new();
+    let env = &harness.env;
+    let user = &harness.accounts.user1;
+    let admin = &harness.accounts.admin;
+    let amount = 1_000_000_000i128;
+    let core_client = CommitmentCoreContractClient::new(env, &harness.contracts.commitment_core);
+    let nft_client = CommitmentNFTContractClient::new(env, &harness.contracts.commitment_nft);
+
+    harness.approve_tokens(user, &harness.contracts.commitment_core, amount);
+    let commitment_id = core_client.create_commitment(
+        user,
+        &amount,
+        &harness.contracts.token,
+        &harness.default_rules(),
+    );
+    let token_id = core_client.get_commitment(&commitment_id).nft_token_id;
+
+    core_client.set_early_exit_penalty(admin, &commitment_id, &25);
+
+    assert_eq!(
+        core_client
+            .get_commitment(&commitment_id)
+            .rules
+            .early_exit_penalty,
+        25
+    );
+    assert_eq!(nft_client.get_early_exit_penalty(&token_id), 25);
+}
+
+/// Integration test: core and engine each keep only the violations they detect
+#[test]
+fn test_violation_histories_split_between_core_and_engine() {
+    let harness = TestHarness::new();
+    let env = &harness.env;
+    let user = &harness.accounts.user1;
+    let amount = 1_000_000_000i128;
+    let core_client = CommitmentCoreContractClient::new(env, &harness.contracts.commitment_core);
+    let engine_client =
+        AttestationEngineContractClient::new(env, &harness.contracts.attestation_engine);
+
+    harness.approve_tokens(user, &harness.contracts.commitment_core, amount);
+    let commitment_id = core_client.create_commitment(
+        user,
+        &amount,
+        &harness.contracts.token,
+        &harness.default_rules(),
+    );
+
+    harness.advance_time(60);
+    engine_client.record_drawdown(&harness.accounts.verifier, &commitment_id, &15);
+    let drawdown_at = harness.current_timestamp();
+    assert_eq!(
+        engine_client.get_violation_history(&commitment_id),
+        Vec::from_array(env, [(drawdown_at, 15i128, 10u32)])
+    );
+    assert_eq!(core_client.get_violation_history(&commitment_id).len(), 0);
+
+    harness.advance_time(60);
+    core_client.update_value(
+        &harness.accounts.admin,
+        &commitment_id,
+        &(amount * 80 / 100),
+    );
+    let violated_at = harness.current_timestamp();
+    assert_eq!(
+        core_client.get_violation_history(&commitment_id),
+        Vec::from_array(env, [(violated_at, 20i128, 10u32)])
+    );
+    assert_eq!(engine_client.get_violation_history(&commitment_id).len(), 1);
+}
+
 /// Integration test: create_commitment mints NFT and metadata matches (#132)
 #[test]
 fn test_create_commitment_mints_nft_metadata_matches() {
@@ -189,11 +442,16 @@ fn test_create_commitment_mints_nft_metadata_matches() {
         });
     assert_eq!(nft_owner, *owner, "NFT owner must match commitment owner");
 
-    // get_metadata(token_id) matches rules and commitment
+    // get_private_metadata(owner, token_id) matches rules and commitment
     let nft = harness
         .env
         .as_contract(&harness.contracts.commitment_nft, || {
-            CommitmentNFTContract::get_metadata(harness.env.clone(), token_id).unwrap()
+            CommitmentNFTContract::get_private_metadata(
+                harness.env.clone(),
+                owner.clone(),
+                token_id,
+            )
+            .unwrap()
         });
 
     // Verify auto-generated commitment_id format: COMMIT_{token_id}
@@ -208,6 +466,57 @@ fn test_create_commitment_mints_nft_metadata_matches() {
     assert_eq!(nft.early_exit_penalty, rules.early_exit_penalty);
 }
 
+/// Core and NFT record the same asset, and the audit flags a forced divergence.
+#[test]
+fn test_core_and_nft_asset_consistency_audit() {
+    let harness = TestHarness::new();
+    let owner = &harness.accounts.user1;
+    let amount = 1_000_000_000_000i128;
+    let asset = &harness.contracts.token;
+
+    harness.approve_tokens(owner, &harness.contracts.commitment_core, amount);
+    let commitment_id = harness.create_commitment(owner, amount, asset, harness.default_rules());
+
+    let commitment = harness
+        .env
+        .as_contract(&harness.contracts.commitment_core, || {
+            CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
+        });
+    let nft_asset = harness
+        .env
+        .as_contract(&harness.contracts.commitment_nft, || {
+            CommitmentNFTContract::get_asset_address(
+                harness.env.clone(),
+                owner.clone(),
+                commitment.nft_token_id,
+            )
+            .unwrap()
+        });
+    assert_eq!(commitment.asset_address, *asset);
+    assert_eq!(nft_asset, commitment.asset_address);
+
+    let audit = |harness: &TestHarness| {
+        harness
+            .env
+            .as_contract(&harness.contracts.commitment_core, || {
+                CommitmentCoreContract::audit_nft_asset(harness.env.clone(), commitment_id.clone())
+            })
+    };
+    assert!(audit(&harness));
+
+    // Force the NFT record to point at another asset.
+    let key = commitment_nft::DataKey::NFT(commitment.nft_token_id);
+    harness
+        .env
+        .as_contract(&harness.contracts.commitment_nft, || {
+            let mut nft: commitment_nft::CommitmentNFT =
+                harness.env.storage().persistent().get(&key).unwrap();
+            nft.metadata.asset_address = Address::generate(&harness.env);
+            harness.env.storage().persistent().set(&key, &nft);
+        });
+    assert!(!audit(&harness));
+}
+
 /// Test: Attestation Engine verifies commitment in Core Contract
 #[test]
 fn test_attestation_engine_verifies_commitment_exists() {
@@ -769,7 +1078,10 @@ fn test_commitment_settlement_calls_nft_settle() {
         });
     assert!(!nft_after_settle.is_active);
     // Verify auto-generated commitment_id format: COMMIT_{token_id}
-    assert_eq!(nft_after_settle.metadata.commitment_id, String::from_str(&harness.env, "COMMIT_0"));
+    assert_eq!(
+        nft_after_settle.commitment_id,
+        String::from_str(&harness.env, "COMMIT_0")
+    );
     assert_eq!(nft_after_settle.owner, *user);
 
     // Verify commitment status
@@ -778,7 +1090,7 @@ fn test_commitment_settlement_calls_nft_settle() {
         .as_contract(&harness.contracts.commitment_core, || {
             CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
         });
-    assert_eq!(commitment.status, String::from_str(&harness.env, "settled"));
+    assert_eq!(commitment.status, CommitmentStatus::Settled);
 }
 
 /// Test: Allocation logic interacts with pools correctly
@@ -927,8 +1239,12 @@ fn test_cross_contract_state_consistency() {
     let nft = harness
         .env
         .as_contract(&harness.contracts.commitment_nft, || {
-            CommitmentNFTContract::get_metadata(harness.env.clone(), commitment.nft_token_id)
-                .unwrap()
+            CommitmentNFTContract::get_private_metadata(
+                harness.env.clone(),
+                user.clone(),
+                commitment.nft_token_id,
+            )
+            .unwrap()
         });
     assert_eq!(nft.owner, *user);
     assert_eq!(nft.metadata.initial_amount, amount);
@@ -1264,7 +1580,8 @@ fn test_nft_transfer_locked_until_settled() {
 // #148: early_exit when current_value is zero
 // =============================================================================
 
-/// Test: early_exit with current_value = 0 completes without panic; penalty = 0, returned = 0
+/// Test: early_exit with current_value = 0 completes without panic; penalty = 0, returned = 0,
+/// and the NFT is marked inactive
 #[test]
 fn test_early_exit_zero_current_value() {
     let harness = TestHarness::new();
@@ -1317,8 +1634,85 @@ fn test_early_exit_zero_current_value() {
         .as_contract(&harness.contracts.commitment_core, || {
             CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
         });
-    assert_eq!(commitment.status, String::from_str(&harness.env, "early_exit"));
+    assert_eq!(commitment.status, CommitmentStatus::EarlyExit);
     assert_eq!(commitment.current_value, 0);
+
+    // The exit unlocks the NFT in the same call, as settle does.
+    let nft_active = harness
+        .env
+        .as_contract(&harness.contracts.commitment_nft, || {
+            CommitmentNFTContract::is_active(harness.env.clone(), commitment.nft_token_id).unwrap()
+        });
+    assert!(!nft_active);
+}
+
+/// Create a commitment, optionally set the NFT violation policy, drive it into
+/// violation and report whether its NFT is still active.
+fn nft_active_after_violation(policy: Option<NftViolationPolicy>) -> bool {
+    let harness = TestHarness::new();
+    let user = &harness.accounts.user1;
+    let admin = &harness.accounts.admin;
+    let amount = 1_000_000_000_000i128;
+
+    harness.approve_tokens(user, &harness.contracts.commitment_core, amount);
+    let commitment_id = harness.create_commitment(
+        user,
+        amount,
+        &harness.contracts.token,
+        harness.default_rules(),
+    );
+
+    if let Some(policy) = policy {
+        harness
+            .env
+            .as_contract(&harness.contracts.commitment_core, || {
+                CommitmentCoreContract::set_nft_violation_policy(
+                    harness.env.clone(),
+                    admin.clone(),
+                    policy,
+                );
+            });
+    }
+    harness
+        .env
+        .as_contract(&harness.contracts.commitment_core, || {
+            CommitmentCoreContract::update_value(
+                harness.env.clone(),
+                admin.clone(),
+                commitment_id.clone(),
+                0,
+            );
+        });
+
+    let commitment = harness
+        .env
+        .as_contract(&harness.contracts.commitment_core, || {
+            CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
+        });
+    assert_eq!(commitment.status, CommitmentStatus::Violated);
+
+    harness
+        .env
+        .as_contract(&harness.contracts.commitment_nft, || {
+            CommitmentNFTContract::is_active(harness.env.clone(), commitment.nft_token_id).unwrap()
+        })
+}
+
+/// Default policy keeps a violated commitment's NFT locked.
+#[test]
+fn test_violation_keeps_nft_locked_by_default() {
+    assert!(nft_active_after_violation(None));
+    assert!(nft_active_after_violation(Some(
+        NftViolationPolicy::LockUntilForceSettle
+    )));
+}
+
+/// `UnlockOnViolation` deactivates the NFT as soon as the violation is detected.
+#[test]
+fn test_violation_unlocks_nft_when_configured() {
+    assert!(!nft_active_after_violation(Some(
+        NftViolationPolicy::UnlockOnViolation
+    )));
 }
 
 // =============================================================================
@@ -12,7 +12,7 @@ use soroban_sdk::{
     Address, Env, String,
 };
 
-use commitment_core::{CommitmentCoreContract, CommitmentRules};
+use commitment_core::{CommitmentCoreContract, CommitmentRules, CommitmentStatus};
 use commitment_nft::CommitmentNFTContract;
 use attestation_engine::AttestationEngineContract;
 use allocation_logic::{AllocationStrategiesContract, RiskLevel, Strategy};
@@ -68,7 +68,7 @@ fn test_e2e_complete_commitment_lifecycle() {
             CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
         });
     assert_eq!(commitment.owner, *user);
-    assert_eq!(commitment.status, String::from_str(&harness.env, "active"));
+    assert_eq!(commitment.status, CommitmentStatus::Active);
 
     // Verify NFT minted
     let nft_balance = harness
@@ -140,10 +140,7 @@ fn test_e2e_complete_commitment_lifecycle() {
         .as_contract(&harness.contracts.commitment_core, || {
             CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
         });
-    assert_eq!(
-        settled_commitment.status,
-        String::from_str(&harness.env, "settled")
-    );
+    assert_eq!(settled_commitment.status, CommitmentStatus::Settled);
 
     // Verify NFT is inactive
     let nft_active = harness
@@ -218,10 +215,7 @@ fn test_e2e_early_exit_with_penalty() {
         .as_contract(&harness.contracts.commitment_core, || {
             CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
         });
-    assert_eq!(
-        commitment.status,
-        String::from_str(&harness.env, "early_exit")
-    );
+    assert_eq!(commitment.status, CommitmentStatus::EarlyExit);
 
     // Verify penalty was applied
     let expected_penalty = amount * early_exit_penalty as i128 / 100;
@@ -685,3 +679,140 @@ fn test_e2e_allocation_rebalancing_flow() {
         });
     assert_eq!(final_allocation.total_allocated, amount);
 }
+
+/// Test: create -> mint -> attest -> update -> settle with the three live contracts
+/// wired together, checking that core, NFT and attestation state agree at every step.
+#[test]
+fn test_e2e_lifecycle_state_consistent_across_contracts() {
+    let harness = TestHarness::new();
+    let env = &harness.env;
+    let user = &harness.accounts.user1;
+    let admin = &harness.accounts.admin;
+    let verifier = &harness.accounts.verifier;
+    let core = &harness.contracts.commitment_core;
+    let nft = &harness.contracts.commitment_nft;
+    let engine = &harness.contracts.attestation_engine;
+    let amount = 1_000_000_000i128;
+    let initial_balance = harness.balance(user);
+
+    // ========== CREATE + MINT ==========
+    harness.approve_tokens(user, core, amount);
+    let rules = harness.default_rules();
+    let commitment_id = env.as_contract(core, || {
+        CommitmentCoreContract::create_commitment(
+            env.clone(),
+            user.clone(),
+            amount,
+            harness.contracts.token.clone(),
+            rules.clone(),
+        )
+    });
+
+    let commitment = env.as_contract(core, || {
+        CommitmentCoreContract::get_commitment(env.clone(), commitment_id.clone())
+    });
+    let token_id = commitment.nft_token_id;
+    assert_eq!(commitment.status, CommitmentStatus::Active);
+
+    let minted = env.as_contract(nft, || {
+        CommitmentNFTContract::get_private_metadata(env.clone(), user.clone(), token_id).unwrap()
+    });
+    assert_eq!(minted.owner, *user);
+    assert!(minted.is_active);
+    assert_eq!(minted.metadata.commitment_id, commitment_id);
+    assert_eq!(minted.metadata.initial_amount, commitment.amount);
+    assert_eq!(minted.metadata.expires_at, commitment.expires_at);
+    assert_eq!(minted.metadata.max_loss_percent, rules.max_loss_percent);
+
+    let metrics = env.as_contract(engine, || {
+        AttestationEngineContract::get_health_metrics(env.clone(), commitment_id.clone())
+    });
+    assert_eq!(metrics.initial_value, commitment.amount);
+    assert_eq!(metrics.current_value, commitment.current_value);
+    assert_eq!(harness.balance(user), initial_balance - amount);
+
+    // ========== ATTEST ==========
+    harness.advance_days(1);
+    env.as_contract(engine, || {
+        AttestationEngineContract::attest(
+            env.clone(),
+            verifier.clone(),
+            commitment_id.clone(),
+            String::from_str(env, "health_check"),
+            harness.health_check_data(),
+            true,
+        )
+        .unwrap();
+    });
+    let attested_ids = env.as_contract(engine, || {
+        AttestationEngineContract::get_attested_commitment_ids(env.clone(), 0, 10)
+    });
+    assert_eq!(attested_ids.len(), 1);
+    assert_eq!(attested_ids.get(0).unwrap(), commitment_id);
+    assert!(env.as_contract(engine, || {
+        AttestationEngineContract::verify_compliance(env.clone(), commitment_id.clone())
+    }));
+
+    // ========== UPDATE (within loss limit) ==========
+    let updated_value = commitment.amount * 95 / 100;
+    env.as_contract(core, || {
+        CommitmentCoreContract::update_value(
+            env.clone(),
+            admin.clone(),
+            commitment_id.clone(),
+            updated_value,
+        )
+    });
+    let updated = env.as_contract(core, || {
+        CommitmentCoreContract::get_commitment(env.clone(), commitment_id.clone())
+    });
+    assert_eq!(updated.current_value, updated_value);
+    assert_eq!(updated.status, CommitmentStatus::Active);
+
+    let metrics = env.as_contract(engine, || {
+        AttestationEngineContract::get_health_metrics(env.clone(), commitment_id.clone())
+    });
+    assert_eq!(metrics.current_value, updated_value);
+    assert_eq!(metrics.initial_value, commitment.amount);
+    let tvl = env.as_contract(core, || {
+        CommitmentCoreContract::get_total_value_locked(env.clone())
+    });
+    assert_eq!(tvl, updated_value);
+
+    // ========== SETTLE ==========
+    harness.advance_days(rules.duration_days as u64);
+    env.as_contract(core, || {
+        CommitmentCoreContract::settle(env.clone(), commitment_id.clone())
+    });
+
+    let settled = env.as_contract(core, || {
+        CommitmentCoreContract::get_commitment(env.clone(), commitment_id.clone())
+    });
+    assert_eq!(settled.status, CommitmentStatus::Settled);
+    assert_eq!(settled.settled_amount, updated_value);
+    assert_eq!(settled.settled_at, harness.current_timestamp());
+    let tvl = env.as_contract(core, || {
+        CommitmentCoreContract::get_total_value_locked(env.clone())
+    });
+    assert_eq!(tvl, 0);
+
+    let settled_nft = env.as_contract(nft, || {
+        CommitmentNFTContract::get_metadata(env.clone(), token_id).unwrap()
+    });
+    assert!(!settled_nft.is_active);
+    assert_eq!(settled_nft.owner, *user);
+
+    let metrics = env.as_contract(engine, || {
+        AttestationEngineContract::get_health_metrics(env.clone(), commitment_id.clone())
+    });
+    assert_eq!(metrics.current_value, updated_value);
+    assert!(env.as_contract(engine, || {
+        AttestationEngineContract::verify_compliance(env.clone(), commitment_id.clone())
+    }));
+
+    // The owner receives the marked-down value, not the original deposit.
+    assert_eq!(
+        harness.balance(user),
+        initial_balance - amount + updated_value
+    );
+}
@@ -8,9 +8,11 @@
 //! - Expected error assertions
 
 use crate::harness::{TestHarness, DEFAULT_USER_BALANCE, SECONDS_PER_DAY};
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
 
-use commitment_core::{CommitmentCoreContract, CommitmentError, CommitmentRules};
+use commitment_core::{
+    CommitmentCoreContract, CommitmentCoreContractClient, CommitmentError, CommitmentRules,
+};
 use commitment_nft::{CommitmentNFTContract, ContractError as NftError};
 use attestation_engine::{AttestationEngineContract, AttestationError};
 use allocation_logic::{AllocationStrategiesContract, Error as AllocationError, RiskLevel, Strategy};
@@ -269,7 +271,7 @@ fn test_error_zero_duration_commitment() {
 
 /// Test: Invalid max loss percent fails
 #[test]
-#[should_panic(expected = "Invalid percent")]
+#[should_panic(expected = "Invalid max loss")]
 fn test_error_invalid_max_loss_percent() {
     let harness = TestHarness::new();
     let user = &harness.accounts.user1;
@@ -860,3 +862,70 @@ fn test_error_rebalance_nonexistent() {
 
     assert_eq!(result, Err(AllocationError::AllocationNotFound));
 }
+
+// ============================================================================
+// Misconfigured Dependency Tests
+// ============================================================================
+
+/// NFT contract whose `mint` reports a token id that was never stored.
+#[contract]
+pub struct PhantomMintNftContract;
+
+#[contractimpl]
+impl PhantomMintNftContract {
+    #[allow(clippy::too_many_arguments)]
+    pub fn mint(
+        _e: Env,
+        _caller: Address,
+        _owner: Address,
+        _commitment_id: String,
+        _duration_days: u32,
+        _max_loss_percent: u32,
+        _commitment_type: String,
+        _initial_amount: i128,
+        _asset_address: Address,
+        _early_exit_penalty: u32,
+    ) -> u32 {
+        7
+    }
+
+    pub fn token_exists(_e: Env, _token_id: u32) -> bool {
+        false
+    }
+}
+
+/// Test: A mint that does not produce a token fails cleanly with no stored commitment
+#[test]
+fn test_error_phantom_nft_mint_fails_without_commitment() {
+    let harness = TestHarness::new();
+    let env = &harness.env;
+    let user = &harness.accounts.user1;
+    let amount = 1_000_000_000i128;
+
+    let nft = env.register_contract(None, PhantomMintNftContract);
+    let core = env.register_contract(None, CommitmentCoreContract);
+    let core_client = CommitmentCoreContractClient::new(env, &core);
+    core_client.initialize(&harness.accounts.admin, &nft);
+    harness.approve_tokens(user, &core, amount);
+
+    let result = core_client.try_create_commitment(
+        user,
+        &amount,
+        &harness.contracts.token,
+        &harness.default_rules(),
+    );
+    assert_eq!(
+        result,
+        Err(Ok(CommitmentError::MintingFailed.into())),
+        "phantom mint must not create a commitment"
+    );
+
+    assert_eq!(core_client.get_total_commitments(), 0);
+    assert_eq!(core_client.get_total_value_locked(), 0);
+    assert_eq!(core_client.get_owner_commitments(user, &0, &10).len(), 0);
+    assert_eq!(
+        core_client.try_get_commitment_by_nft(&nft, &7),
+        Err(Ok(CommitmentError::CommitmentNotFound.into()))
+    );
+    assert_eq!(harness.balance(user), DEFAULT_USER_BALANCE);
+}
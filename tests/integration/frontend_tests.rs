@@ -13,7 +13,7 @@ use soroban_sdk::{
     Address, Env, String, IntoVal, Symbol,
 };
 
-use commitment_core::{CommitmentCoreContract, CommitmentRules};
+use commitment_core::{CommitmentCoreContract, CommitmentRules, CommitmentStatus};
 use commitment_nft::CommitmentNFTContract;
 
 /// Test: Simulate frontend wallet connection and basic interaction
@@ -418,10 +418,7 @@ fn test_frontend_early_exit_flow() {
         .as_contract(&harness.contracts.commitment_core, || {
             CommitmentCoreContract::get_commitment(harness.env.clone(), commitment_id.clone())
         });
-    assert_eq!(
-        commitment.status,
-        String::from_str(&harness.env, "early_exit")
-    );
+    assert_eq!(commitment.status, CommitmentStatus::EarlyExit);
 }
 
 /// Test: Frontend commitment rule display for different types
@@ -4,7 +4,7 @@
 #![cfg(test)]
 
 use attestation_engine::{AttestationEngineContract, AttestationEngineContractClient};
-use commitment_core::{CommitmentCoreContract, CommitmentCoreContractClient, CommitmentRules};
+use commitment_core::{CommitmentCoreContract, CommitmentCoreContractClient, CommitmentRules, CommitmentStatus};
 use commitment_nft::{CommitmentNFTContract, CommitmentNFTContractClient};
 use price_oracle::{PriceOracleContract, PriceOracleContractClient};
 use soroban_sdk::{
@@ -99,7 +99,7 @@ fn test_create_commitment_with_attestation_flow() {
     let commitment = fixture.core_client.get_commitment(&commitment_id);
     assert_eq!(commitment.owner, fixture.owner);
     assert_eq!(commitment.amount, 1000_0000000);
-    assert_eq!(commitment.status, String::from_str(&fixture.env, "active"));
+    assert_eq!(commitment.status, CommitmentStatus::Active);
 
     // Step 2: Record attestation for the commitment
     let mut data = Map::new(&fixture.env);
@@ -165,7 +165,7 @@ fn test_commitment_value_update_with_health_tracking() {
     // Verify commitment status
     let commitment = fixture.core_client.get_commitment(&commitment_id);
     assert_eq!(commitment.current_value, 1050_0000000);
-    assert_eq!(commitment.status, String::from_str(&fixture.env, "active"));
+    assert_eq!(commitment.status, CommitmentStatus::Active);
 }
 
 #[test]
@@ -199,10 +199,7 @@ fn test_settlement_flow_end_to_end() {
 
     // Verify commitment is settled
     let settled_commitment = fixture.core_client.get_commitment(&commitment_id);
-    assert_eq!(
-        settled_commitment.status,
-        String::from_str(&fixture.env, "settled")
-    );
+    assert_eq!(settled_commitment.status, CommitmentStatus::Settled);
 }
 
 #[test]
@@ -254,10 +251,7 @@ fn test_early_exit_flow_end_to_end() {
 
     // Verify commitment is marked as early exit
     let commitment = fixture.core_client.get_commitment(&commitment_id);
-    assert_eq!(
-        commitment.status,
-        String::from_str(&fixture.env, "early_exit")
-    );
+    assert_eq!(commitment.status, CommitmentStatus::EarlyExit);
 }
 
 #[test]